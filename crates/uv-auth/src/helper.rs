@@ -0,0 +1,212 @@
+use std::fmt::Write;
+use std::process::Stdio;
+
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::{debug, instrument, warn};
+use url::Url;
+
+use uv_static::EnvVars;
+
+use crate::credentials::Credentials;
+
+/// An error parsing a credential helper command.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum CredentialHelperError {
+    #[error("The credential helper command is empty")]
+    Empty,
+    #[error("Failed to parse credential helper command: `{0}`")]
+    Parse(String),
+}
+
+/// An external credential helper that speaks the `git-credential` protocol.
+///
+/// The helper is invoked with an action (`get`, `store`, or `erase`) as its final argument, and
+/// receives a description of the credential as `key=value` lines on standard input, e.g.:
+///
+/// ```text
+/// protocol=https
+/// host=pypi.example.com
+/// username=user
+/// ```
+///
+/// As in Git, the path of the URL is only included (e.g., `path=simple/flask/`) if the helper is
+/// configured to use it, since most helpers store a single credential per host.
+///
+/// For `get`, the helper responds with the same format on standard output, including (at least)
+/// a `password`. See: <https://git-scm.com/docs/git-credential#IOFMT>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CredentialHelper {
+    program: String,
+    args: Vec<String>,
+    /// Whether to include the path of the URL in the credential description, like Git's
+    /// `credential.useHttpPath`.
+    use_http_path: bool,
+}
+
+impl CredentialHelper {
+    /// Create a [`CredentialHelper`] from a command, e.g., `git credential-store --file creds`.
+    ///
+    /// The command is split into arguments following shell quoting rules, such that arguments
+    /// may contain whitespace (e.g., `helper --file "My Credentials"`).
+    pub fn new(command: &str) -> Result<Self, CredentialHelperError> {
+        let mut parts = shlex::split(command)
+            .ok_or_else(|| CredentialHelperError::Parse(command.to_string()))?
+            .into_iter();
+        let program = parts.next().ok_or(CredentialHelperError::Empty)?;
+        Ok(Self {
+            program,
+            args: parts.collect(),
+            use_http_path: false,
+        })
+    }
+
+    /// Include the path of the URL when describing a credential to the helper.
+    #[must_use]
+    pub fn with_use_http_path(mut self, use_http_path: bool) -> Self {
+        self.use_http_path = use_http_path;
+        self
+    }
+
+    /// Create a [`CredentialHelper`] from the `UV_CREDENTIAL_HELPER` environment variable, along
+    /// with `UV_CREDENTIAL_HELPER_USE_HTTP_PATH`.
+    ///
+    /// Returns `None` if the variable is unset or empty.
+    pub fn from_env() -> Result<Option<Self>, CredentialHelperError> {
+        let Some(command) = std::env::var(EnvVars::UV_CREDENTIAL_HELPER)
+            .ok()
+            .filter(|command| !command.trim().is_empty())
+        else {
+            return Ok(None);
+        };
+        let use_http_path = std::env::var(EnvVars::UV_CREDENTIAL_HELPER_USE_HTTP_PATH)
+            .is_ok_and(|value| matches!(value.as_str(), "1" | "true"));
+        Ok(Some(Self::new(&command)?.with_use_http_path(use_http_path)))
+    }
+
+    /// Request credentials for the given [`Url`] from the helper.
+    ///
+    /// Returns [`None`] if the helper does not provide a password, or if the helper fails.
+    #[instrument(skip_all, fields(url = % url.to_string(), username))]
+    pub(crate) async fn get(&self, url: &Url, username: Option<&str>) -> Option<Credentials> {
+        let output = self.run("get", self.describe(url, username, None)).await?;
+
+        let mut found_username = None;
+        let mut password = None;
+        for line in output.lines() {
+            match line.split_once('=') {
+                Some(("username", value)) => found_username = Some(value.to_string()),
+                Some(("password", value)) => password = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        // The helper may not change a username that was explicitly requested.
+        if let (Some(username), Some(found_username)) = (username, found_username.as_deref()) {
+            if username != found_username {
+                debug!(
+                    "Ignoring credentials from helper for mismatched username: {found_username}"
+                );
+                return None;
+            }
+        }
+
+        let password = password?;
+        Some(Credentials::new(
+            found_username.or_else(|| username.map(ToString::to_string)),
+            Some(password),
+        ))
+    }
+
+    /// Ask the helper to store credentials that were accepted for the given [`Url`].
+    pub(crate) async fn store(&self, url: &Url, credentials: &Credentials) {
+        self.run(
+            "store",
+            self.describe(url, credentials.username(), credentials.password()),
+        )
+        .await;
+    }
+
+    /// Ask the helper to erase credentials that were rejected for the given [`Url`].
+    pub(crate) async fn erase(&self, url: &Url, credentials: &Credentials) {
+        self.run(
+            "erase",
+            self.describe(url, credentials.username(), credentials.password()),
+        )
+        .await;
+    }
+
+    /// Describe a credential for the given [`Url`] in the `git-credential` input format.
+    fn describe(&self, url: &Url, username: Option<&str>, password: Option<&str>) -> String {
+        let mut input = String::new();
+        writeln!(input, "protocol={}", url.scheme()).unwrap();
+        if let Some(host) = url.host_str() {
+            if let Some(port) = url.port() {
+                writeln!(input, "host={host}:{port}").unwrap();
+            } else {
+                writeln!(input, "host={host}").unwrap();
+            }
+        }
+        if self.use_http_path {
+            let path = url.path().trim_start_matches('/');
+            if !path.is_empty() {
+                writeln!(input, "path={path}").unwrap();
+            }
+        }
+        if let Some(username) = username {
+            writeln!(input, "username={username}").unwrap();
+        }
+        if let Some(password) = password {
+            writeln!(input, "password={password}").unwrap();
+        }
+        // A blank line terminates the description.
+        input.push('\n');
+        input
+    }
+
+    /// Run the helper with the given action and input, returning its standard output.
+    async fn run(&self, action: &str, input: String) -> Option<String> {
+        debug!(
+            "Running credential helper `{}` with `{action}`",
+            self.program
+        );
+        let mut child = Command::new(&self.program)
+            .args(&self.args)
+            .arg(action)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .inspect_err(|err| warn!("Failed to run credential helper `{}`: {err}", self.program))
+            .ok()?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(input.as_bytes())
+                .await
+                .inspect_err(|err| warn!("Failed to write to credential helper: {err}"))
+                .ok()?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .await
+            .inspect_err(|err| warn!("Failed to wait for credential helper output: {err}"))
+            .ok()?;
+
+        if output.status.success() {
+            String::from_utf8(output.stdout)
+                .inspect_err(|err| warn!("Failed to parse response from credential helper: {err}"))
+                .ok()
+        } else {
+            debug!(
+                "Credential helper `{}` failed with {}",
+                self.program, output.status
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,127 @@
+use super::*;
+
+/// Write a fake credential helper that records its invocations to `log`, and responds to `get`
+/// requests with the given output.
+#[cfg(unix)]
+fn fake_helper(dir: &std::path::Path, response: &str) -> (CredentialHelper, std::path::PathBuf) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let log = dir.join("log");
+    let script = dir.join("helper");
+    fs_err::write(
+        &script,
+        format!(
+            "#!/bin/sh\necho \"action=$1\" >> '{log}'\ncat >> '{log}'\nif [ \"$1\" = \"get\" ]; then\nprintf '{response}'\nfi\n",
+            log = log.display(),
+        ),
+    )
+    .unwrap();
+    fs_err::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let helper = CredentialHelper::new(&script.display().to_string()).unwrap();
+    (helper, log)
+}
+
+#[test]
+fn describe_url() {
+    let url = Url::parse("https://example.com:8080/simple/flask/").unwrap();
+    let helper = CredentialHelper::new("helper").unwrap();
+    insta::assert_snapshot!(helper.describe(&url, Some("user"), None), @r###"
+    protocol=https
+    host=example.com:8080
+    username=user
+    "###);
+
+    // The path is only included if the helper asks for it.
+    let helper = helper.with_use_http_path(true);
+    insta::assert_snapshot!(helper.describe(&url, Some("user"), None), @r###"
+    protocol=https
+    host=example.com:8080
+    path=simple/flask/
+    username=user
+    "###);
+}
+
+#[test]
+fn new_command() {
+    assert_eq!(
+        CredentialHelper::new("  "),
+        Err(CredentialHelperError::Empty)
+    );
+    assert_eq!(
+        CredentialHelper::new("git credential-store --file creds"),
+        Ok(CredentialHelper {
+            program: "git".to_string(),
+            args: vec![
+                "credential-store".to_string(),
+                "--file".to_string(),
+                "creds".to_string()
+            ],
+            use_http_path: false,
+        })
+    );
+    assert_eq!(
+        CredentialHelper::new(r#"'/opt/My Tools/helper' --file "My Credentials""#),
+        Ok(CredentialHelper {
+            program: "/opt/My Tools/helper".to_string(),
+            args: vec!["--file".to_string(), "My Credentials".to_string()],
+            use_http_path: false,
+        })
+    );
+    assert_eq!(
+        CredentialHelper::new(r#"helper --file "creds"#),
+        Err(CredentialHelperError::Parse(
+            r#"helper --file "creds"#.to_string()
+        ))
+    );
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn get() {
+    let dir = tempfile::tempdir().unwrap();
+    let (helper, log) = fake_helper(dir.path(), "username=user\\npassword=password\\n");
+
+    let url = Url::parse("https://example.com/simple/").unwrap();
+    assert_eq!(
+        helper.get(&url, None).await,
+        Some(Credentials::new(
+            Some("user".to_string()),
+            Some("password".to_string())
+        ))
+    );
+
+    insta::assert_snapshot!(fs_err::read_to_string(&log).unwrap(), @r###"
+    action=get
+    protocol=https
+    host=example.com
+    "###);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn get_username_mismatch() {
+    let dir = tempfile::tempdir().unwrap();
+    let (helper, _) = fake_helper(dir.path(), "username=other\\npassword=password\\n");
+
+    let url = Url::parse("https://example.com/simple/").unwrap();
+    assert_eq!(helper.get(&url, Some("user")).await, None);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn get_no_password() {
+    let dir = tempfile::tempdir().unwrap();
+    let (helper, _) = fake_helper(dir.path(), "");
+
+    let url = Url::parse("https://example.com/simple/").unwrap();
+    assert_eq!(helper.get(&url, Some("user")).await, None);
+}
+
+#[tokio::test]
+async fn get_missing_program() {
+    let helper = CredentialHelper::new("uv-test-missing-credential-helper").unwrap();
+
+    let url = Url::parse("https://example.com/simple/").unwrap();
+    assert_eq!(helper.get(&url, None).await, None);
+}
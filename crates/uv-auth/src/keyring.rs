@@ -1,10 +1,25 @@
-use std::process::Stdio;
+use std::process::{ExitStatus, Stdio};
+use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 use tracing::{instrument, trace, warn};
 use url::Url;
 
 use crate::credentials::Credentials;
 
+#[derive(Debug, thiserror::Error)]
+pub enum KeyringError {
+    #[error("Failed to run `keyring` command")]
+    Io(#[from] std::io::Error),
+    #[error("The `keyring` command failed ({0})")]
+    Subprocess(ExitStatus),
+    #[error(transparent)]
+    Native(#[from] keyring::Error),
+    #[error("Failed to join system keyring task")]
+    Join(#[from] tokio::task::JoinError),
+    #[error("The keyring provider does not support modifying credentials")]
+    Unsupported,
+}
+
 /// A backend for retrieving credentials from a keyring.
 ///
 /// See pip's implementation for reference
@@ -18,6 +33,9 @@ pub struct KeyringProvider {
 pub(crate) enum KeyringProviderBackend {
     /// Use the `keyring` command to fetch credentials.
     Subprocess,
+    /// Use the operating system's credential store to fetch credentials (e.g., the macOS
+    /// Keychain, the Windows Credential Manager, or the Secret Service on Linux).
+    Native,
     #[cfg(test)]
     Dummy(std::collections::HashMap<(String, &'static str), &'static str>),
 }
@@ -30,6 +48,13 @@ impl KeyringProvider {
         }
     }
 
+    /// Create a new [`KeyringProvider::Native`].
+    pub fn native() -> Self {
+        Self {
+            backend: KeyringProviderBackend::Native,
+        }
+    }
+
     /// Fetch credentials for the given [`Url`] from the keyring.
     ///
     /// Returns [`None`] if no password was found for the username or if any errors
@@ -57,6 +82,7 @@ impl KeyringProvider {
             KeyringProviderBackend::Subprocess => {
                 self.fetch_subprocess(url.as_str(), username).await
             }
+            KeyringProviderBackend::Native => self.fetch_native(url.as_str(), username).await,
             #[cfg(test)]
             KeyringProviderBackend::Dummy(ref store) => {
                 Self::fetch_dummy(store, url.as_str(), username)
@@ -72,6 +98,7 @@ impl KeyringProvider {
             trace!("Checking keyring for host {host}");
             password = match self.backend {
                 KeyringProviderBackend::Subprocess => self.fetch_subprocess(&host, username).await,
+                KeyringProviderBackend::Native => self.fetch_native(&host, username).await,
                 #[cfg(test)]
                 KeyringProviderBackend::Dummy(ref store) => {
                     Self::fetch_dummy(store, &host, username)
@@ -82,6 +109,99 @@ impl KeyringProvider {
         password.map(|password| Credentials::new(Some(username.to_string()), Some(password)))
     }
 
+    /// Store a password for the given service and username in the keyring.
+    ///
+    /// The service is the host (and port, if non-default) of the given [`Url`], which is checked
+    /// as a fallback for any URL on that host by [`KeyringProvider::fetch`].
+    pub async fn store(
+        &self,
+        url: &Url,
+        username: &str,
+        password: &str,
+    ) -> Result<(), KeyringError> {
+        let service_name = Self::service_name(url);
+        match self.backend {
+            KeyringProviderBackend::Subprocess => {
+                // Pass the password on standard input, rather than as an argument.
+                let mut child = Command::new("keyring")
+                    .arg("set")
+                    .arg(&service_name)
+                    .arg(username)
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::inherit())
+                    .spawn()?;
+                if let Some(mut stdin) = child.stdin.take() {
+                    stdin.write_all(password.as_bytes()).await?;
+                    stdin.write_all(b"\n").await?;
+                }
+                let status = child.wait().await?;
+                if status.success() {
+                    Ok(())
+                } else {
+                    Err(KeyringError::Subprocess(status))
+                }
+            }
+            KeyringProviderBackend::Native => {
+                let username = username.to_string();
+                let password = password.to_string();
+                tokio::task::spawn_blocking(move || {
+                    keyring::Entry::new(&service_name, &username)?.set_password(&password)
+                })
+                .await??;
+                Ok(())
+            }
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(_) => Err(KeyringError::Unsupported),
+        }
+    }
+
+    /// Remove the password for the given service and username from the keyring.
+    ///
+    /// Returns `false` if no password was stored.
+    pub async fn remove(&self, url: &Url, username: &str) -> Result<bool, KeyringError> {
+        let service_name = Self::service_name(url);
+        match self.backend {
+            KeyringProviderBackend::Subprocess => {
+                let status = Command::new("keyring")
+                    .arg("del")
+                    .arg(&service_name)
+                    .arg(username)
+                    .stdin(Stdio::null())
+                    .stdout(Stdio::null())
+                    .stderr(Stdio::null())
+                    .status()
+                    .await?;
+                // `keyring del` fails if the password does not exist.
+                Ok(status.success())
+            }
+            KeyringProviderBackend::Native => {
+                let username = username.to_string();
+                let result = tokio::task::spawn_blocking(move || {
+                    keyring::Entry::new(&service_name, &username)?.delete_credential()
+                })
+                .await?;
+                match result {
+                    Ok(()) => Ok(true),
+                    Err(keyring::Error::NoEntry) => Ok(false),
+                    Err(err) => Err(err.into()),
+                }
+            }
+            #[cfg(test)]
+            KeyringProviderBackend::Dummy(_) => Err(KeyringError::Unsupported),
+        }
+    }
+
+    /// The service name under which to store credentials for the given [`Url`].
+    fn service_name(url: &Url) -> String {
+        let host = url.host_str().unwrap_or_default();
+        if let Some(port) = url.port() {
+            format!("{host}:{port}")
+        } else {
+            host.to_string()
+        }
+    }
+
     #[instrument(skip(self))]
     async fn fetch_subprocess(&self, service_name: &str, username: &str) -> Option<String> {
         // https://github.com/pypa/pip/blob/24.0/src/pip/_internal/network/auth.py#L136-L141
@@ -114,6 +234,31 @@ impl KeyringProvider {
         }
     }
 
+    #[instrument(skip(self))]
+    async fn fetch_native(&self, service_name: &str, username: &str) -> Option<String> {
+        let service_name = service_name.to_string();
+        let username = username.to_string();
+
+        // The platform credential stores are synchronous (and may block on, e.g., D-Bus).
+        tokio::task::spawn_blocking(move || {
+            let entry = keyring::Entry::new(&service_name, &username)
+                .inspect_err(|err| warn!("Failed to access the system keyring: {err}"))
+                .ok()?;
+            match entry.get_password() {
+                Ok(password) => Some(password),
+                Err(keyring::Error::NoEntry) => None,
+                Err(err) => {
+                    warn!("Failed to read credentials from the system keyring: {err}");
+                    None
+                }
+            }
+        })
+        .await
+        .inspect_err(|err| warn!("Failed to join system keyring task: {err}"))
+        .ok()
+        .flatten()
+    }
+
     #[cfg(test)]
     fn fetch_dummy(
         store: &std::collections::HashMap<(String, &'static str), &'static str>,
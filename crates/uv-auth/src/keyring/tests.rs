@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Once};
+
 use super::*;
 use futures::FutureExt;
 
@@ -126,3 +129,113 @@ async fn fetch_url_username_no_match() {
     let credentials = keyring.fetch(&url, "bar").await;
     assert_eq!(credentials, None);
 }
+
+/// The secrets in a [`MemoryStore`], keyed by service name and username.
+type Secrets = Arc<Mutex<HashMap<(String, String), Vec<u8>>>>;
+
+/// An in-memory credential store, shared across all entries (unlike [`keyring::mock`], in which
+/// every entry has its own credential).
+///
+/// The native tests below run against this store, so they cover how uv stores and looks up
+/// credentials, but not the platform backends themselves: in particular, the Secret Service
+/// backend used on Linux, which talks to a keyring daemon over D-Bus, isn't exercised by any test.
+#[derive(Debug, Default)]
+struct MemoryStore(Secrets);
+
+#[derive(Debug)]
+struct MemoryCredential {
+    store: Secrets,
+    key: (String, String),
+}
+
+impl keyring::credential::CredentialApi for MemoryCredential {
+    fn set_secret(&self, secret: &[u8]) -> keyring::Result<()> {
+        let mut store = self.store.lock().unwrap();
+        store.insert(self.key.clone(), secret.to_vec());
+        Ok(())
+    }
+
+    fn get_secret(&self) -> keyring::Result<Vec<u8>> {
+        let store = self.store.lock().unwrap();
+        store.get(&self.key).cloned().ok_or(keyring::Error::NoEntry)
+    }
+
+    fn delete_credential(&self) -> keyring::Result<()> {
+        let mut store = self.store.lock().unwrap();
+        store
+            .remove(&self.key)
+            .map(|_| ())
+            .ok_or(keyring::Error::NoEntry)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl keyring::credential::CredentialBuilderApi for MemoryStore {
+    fn build(
+        &self,
+        _target: Option<&str>,
+        service: &str,
+        user: &str,
+    ) -> keyring::Result<Box<keyring::credential::Credential>> {
+        Ok(Box::new(MemoryCredential {
+            store: self.0.clone(),
+            key: (service.to_string(), user.to_string()),
+        }))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// Use an in-memory credential store, rather than the system keyring.
+///
+/// The credential builder is process-global, so it's installed once and shared across tests.
+fn use_memory_store() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        keyring::set_default_credential_builder(Box::new(MemoryStore::default()));
+    });
+}
+
+#[tokio::test]
+async fn fetch_native_no_entry() {
+    use_memory_store();
+
+    let url = Url::parse("https://example.com").unwrap();
+    let keyring = KeyringProvider::native();
+    assert_eq!(keyring.fetch(&url, "user").await, None);
+}
+
+#[tokio::test]
+async fn fetch_native_host() {
+    use_memory_store();
+
+    let keyring = KeyringProvider::native();
+    let url = Url::parse("https://native.example.com:8080/simple/").unwrap();
+    keyring.store(&url, "user", "password").await.unwrap();
+
+    // Credentials are stored for the host, and so are found for any URL on that host.
+    let url = Url::parse("https://native.example.com:8080/simple/anyio/").unwrap();
+    assert_eq!(
+        keyring.fetch(&url, "user").await,
+        Some(Credentials::new(
+            Some("user".to_string()),
+            Some("password".to_string())
+        ))
+    );
+
+    // But not for other users, or other ports.
+    assert_eq!(keyring.fetch(&url, "other").await, None);
+    let url = Url::parse("https://native.example.com/simple/anyio/").unwrap();
+    assert_eq!(keyring.fetch(&url, "user").await, None);
+
+    // Once removed, the credentials should no longer be found.
+    let url = Url::parse("https://native.example.com:8080/simple/").unwrap();
+    assert!(keyring.remove(&url, "user").await.unwrap());
+    assert!(!keyring.remove(&url, "user").await.unwrap());
+    assert_eq!(keyring.fetch(&url, "user").await, None);
+}
@@ -5,15 +5,21 @@ use url::Url;
 
 use cache::CredentialsCache;
 pub use credentials::Credentials;
-pub use keyring::KeyringProvider;
+pub use helper::{CredentialHelper, CredentialHelperError};
+pub use keyring::{KeyringError, KeyringProvider};
 pub use middleware::AuthMiddleware;
+pub use policy::{AuthPolicies, AuthPolicy, CredentialProvider, IndexPolicy};
 use realm::Realm;
+pub use store::{TextCredentialStore, TextStoreError};
 
 mod cache;
 mod credentials;
+mod helper;
 mod keyring;
 mod middleware;
+mod policy;
 mod realm;
+mod store;
 
 // TODO(zanieb): Consider passing a cache explicitly throughout
 
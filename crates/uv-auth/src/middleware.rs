@@ -5,8 +5,10 @@ use url::Url;
 
 use crate::{
     credentials::{Credentials, Username},
+    policy::{AuthPolicies, AuthPolicy, CredentialProvider},
     realm::Realm,
-    CredentialsCache, KeyringProvider, CREDENTIALS_CACHE,
+    store::TextCredentialStore,
+    CredentialHelper, CredentialsCache, KeyringProvider, CREDENTIALS_CACHE,
 };
 use anyhow::{anyhow, format_err};
 use netrc::Netrc;
@@ -48,26 +50,94 @@ impl NetrcMode {
     }
 }
 
+/// Strategy for configuring the external credential helper.
+enum CredentialHelperMode {
+    Automatic(LazyLock<Option<CredentialHelper>>),
+    Enabled(CredentialHelper),
+    Disabled,
+}
+
+impl Default for CredentialHelperMode {
+    fn default() -> Self {
+        CredentialHelperMode::Automatic(LazyLock::new(|| {
+            CredentialHelper::from_env()
+                .inspect_err(|err| warn!("Ignoring `UV_CREDENTIAL_HELPER`: {err}"))
+                .ok()
+                .flatten()
+        }))
+    }
+}
+
+impl CredentialHelperMode {
+    /// Get the credential helper if enabled.
+    fn get(&self) -> Option<&CredentialHelper> {
+        match self {
+            CredentialHelperMode::Automatic(lock) => lock.as_ref(),
+            CredentialHelperMode::Enabled(helper) => Some(helper),
+            CredentialHelperMode::Disabled => None,
+        }
+    }
+}
+
+/// Strategy for loading the uv-managed credentials file.
+enum TextStoreMode {
+    Automatic(LazyLock<Option<TextCredentialStore>>),
+    Enabled(TextCredentialStore),
+    Disabled,
+}
+
+impl Default for TextStoreMode {
+    fn default() -> Self {
+        TextStoreMode::Automatic(LazyLock::new(|| {
+            let path = TextCredentialStore::default_path()
+                .inspect_err(|err| warn!("Failed to determine credentials file path: {err}"))
+                .ok()?;
+            TextCredentialStore::read(&path)
+                .inspect_err(|err| warn!("Error reading credentials file: {err}"))
+                .ok()
+        }))
+    }
+}
+
+impl TextStoreMode {
+    /// Get the parsed credentials file if enabled.
+    fn get(&self) -> Option<&TextCredentialStore> {
+        match self {
+            TextStoreMode::Automatic(lock) => lock.as_ref(),
+            TextStoreMode::Enabled(store) => Some(store),
+            TextStoreMode::Disabled => None,
+        }
+    }
+}
+
 /// A middleware that adds basic authentication to requests.
 ///
 /// Uses a cache to propagate credentials from previously seen requests and
-/// fetches credentials from a netrc file and the keyring.
+/// fetches credentials from the uv credentials file, a netrc file, a credential helper, and the
+/// keyring.
 pub struct AuthMiddleware {
     netrc: NetrcMode,
+    text_store: TextStoreMode,
+    credential_helper: CredentialHelperMode,
     keyring: Option<KeyringProvider>,
     cache: Option<CredentialsCache>,
     /// We know that the endpoint needs authentication, so we don't try to send an unauthenticated
     /// request, avoiding cloning an uncloneable request.
     only_authenticated: bool,
+    /// The per-index authentication policies.
+    policies: AuthPolicies,
 }
 
 impl AuthMiddleware {
     pub fn new() -> Self {
         Self {
             netrc: NetrcMode::default(),
+            text_store: TextStoreMode::default(),
+            credential_helper: CredentialHelperMode::default(),
             keyring: None,
             cache: None,
             only_authenticated: false,
+            policies: AuthPolicies::default(),
         }
     }
 
@@ -84,6 +154,32 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`TextCredentialStore`] to use.
+    ///
+    /// `None` disables authentication via the uv credentials file.
+    #[must_use]
+    pub fn with_text_store(mut self, store: Option<TextCredentialStore>) -> Self {
+        self.text_store = if let Some(store) = store {
+            TextStoreMode::Enabled(store)
+        } else {
+            TextStoreMode::Disabled
+        };
+        self
+    }
+
+    /// Configure the [`CredentialHelper`] to use.
+    ///
+    /// `None` disables authentication via a credential helper.
+    #[must_use]
+    pub fn with_credential_helper(mut self, helper: Option<CredentialHelper>) -> Self {
+        self.credential_helper = if let Some(helper) = helper {
+            CredentialHelperMode::Enabled(helper)
+        } else {
+            CredentialHelperMode::Disabled
+        };
+        self
+    }
+
     /// Configure the [`KeyringProvider`] to use.
     #[must_use]
     pub fn with_keyring(mut self, keyring: Option<KeyringProvider>) -> Self {
@@ -106,6 +202,13 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the per-index [`AuthPolicies`] to use.
+    #[must_use]
+    pub fn with_policies(mut self, policies: AuthPolicies) -> Self {
+        self.policies = policies;
+        self
+    }
+
     /// Get the configured authentication store.
     ///
     /// If not set, the global store is used.
@@ -138,7 +241,9 @@ impl Middleware for AuthMiddleware {
     /// The discovered credentials must have the requested username to be used.
     ///
     /// - Check the cache (realm key) for a password
+    /// - Check the uv credentials file for a password
     /// - Check the netrc for a password
+    /// - Check the credential helper for a password
     /// - Check the keyring for a password
     /// - Perform the request
     /// - Add the username and password to the cache if successful
@@ -155,15 +260,38 @@ impl Middleware for AuthMiddleware {
     /// - Perform the request
     /// - On 401, 403, or 404 check for authentication if there was a cache miss
     ///     - Check the cache (realm key) for the username and password
+    ///     - Check the uv credentials file for a username and password
     ///     - Check the netrc for a username and password
+    ///     - Check the credential helper for a username and password
     ///     - Perform the request again if found
     ///     - Add the username and password to the cache if successful
+    ///
+    /// ## If the index has an authentication policy
+    ///
+    /// If the request targets an index with `authenticate = "never"`, any credentials are removed
+    /// from the request and no look-ups are performed. If the index has `authenticate = "always"`,
+    /// we never send an unauthenticated request, as if `only_authenticated` were set.
     async fn handle(
         &self,
         mut request: Request,
         extensions: &mut Extensions,
         next: Next<'_>,
     ) -> reqwest_middleware::Result<Response> {
+        let policy = self.policies.get(request.url()).unwrap_or_default();
+
+        if policy.authenticate == AuthPolicy::Never {
+            trace!(
+                "Request for {} is configured to never authenticate",
+                request.url()
+            );
+            request.headers_mut().remove(reqwest::header::AUTHORIZATION);
+            let _ = request.url_mut().set_username("");
+            let _ = request.url_mut().set_password(None);
+            return next.run(request, extensions).await;
+        }
+        let only_authenticated =
+            self.only_authenticated || policy.authenticate == AuthPolicy::Always;
+
         // Check for credentials attached to the request already
         let credentials = Credentials::from_request(&request);
 
@@ -199,12 +327,18 @@ impl Middleware for AuthMiddleware {
                 request = credentials.authenticate(request);
                 // Do not insert already-cached credentials
                 None
-            } else if let Some(credentials) = self
-                .fetch_credentials(Some(&credentials), request.url())
+            } else if let Some((credentials, source)) = self
+                .fetch_credentials(
+                    Some(&credentials),
+                    request.url(),
+                    policy.credential_provider,
+                )
                 .await
             {
                 request = credentials.authenticate(request);
-                Some(credentials)
+                return self
+                    .complete_fetched_request(credentials, source, request, extensions, next)
+                    .await;
             } else {
                 // If we don't find a password, we'll still attempt the request with the existing credentials
                 Some(credentials)
@@ -237,7 +371,7 @@ impl Middleware for AuthMiddleware {
             .as_ref()
             .is_some_and(|credentials| credentials.username().is_some());
 
-        let (mut retry_request, response) = if self.only_authenticated {
+        let (mut retry_request, response) = if only_authenticated {
             // For endpoints where we require the user to provide credentials, we don't try the
             // unauthenticated request first.
             trace!("Checking for credentials for {url}");
@@ -301,14 +435,18 @@ impl Middleware for AuthMiddleware {
 
         // Then, fetch from external services.
         // Here, we use the username from the cache if present.
-        if let Some(credentials) = self
-            .fetch_credentials(credentials.as_deref(), retry_request.url())
+        if let Some((credentials, source)) = self
+            .fetch_credentials(
+                credentials.as_deref(),
+                retry_request.url(),
+                policy.credential_provider,
+            )
             .await
         {
             retry_request = credentials.authenticate(retry_request);
             trace!("Retrying request for {url} with {credentials:?}");
             return self
-                .complete_request(Some(credentials), retry_request, extensions, next)
+                .complete_fetched_request(credentials, source, retry_request, extensions, next)
                 .await;
         }
 
@@ -363,14 +501,70 @@ impl AuthMiddleware {
         result
     }
 
+    /// Run a request authenticated with credentials fetched from an external source to completion.
+    ///
+    /// Like [`AuthMiddleware::complete_request`], but if the credentials were supplied by the
+    /// credential helper, also reports the outcome to the helper, which may store accepted
+    /// credentials and erase rejected ones.
+    async fn complete_fetched_request(
+        &self,
+        credentials: Arc<Credentials>,
+        source: CredentialSource,
+        request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let url = request.url().clone();
+        let result = self
+            .complete_request(Some(credentials.clone()), request, extensions, next)
+            .await;
+
+        if let Some(helper) = self
+            .credential_helper
+            .get()
+            .filter(|_| source == CredentialSource::Helper)
+        {
+            match result.as_ref().map(Response::status) {
+                Ok(status) if status.is_success() => helper.store(&url, &credentials).await,
+                Ok(StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) => {
+                    helper.erase(&url, &credentials).await;
+                }
+                _ => {}
+            }
+        }
+
+        result
+    }
+
+    /// Find the credentials that would be used to authenticate a request to the given URL.
+    ///
+    /// Searches the uv credentials file, the netrc file, the credential helper, and the keyring, in
+    /// that order, without sending any requests. Respects the [`crate::IndexPolicy`] registered for the
+    /// URL, if any.
+    pub async fn find_credentials(&self, url: &Url, username: Option<&str>) -> Option<Credentials> {
+        let policy = self.policies.get(url).unwrap_or_default();
+        if policy.authenticate == AuthPolicy::Never {
+            trace!("Authentication is disabled for {url}");
+            return None;
+        }
+        let credentials =
+            username.map(|username| Credentials::new(Some(username.to_string()), None));
+        self.fetch_credentials(credentials.as_ref(), url, policy.credential_provider)
+            .await
+            .map(|(credentials, _)| Arc::unwrap_or_clone(credentials))
+    }
+
     /// Fetch credentials for a URL.
     ///
-    /// Supports netrc file and keyring lookups.
+    /// Supports lookups in the uv credentials file, along with netrc file, credential helper, and
+    /// keyring lookups, as permitted by the [`CredentialProvider`]. Returns the credentials along
+    /// with where they were found.
     async fn fetch_credentials(
         &self,
         credentials: Option<&Credentials>,
         url: &Url,
-    ) -> Option<Arc<Credentials>> {
+        provider: CredentialProvider,
+    ) -> Option<(Arc<Credentials>, CredentialSource)> {
         // Fetches can be expensive, so we will only run them _once_ per realm and username combination
         // All other requests for the same realm will wait until the first one completes
         let key = (
@@ -395,28 +589,51 @@ impl AuthMiddleware {
                 trace!("Skipping fetch of credentials for {url}, previous attempt failed");
             };
 
-            return credentials;
+            return credentials.map(|credentials| (credentials, CredentialSource::PreviousFetch));
         }
 
-        // Netrc support based on: <https://github.com/gribouille/netrc>.
-        let credentials = if let Some(credentials) = self.netrc.get().and_then(|netrc| {
-            debug!("Checking netrc for credentials for {url}");
-            Credentials::from_netrc(
-                netrc,
-                url,
-                credentials
-                    .as_ref()
-                    .and_then(|credentials| credentials.username()),
-            )
+        let username = credentials.and_then(|credentials| credentials.username());
+
+        // Credentials stored via `uv auth login`.
+        let credentials = if let Some(credentials) = self.text_store.get().and_then(|store| {
+            debug!("Checking uv credentials file for credentials for {url}");
+            store.get(url, username)
         }) {
+            debug!("Found credentials in uv credentials file for {url}");
+            Some((credentials, CredentialSource::TextStore))
+        // Netrc support based on: <https://github.com/gribouille/netrc>.
+        } else if let Some(credentials) =
+            self.netrc
+                .get()
+                .filter(|_| provider.netrc())
+                .and_then(|netrc| {
+                    debug!("Checking netrc for credentials for {url}");
+                    Credentials::from_netrc(
+                        netrc,
+                        url,
+                        credentials
+                            .as_ref()
+                            .and_then(|credentials| credentials.username()),
+                    )
+                })
+        {
             debug!("Found credentials in netrc file for {url}");
-            Some(credentials)
+            Some((credentials, CredentialSource::Netrc))
         // N.B. The keyring provider performs lookups for the exact URL then
         //      falls back to the host, but we cache the result per realm so if a keyring
         //      implementation returns different credentials for different URLs in the
         //      same realm we will use the wrong credentials.
+        } else if let Some(credentials) = match self.credential_helper.get() {
+            Some(helper) if provider.helper() => {
+                debug!("Checking credential helper for credentials for {url}");
+                helper.get(url, username).await
+            }
+            _ => None,
+        } {
+            debug!("Found credentials from credential helper for {url}");
+            Some((credentials, CredentialSource::Helper))
         } else if let Some(credentials) = match self.keyring {
-            Some(ref keyring) => {
+            Some(ref keyring) if provider.keyring() => {
                 if let Some(username) = credentials.and_then(|credentials| credentials.username()) {
                     debug!("Checking keyring for credentials for {username}@{url}");
                     keyring.fetch(url, username).await
@@ -425,22 +642,43 @@ impl AuthMiddleware {
                     None
                 }
             }
-            None => None,
+            _ => None,
         } {
             debug!("Found credentials in keyring for {url}");
-            Some(credentials)
+            Some((credentials, CredentialSource::Keyring))
         } else {
             None
         }
-        .map(Arc::new);
+        .map(|(credentials, source)| (Arc::new(credentials), source));
 
         // Register the fetch for this key
-        self.cache().fetches.done(key.clone(), credentials.clone());
+        self.cache().fetches.done(
+            key.clone(),
+            credentials
+                .as_ref()
+                .map(|(credentials, _)| credentials.clone()),
+        );
 
         credentials
     }
 }
 
+/// Where credentials fetched by [`AuthMiddleware::fetch_credentials`] were found.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum CredentialSource {
+    /// A concurrent fetch for the same realm and username, which reports to the credential
+    /// helper on its own.
+    PreviousFetch,
+    /// The uv credentials file.
+    TextStore,
+    /// The netrc file.
+    Netrc,
+    /// The credential helper.
+    Helper,
+    /// The keyring.
+    Keyring,
+}
+
 fn tracing_url(request: &Request, credentials: Option<&Credentials>) -> String {
     if tracing::enabled!(tracing::Level::DEBUG) {
         let mut url = request.url().clone();
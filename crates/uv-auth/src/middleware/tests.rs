@@ -9,6 +9,7 @@ use wiremock::matchers::{basic_auth, method, path_regex};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 use super::*;
+use crate::IndexPolicy;
 
 type Error = Box<dyn std::error::Error>;
 
@@ -1077,3 +1078,234 @@ async fn test_credentials_from_keyring_mixed_authentication_in_realm_same_userna
 
     Ok(())
 }
+
+#[test(tokio::test)]
+async fn test_policy_never_authenticate() -> Result<(), Error> {
+    let username = "user";
+    let password = "password";
+    let server = start_test_server(username, password).await;
+    let base_url = Url::parse(&server.uri())?;
+
+    let mut netrc_file = NamedTempFile::new()?;
+    writeln!(netrc_file, "default login {username} password {password}")?;
+
+    let mut policies = AuthPolicies::default();
+    policies.insert(
+        base_url.clone(),
+        IndexPolicy {
+            authenticate: AuthPolicy::Never,
+            ..IndexPolicy::default()
+        },
+    );
+
+    let client = test_client_builder()
+        .with(
+            AuthMiddleware::new()
+                .with_cache(CredentialsCache::new())
+                .with_netrc(Netrc::from_file(netrc_file.path()).ok())
+                .with_policies(policies),
+        )
+        .build();
+
+    assert_eq!(
+        client.get(server.uri()).send().await?.status(),
+        401,
+        "Credentials should not be pulled from the netrc file"
+    );
+
+    let mut url = base_url.clone();
+    url.set_username(username).unwrap();
+    url.set_password(Some(password)).unwrap();
+    assert_eq!(
+        client.get(url).send().await?.status(),
+        401,
+        "Credentials in the URL should be stripped"
+    );
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn test_policy_keyring_credential_provider() -> Result<(), Error> {
+    let username = "user";
+    let password = "password";
+    let server = start_test_server(username, password).await;
+    let base_url = Url::parse(&server.uri())?;
+
+    let mut netrc_file = NamedTempFile::new()?;
+    writeln!(netrc_file, "default login {username} password {password}")?;
+
+    let mut policies = AuthPolicies::default();
+    policies.insert(
+        base_url.clone(),
+        IndexPolicy {
+            credential_provider: CredentialProvider::Keyring,
+            ..IndexPolicy::default()
+        },
+    );
+
+    let client = test_client_builder()
+        .with(
+            AuthMiddleware::new()
+                .with_cache(CredentialsCache::new())
+                .with_netrc(Netrc::from_file(netrc_file.path()).ok())
+                .with_policies(policies),
+        )
+        .build();
+
+    assert_eq!(
+        client.get(server.uri()).send().await?.status(),
+        401,
+        "Credentials should not be pulled from the netrc file"
+    );
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn test_text_store() -> Result<(), Error> {
+    let username = "user";
+    let password = "password";
+    let server = start_test_server(username, password).await;
+    let base_url = Url::parse(&server.uri())?;
+
+    let mut store = TextCredentialStore::default();
+    store.insert(
+        base_url.clone(),
+        Some(username.to_string()),
+        password.to_string(),
+    );
+
+    let client = test_client_builder()
+        .with(
+            AuthMiddleware::new()
+                .with_cache(CredentialsCache::new())
+                .with_netrc(None)
+                .with_text_store(Some(store)),
+        )
+        .build();
+
+    assert_eq!(
+        client.get(server.uri()).send().await?.status(),
+        200,
+        "Credentials should be pulled from the credentials file"
+    );
+
+    let mut url = base_url.clone();
+    url.set_username(username).unwrap();
+    url.set_password(Some("invalid")).unwrap();
+    assert_eq!(
+        client.get(url).send().await?.status(),
+        401,
+        "Credentials in the URL should take precedence and fail"
+    );
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test(tokio::test)]
+async fn test_credential_helper() -> Result<(), Error> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let username = "user";
+    let password = "password";
+    let server = start_test_server(username, password).await;
+
+    // A helper that provides credentials, and records any other actions.
+    let dir = tempfile::tempdir()?;
+    let log = dir.path().join("log");
+    let script = dir.path().join("helper");
+    fs_err::write(
+        &script,
+        format!(
+            "#!/bin/sh\nif [ \"$1\" = \"get\" ]; then\nprintf 'username={username}\\npassword={password}\\n'\nelse\necho \"action=$1\" >> '{log}'\nfi\n",
+            log = log.display(),
+        ),
+    )?;
+    fs_err::set_permissions(&script, std::fs::Permissions::from_mode(0o755))?;
+
+    let cache = CredentialsCache::new();
+    let client = test_client_builder()
+        .with(
+            AuthMiddleware::new()
+                .with_cache(cache)
+                .with_netrc(None)
+                .with_text_store(None)
+                .with_credential_helper(CredentialHelper::new(&script.display().to_string()).ok()),
+        )
+        .build();
+
+    assert_eq!(
+        client.get(server.uri()).send().await?.status(),
+        200,
+        "Credentials should be pulled from the credential helper"
+    );
+    assert_eq!(
+        fs_err::read_to_string(&log)?.trim(),
+        "action=store",
+        "Accepted credentials should be reported to the helper"
+    );
+
+    // The credentials are cached for the realm, so the helper is not invoked again.
+    fs_err::remove_file(&script)?;
+    assert_eq!(
+        client
+            .get(format!("{}/foo", server.uri()))
+            .send()
+            .await?
+            .status(),
+        200,
+        "Credentials should be cached for the realm"
+    );
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test(tokio::test)]
+async fn test_credential_helper_other_source() -> Result<(), Error> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let username = "user";
+    let password = "password";
+    let server = start_test_server(username, password).await;
+
+    let mut netrc_file = NamedTempFile::new()?;
+    writeln!(netrc_file, "default login {username} password {password}")?;
+
+    // A helper that has no credentials, and records any other actions.
+    let dir = tempfile::tempdir()?;
+    let log = dir.path().join("log");
+    let script = dir.path().join("helper");
+    fs_err::write(
+        &script,
+        format!(
+            "#!/bin/sh\nif [ \"$1\" != \"get\" ]; then\necho \"action=$1\" >> '{log}'\nfi\n",
+            log = log.display(),
+        ),
+    )?;
+    fs_err::set_permissions(&script, std::fs::Permissions::from_mode(0o755))?;
+
+    let client = test_client_builder()
+        .with(
+            AuthMiddleware::new()
+                .with_cache(CredentialsCache::new())
+                .with_netrc(Netrc::from_file(netrc_file.path()).ok())
+                .with_text_store(None)
+                .with_credential_helper(CredentialHelper::new(&script.display().to_string()).ok()),
+        )
+        .build();
+
+    assert_eq!(
+        client.get(server.uri()).send().await?.status(),
+        200,
+        "Credentials should be pulled from the netrc file"
+    );
+    assert!(
+        !log.exists(),
+        "Credentials from other sources should not be reported to the helper"
+    );
+
+    Ok(())
+}
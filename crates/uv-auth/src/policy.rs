@@ -0,0 +1,102 @@
+use url::Url;
+
+use crate::realm::Realm;
+
+/// When to authenticate requests to an index.
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AuthPolicy {
+    /// Send an unauthenticated request first, and only search for credentials if the index
+    /// responds with an authentication error (e.g., `401 Unauthorized`).
+    #[default]
+    Auto,
+    /// Always authenticate requests to the index, and fail if no credentials can be found.
+    Always,
+    /// Never authenticate requests to the index, even if credentials are available.
+    Never,
+}
+
+/// Where to search for credentials for an index, in addition to those provided via the URL, the
+/// environment, or `uv auth login`.
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum CredentialProvider {
+    /// Search the netrc file, followed by the credential helper and the keyring (if enabled).
+    #[default]
+    Auto,
+    /// Only search the netrc file.
+    Netrc,
+    /// Only search the keyring (if enabled).
+    Keyring,
+    /// Only query the credential helper (if configured via `UV_CREDENTIAL_HELPER`).
+    Helper,
+}
+
+impl CredentialProvider {
+    /// Returns `true` if the netrc file should be searched for credentials.
+    pub(crate) fn netrc(self) -> bool {
+        matches!(self, Self::Auto | Self::Netrc)
+    }
+
+    /// Returns `true` if the credential helper should be queried for credentials.
+    pub(crate) fn helper(self) -> bool {
+        matches!(self, Self::Auto | Self::Helper)
+    }
+
+    /// Returns `true` if the keyring should be searched for credentials.
+    pub(crate) fn keyring(self) -> bool {
+        matches!(self, Self::Auto | Self::Keyring)
+    }
+}
+
+/// The authentication settings for a single index.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct IndexPolicy {
+    pub authenticate: AuthPolicy,
+    pub credential_provider: CredentialProvider,
+}
+
+/// A set of per-index authentication policies, keyed by index URL.
+#[derive(Debug, Clone, Default)]
+pub struct AuthPolicies(Vec<(Url, IndexPolicy)>);
+
+impl AuthPolicies {
+    /// Register the [`IndexPolicy`] for the index at the given [`Url`].
+    pub fn insert(&mut self, url: Url, policy: IndexPolicy) {
+        self.0.push((url, policy));
+    }
+
+    /// Returns `true` if no policies have been registered.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Return the [`IndexPolicy`] that applies to a request for the given [`Url`], if any.
+    ///
+    /// Policies are scoped to the realm (scheme, host, and port) of the index, such that they
+    /// also apply to files hosted alongside the index. If multiple indexes share a realm, the
+    /// index with the longest matching path prefix takes precedence, followed by the first-defined
+    /// index.
+    pub fn get(&self, url: &Url) -> Option<IndexPolicy> {
+        let realm = Realm::from(url);
+        let candidates = || {
+            self.0
+                .iter()
+                .filter(|(index, _)| Realm::from(index) == realm)
+        };
+        candidates()
+            .filter(|(index, _)| url.path().starts_with(index.path()))
+            .max_by_key(|(index, _)| index.path().len())
+            .or_else(|| candidates().next())
+            .map(|(_, policy)| *policy)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,57 @@
+use url::{ParseError, Url};
+
+use super::*;
+
+fn policy(authenticate: AuthPolicy) -> IndexPolicy {
+    IndexPolicy {
+        authenticate,
+        credential_provider: CredentialProvider::Auto,
+    }
+}
+
+#[test]
+fn test_get_policy() -> Result<(), ParseError> {
+    let mut policies = AuthPolicies::default();
+    policies.insert(
+        Url::parse("https://example.com/simple/")?,
+        policy(AuthPolicy::Always),
+    );
+    policies.insert(
+        Url::parse("https://example.com/private/simple/")?,
+        policy(AuthPolicy::Never),
+    );
+
+    // The longest matching path prefix wins.
+    assert_eq!(
+        policies.get(&Url::parse("https://example.com/private/simple/flask/")?),
+        Some(policy(AuthPolicy::Never))
+    );
+    assert_eq!(
+        policies.get(&Url::parse("https://example.com/simple/flask/")?),
+        Some(policy(AuthPolicy::Always))
+    );
+
+    // Files in the same realm fall back to the first-defined index.
+    assert_eq!(
+        policies.get(&Url::parse(
+            "https://example.com/packages/flask-3.0.0-py3-none-any.whl"
+        )?),
+        Some(policy(AuthPolicy::Always))
+    );
+
+    // Other realms are unaffected.
+    assert_eq!(
+        policies.get(&Url::parse("https://example.com:8080/simple/flask/")?),
+        None
+    );
+    assert_eq!(
+        policies.get(&Url::parse("http://example.com/simple/flask/")?),
+        None
+    );
+    assert_eq!(
+        policies.get(&Url::parse("https://files.example.com/simple/flask/")?),
+        None
+    );
+
+    Ok(())
+}
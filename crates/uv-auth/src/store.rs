@@ -0,0 +1,169 @@
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use url::Url;
+
+use uv_fs::Simplified;
+use uv_state::{StateBucket, StateStore};
+use uv_static::EnvVars;
+
+use crate::credentials::Credentials;
+use crate::realm::Realm;
+
+#[derive(Debug, thiserror::Error)]
+pub enum TextStoreError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error("Failed to parse credentials file: {}", _0.user_display())]
+    Parse(PathBuf, #[source] toml::de::Error),
+    #[error("Failed to serialize credentials")]
+    Serialize(#[from] toml::ser::Error),
+}
+
+/// A plaintext store of credentials managed by `uv auth`.
+///
+/// The store is persisted as a TOML file in the uv state directory (or `UV_CREDENTIALS_DIR`),
+/// with one entry per service URL and username.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextCredentialStore {
+    #[serde(default, rename = "credential", skip_serializing_if = "Vec::is_empty")]
+    credentials: Vec<StoredCredential>,
+}
+
+/// A single entry in the [`TextCredentialStore`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct StoredCredential {
+    /// The URL of the service, e.g., an index URL.
+    service: Url,
+    /// The username to authenticate with, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    /// The password or token to authenticate with.
+    password: String,
+}
+
+impl TextCredentialStore {
+    /// Return the path to the default credentials file.
+    ///
+    /// Prefers `UV_CREDENTIALS_DIR`, falling back to the `credentials` bucket of the uv state
+    /// directory.
+    pub fn default_path() -> Result<PathBuf, io::Error> {
+        let directory = if let Some(directory) = std::env::var_os(EnvVars::UV_CREDENTIALS_DIR) {
+            PathBuf::from(directory)
+        } else {
+            StateStore::from_settings(None)?.bucket(StateBucket::Credentials)
+        };
+        Ok(directory.join("credentials.toml"))
+    }
+
+    /// Read the store from the given path.
+    ///
+    /// Returns an empty store if the file does not exist.
+    pub fn read(path: &Path) -> Result<Self, TextStoreError> {
+        let contents = match fs_err::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                debug!("No credentials file found at: {}", path.user_display());
+                return Ok(Self::default());
+            }
+            Err(err) => return Err(err.into()),
+        };
+        toml::from_str(&contents).map_err(|err| TextStoreError::Parse(path.to_path_buf(), err))
+    }
+
+    /// Write the store to the given path, creating the parent directory if necessary.
+    pub fn write(&self, path: &Path) -> Result<(), TextStoreError> {
+        if let Some(parent) = path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        let contents = toml::to_string(self)?;
+
+        // The file contains plaintext secrets, so it's created readable by the current user only
+        // (the `tempfile` default of `0o600`) _before_ any secrets are written to it, rather than
+        // via `uv_fs::write_atomic_sync`, which widens the permissions to `0o666`.
+        let mut file = tempfile::Builder::new()
+            .tempfile_in(path.parent().expect("Credentials path must have a parent"))?;
+        file.write_all(contents.as_bytes())?;
+        file.persist(path).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::Other,
+                format!(
+                    "Failed to persist temporary file to {}: {}",
+                    path.user_display(),
+                    err.error
+                ),
+            )
+        })?;
+
+        Ok(())
+    }
+
+    /// Return the credentials for a request to the given [`Url`], if any.
+    ///
+    /// Entries must share the realm (scheme, host, and port) of the URL, and the URL path must
+    /// start with the service path. If multiple entries match, the entry with the longest service
+    /// path takes precedence. If a username is provided, only entries for that username match.
+    pub fn get(&self, url: &Url, username: Option<&str>) -> Option<Credentials> {
+        let realm = Realm::from(url);
+        self.credentials
+            .iter()
+            .filter(|entry| Realm::from(&entry.service) == realm)
+            .filter(|entry| is_path_prefix(entry.service.path(), url.path()))
+            .filter(|entry| {
+                username.map_or(true, |username| entry.username.as_deref() == Some(username))
+            })
+            .max_by_key(|entry| entry.service.path().len())
+            .map(|entry| Credentials::new(entry.username.clone(), Some(entry.password.clone())))
+    }
+
+    /// Store the credentials for the given service, replacing any existing entry for the same
+    /// service and username.
+    pub fn insert(&mut self, service: Url, username: Option<String>, password: String) {
+        let service = normalize_service(service);
+        self.credentials
+            .retain(|entry| !(entry.service == service && entry.username == username));
+        self.credentials.push(StoredCredential {
+            service,
+            username,
+            password,
+        });
+    }
+
+    /// Remove the credentials for the given service.
+    ///
+    /// If a username is provided, only the entry for that username is removed.
+    ///
+    /// Returns `true` if any entries were removed.
+    pub fn remove(&mut self, service: &Url, username: Option<&str>) -> bool {
+        let service = normalize_service(service.clone());
+        let len = self.credentials.len();
+        self.credentials.retain(|entry| {
+            !(entry.service == service
+                && username.map_or(true, |username| entry.username.as_deref() == Some(username)))
+        });
+        self.credentials.len() != len
+    }
+}
+
+/// Returns `true` if `prefix` is a prefix of `path`, on a path segment boundary.
+fn is_path_prefix(prefix: &str, path: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    path.strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Remove any credentials, query, and fragment from a service URL, such that entries are keyed
+/// consistently.
+fn normalize_service(mut service: Url) -> Url {
+    let _ = service.set_username("");
+    let _ = service.set_password(None);
+    service.set_query(None);
+    service.set_fragment(None);
+    service
+}
+
+#[cfg(test)]
+mod tests;
@@ -2,7 +2,7 @@ use std::io;
 use std::path::{Path, PathBuf};
 use uv_static::EnvVars;
 
-use crate::Cache;
+use crate::{Cache, CacheSize};
 use clap::Parser;
 use tracing::{debug, warn};
 
@@ -29,6 +29,14 @@ pub struct CacheArgs {
     /// To view the location of the cache directory, run `uv cache dir`.
     #[arg(global = true, long, env = EnvVars::UV_CACHE_DIR)]
     pub cache_dir: Option<PathBuf>,
+
+    /// The maximum size of the cached environments used by `uv run --with` and `uv tool run`.
+    ///
+    /// Accepts a number of bytes, or a size with a unit suffix (e.g., `500MiB` or `10GB`). When
+    /// the cached environments exceed this size, the least-recently-used environments are evicted,
+    /// excluding any that are in use by another uv process.
+    #[arg(global = true, long, env = EnvVars::UV_MAX_CACHE_SIZE, value_name = "SIZE")]
+    pub max_cache_size: Option<CacheSize>,
 }
 
 impl Cache {
@@ -82,7 +90,8 @@ impl TryFrom<CacheArgs> for Cache {
     type Error = io::Error;
 
     fn try_from(value: CacheArgs) -> Result<Self, Self::Error> {
-        Cache::from_settings(value.no_cache, value.cache_dir)
+        Ok(Cache::from_settings(value.no_cache, value.cache_dir)?
+            .with_max_size(value.max_cache_size))
     }
 }
 
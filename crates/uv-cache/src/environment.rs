@@ -0,0 +1,280 @@
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use tracing::debug;
+
+use uv_fs::LockedFile;
+
+use crate::{rm_rf, Cache, CacheBucket, CacheEntry, CacheSize, Removal};
+
+/// A lease on an environment in the [`CacheBucket::Environments`] bucket.
+///
+/// While the lease is held, the environment won't be removed by `uv cache prune` or evicted to
+/// satisfy the maximum cache size.
+#[derive(Debug)]
+pub struct EnvironmentLease {
+    _lock: LockedFile,
+}
+
+/// An environment in the [`CacheBucket::Environments`] bucket.
+#[derive(Debug)]
+struct EnvironmentEntry {
+    /// The symlink from the environment's content-addressed location to its archive.
+    link: PathBuf,
+    /// The lock file used to lease the environment.
+    lock: PathBuf,
+    /// The last time at which the environment was leased.
+    last_used: SystemTime,
+}
+
+impl Cache {
+    /// Lease the cached environment at the given [`CacheEntry`], recording its last-used time.
+    ///
+    /// The lease should be acquired before reading or creating the environment, and held for as
+    /// long as the environment is in use.
+    pub async fn lease_environment(&self, entry: &CacheEntry) -> io::Result<EnvironmentLease> {
+        fs_err::create_dir_all(entry.dir())?;
+
+        // Hold the bucket lock while acquiring the lease, to avoid racing with an eviction that
+        // has already determined that the environment is unused.
+        let _bucket = LockedFile::acquire_shared(self.environments_lock(), "environments").await?;
+        let lock =
+            LockedFile::acquire_shared(lock_path(entry.path()), entry.path().display().to_string())
+                .await?;
+
+        // Record the last-used time on the lock file itself.
+        lock.file().file().set_modified(SystemTime::now())?;
+
+        Ok(EnvironmentLease { _lock: lock })
+    }
+
+    /// Remove any cached environments that haven't been used within the given duration, or all
+    /// cached environments if no duration is provided.
+    ///
+    /// Environments that are in use by another process are retained.
+    pub fn prune_environments(&self, older_than: Option<Duration>) -> io::Result<Removal> {
+        let mut summary = Removal::default();
+        if !self.bucket(CacheBucket::Environments).is_dir() {
+            return Ok(summary);
+        }
+
+        let _lock = LockedFile::acquire_blocking(self.environments_lock(), "environments")?;
+
+        let now = SystemTime::now();
+        for environment in self.environments()? {
+            if let Some(older_than) = older_than {
+                let age = now
+                    .duration_since(environment.last_used)
+                    .unwrap_or_default();
+                if age < older_than {
+                    continue;
+                }
+            }
+            if let Some(removal) = evict(&environment, true)? {
+                debug!(
+                    "Removing unused cache environment: {}",
+                    environment.link.display()
+                );
+                summary += removal;
+            }
+        }
+
+        if let Some(max_size) = self.max_size {
+            summary += self.evict_to_size(max_size)?;
+        }
+
+        summary += self.remove_empty_environment_shards()?;
+
+        Ok(summary)
+    }
+
+    /// Evict the least-recently-used cached environments until the total size of the
+    /// [`CacheBucket::Environments`] bucket falls under the configured maximum cache size.
+    pub async fn enforce_max_size(&self) -> io::Result<Removal> {
+        let Some(max_size) = self.max_size else {
+            return Ok(Removal::default());
+        };
+        if !self.bucket(CacheBucket::Environments).is_dir() {
+            return Ok(Removal::default());
+        }
+
+        let _lock = LockedFile::acquire(self.environments_lock(), "environments").await?;
+
+        let mut summary = self.evict_to_size(max_size)?;
+        summary += self.remove_empty_environment_shards()?;
+        Ok(summary)
+    }
+
+    /// Remove the symlinks for any cached environments that aren't in use by another process,
+    /// leaving the archives in place.
+    pub(crate) fn prune_environment_links(&self) -> io::Result<Removal> {
+        let mut summary = Removal::default();
+        if !self.bucket(CacheBucket::Environments).is_dir() {
+            return Ok(summary);
+        }
+
+        let _lock = LockedFile::acquire_blocking(self.environments_lock(), "environments")?;
+
+        for environment in self.environments()? {
+            if let Some(removal) = evict(&environment, false)? {
+                debug!(
+                    "Removing dangling cache environment: {}",
+                    environment.link.display()
+                );
+                summary += removal;
+            }
+        }
+
+        summary += self.remove_empty_environment_shards()?;
+
+        Ok(summary)
+    }
+
+    /// Evict the least-recently-used environments until the bucket fits within `max_size`.
+    ///
+    /// Assumes that the exclusive bucket lock is held.
+    fn evict_to_size(&self, max_size: CacheSize) -> io::Result<Removal> {
+        let mut summary = Removal::default();
+
+        let mut environments = self
+            .environments()?
+            .into_iter()
+            .map(|environment| {
+                let size = fs_err::canonicalize(&environment.link)
+                    .map(|target| directory_size(&target))
+                    .unwrap_or(0);
+                (environment, size)
+            })
+            .collect::<Vec<_>>();
+
+        let mut total = environments.iter().map(|(_, size)| size).sum::<u64>();
+        if total <= max_size.bytes() {
+            return Ok(summary);
+        }
+
+        debug!(
+            "Cached environments use {total} bytes, exceeding the maximum cache size of {max_size}"
+        );
+
+        environments.sort_by_key(|(environment, _)| environment.last_used);
+        for (environment, size) in environments {
+            if total <= max_size.bytes() {
+                break;
+            }
+            if let Some(removal) = evict(&environment, true)? {
+                debug!(
+                    "Evicted least-recently-used cache environment: {}",
+                    environment.link.display()
+                );
+                total = total.saturating_sub(size);
+                summary += removal;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Return all environments in the [`CacheBucket::Environments`] bucket.
+    fn environments(&self) -> io::Result<Vec<EnvironmentEntry>> {
+        let mut environments = Vec::new();
+        for shard in fs_err::read_dir(self.bucket(CacheBucket::Environments))? {
+            let shard = shard?;
+            if !shard.file_type()?.is_dir() {
+                continue;
+            }
+            for entry in fs_err::read_dir(shard.path())? {
+                let entry = entry?;
+                let link = entry.path();
+                if link.extension().is_some_and(|ext| ext == "lock") {
+                    continue;
+                }
+                let lock = lock_path(&link);
+
+                // Environments created before leases were introduced won't have a lock file, so
+                // fall back to the creation time of the symlink.
+                let last_used = fs_err::metadata(&lock)
+                    .or_else(|_| fs_err::symlink_metadata(&link))
+                    .and_then(|metadata| metadata.modified())?;
+
+                environments.push(EnvironmentEntry {
+                    link,
+                    lock,
+                    last_used,
+                });
+            }
+        }
+        Ok(environments)
+    }
+
+    /// Remove any interpreter directories in the [`CacheBucket::Environments`] bucket that no
+    /// longer contain any environments.
+    fn remove_empty_environment_shards(&self) -> io::Result<Removal> {
+        let mut summary = Removal::default();
+        for shard in fs_err::read_dir(self.bucket(CacheBucket::Environments))? {
+            let shard = shard?;
+            if !shard.file_type()?.is_dir() {
+                continue;
+            }
+            if fs_err::read_dir(shard.path())?.next().is_none() {
+                summary += rm_rf(shard.path())?;
+            }
+        }
+        Ok(summary)
+    }
+
+    /// The path to the lock file that guards the [`CacheBucket::Environments`] bucket.
+    fn environments_lock(&self) -> PathBuf {
+        self.bucket(CacheBucket::Environments).join(".lock")
+    }
+}
+
+/// Remove a cached environment, if it isn't leased by another process.
+///
+/// If `archive` is `true`, the archive to which the environment is linked is removed as well.
+/// Otherwise, it's left in place to be removed as a dangling archive.
+///
+/// Assumes that the exclusive bucket lock is held.
+fn evict(environment: &EnvironmentEntry, archive: bool) -> io::Result<Option<Removal>> {
+    let Some(lock) = LockedFile::try_acquire(&environment.lock, environment.link.display())? else {
+        debug!(
+            "Skipping cache environment in use by another process: {}",
+            environment.link.display()
+        );
+        return Ok(None);
+    };
+
+    let target = fs_err::canonicalize(&environment.link).ok();
+    let mut summary = rm_rf(&environment.link)?;
+    if archive {
+        if let Some(target) = target {
+            summary += rm_rf(target)?;
+        }
+    }
+
+    // Release the lease before removing the lock file, which can't be removed while open on
+    // Windows.
+    drop(lock);
+    summary += rm_rf(&environment.lock)?;
+
+    Ok(Some(summary))
+}
+
+/// Return the path to the lock file for the environment at the given path.
+fn lock_path(link: &Path) -> PathBuf {
+    let mut path = OsString::from(link.as_os_str());
+    path.push(".lock");
+    PathBuf::from(path)
+}
+
+/// Return the total size of the files in a directory, in bytes.
+fn directory_size(path: &Path) -> u64 {
+    walkdir::WalkDir::new(path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
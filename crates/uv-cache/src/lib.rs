@@ -19,7 +19,9 @@ use uv_pypi_types::ResolutionMetadata;
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::environment::EnvironmentLease;
 pub use crate::removal::{rm_rf, Removal};
+pub use crate::size::{CacheSize, CacheSizeError};
 pub use crate::wheel::WheelCache;
 use crate::wheel::WheelCacheKind;
 
@@ -27,7 +29,9 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod environment;
 mod removal;
+mod size;
 mod wheel;
 
 /// A [`CacheEntry`] which may or may not exist yet.
@@ -119,6 +123,9 @@ pub struct Cache {
     root: PathBuf,
     /// The refresh strategy to use when reading from the cache.
     refresh: Refresh,
+    /// The maximum size of the cached environments, beyond which the least-recently-used
+    /// environments are evicted.
+    max_size: Option<CacheSize>,
     /// A temporary cache directory, if the user requested `--no-cache`.
     ///
     /// Included to ensure that the temporary directory exists for the length of the operation, but
@@ -132,6 +139,7 @@ impl Cache {
         Self {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
+            max_size: None,
             temp_dir: None,
         }
     }
@@ -142,6 +150,7 @@ impl Cache {
         Ok(Self {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
+            max_size: None,
             temp_dir: Some(Arc::new(temp_dir)),
         })
     }
@@ -152,6 +161,12 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the maximum size of the cached environments.
+    #[must_use]
+    pub fn with_max_size(self, max_size: Option<CacheSize>) -> Self {
+        Self { max_size, ..self }
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
@@ -419,20 +434,9 @@ impl Cache {
             }
         }
 
-        // Second, remove any cached environments. These are never referenced by symlinks, so we can
-        // remove them directly.
-        match fs_err::read_dir(self.bucket(CacheBucket::Environments)) {
-            Ok(entries) => {
-                for entry in entries {
-                    let entry = entry?;
-                    let path = fs_err::canonicalize(entry.path())?;
-                    debug!("Removing dangling cache environment: {}", path.display());
-                    summary += rm_rf(path)?;
-                }
-            }
-            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
-            Err(err) => return Err(err),
-        }
+        // Second, remove any cached environments that aren't in use by another process. The
+        // underlying archives are no longer referenced, so they're removed below.
+        summary += self.prune_environment_links()?;
 
         // Third, if enabled, remove all unzipped wheels, leaving only the wheel archives.
         if ci {
@@ -767,7 +771,7 @@ impl CacheBucket {
             Self::SourceDistributions => "sdists-v5",
             Self::FlatIndex => "flat-index-v1",
             Self::Git => "git-v0",
-            Self::Interpreter => "interpreter-v2",
+            Self::Interpreter => "interpreter-v3",
             // Note that when bumping this, you'll also need to bump it
             // in crates/uv/tests/cache_clean.rs.
             Self::Simple => "simple-v13",
@@ -0,0 +1,121 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A size limit for the cache, in bytes.
+///
+/// Parsed from a plain number of bytes (e.g., `1073741824`), or from a number with a unit suffix
+/// (e.g., `512MiB`, `10GB`, or `1.5G`). Single-letter suffixes (`K`, `M`, `G`, `T`) and `*iB`
+/// suffixes use powers of 1024, while `*B` suffixes use powers of 1000.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CacheSize(u64);
+
+impl CacheSize {
+    /// Create a [`CacheSize`] from a number of bytes.
+    pub fn from_bytes(bytes: u64) -> Self {
+        Self(bytes)
+    }
+
+    /// Return the size in bytes.
+    pub fn bytes(self) -> u64 {
+        self.0
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CacheSizeError {
+    #[error("Cache size must be a number of bytes with an optional unit (e.g., `500MiB` or `10GB`), but received: `{0}`")]
+    Invalid(String),
+    #[error("Unknown cache size unit `{unit}` in `{size}` (expected one of: `B`, `K`, `KB`, `KiB`, `M`, `MB`, `MiB`, `G`, `GB`, `GiB`, `T`, `TB`, `TiB`)")]
+    UnknownUnit { size: String, unit: String },
+}
+
+impl FromStr for CacheSize {
+    type Err = CacheSizeError;
+
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let size = s.trim();
+        let split = size
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(size.len());
+        let (number, unit) = size.split_at(split);
+
+        let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" | "kib" => 1 << 10,
+            "m" | "mib" => 1 << 20,
+            "g" | "gib" => 1 << 30,
+            "t" | "tib" => 1 << 40,
+            "kb" => 1_000,
+            "mb" => 1_000_000,
+            "gb" => 1_000_000_000,
+            "tb" => 1_000_000_000_000,
+            _ => {
+                return Err(CacheSizeError::UnknownUnit {
+                    size: s.to_string(),
+                    unit: unit.trim().to_string(),
+                })
+            }
+        };
+
+        if let Ok(number) = number.parse::<u64>() {
+            return number
+                .checked_mul(multiplier)
+                .map(Self)
+                .ok_or_else(|| CacheSizeError::Invalid(s.to_string()));
+        }
+
+        let number = number
+            .parse::<f64>()
+            .map_err(|_| CacheSizeError::Invalid(s.to_string()))?;
+        let bytes = (number * multiplier as f64).round();
+        if !bytes.is_finite() || bytes < 0.0 || bytes > u64::MAX as f64 {
+            return Err(CacheSizeError::Invalid(s.to_string()));
+        }
+
+        Ok(Self(bytes as u64))
+    }
+}
+
+impl Display for CacheSize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}B", self.0)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CacheSize {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl serde::de::Visitor<'_> for Visitor {
+            type Value = CacheSize;
+
+            fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+                f.write_str("a number of bytes, or a string like `500MiB` or `10GB`")
+            }
+
+            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(CacheSize(v))
+            }
+
+            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                u64::try_from(v)
+                    .map(CacheSize)
+                    .map_err(|_| E::custom("cache size must be non-negative"))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                CacheSize::from_str(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
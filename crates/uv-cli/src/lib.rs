@@ -1,7 +1,9 @@
 use std::ffi::OsString;
+use std::num::NonZeroUsize;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::{anyhow, Result};
 use clap::builder::styling::{AnsiColor, Effects, Style};
@@ -26,6 +28,9 @@ pub mod compat;
 pub mod options;
 pub mod version;
 
+/// The platform-specific separator for lists of paths, e.g., in `PATH`.
+const PATH_DELIMITER: char = if cfg!(windows) { ';' } else { ':' };
+
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
 pub enum VersionFormat {
     /// Display the version as plain text.
@@ -34,6 +39,15 @@ pub enum VersionFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum ProfileFormat {
+    /// Write a summary of the resolution as JSON.
+    #[default]
+    Json,
+    /// Write a trace in the Chrome trace event format, viewable in `chrome://tracing` or Perfetto.
+    ChromeTrace,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -391,6 +405,18 @@ pub enum Commands {
     Build(BuildArgs),
     /// Upload distributions to an index.
     Publish(PublishArgs),
+    /// Manage package indexes.
+    #[command(
+        after_help = "Use `uv help index` for more details.",
+        after_long_help = ""
+    )]
+    Index(IndexNamespace),
+    /// Manage authentication.
+    #[command(
+        after_help = "Use `uv help auth` for more details.",
+        after_long_help = ""
+    )]
+    Auth(AuthNamespace),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -500,6 +526,211 @@ pub enum CacheCommand {
     Dir,
 }
 
+#[derive(Args)]
+pub struct IndexNamespace {
+    #[command(subcommand)]
+    pub command: IndexCommand,
+}
+
+#[derive(Subcommand)]
+pub enum IndexCommand {
+    /// Download the distributions referenced by the project's lockfile into a local index.
+    ///
+    /// Every wheel and source distribution that `uv.lock` references from a package index is
+    /// downloaded into the output directory, and verified against the hashes recorded in the
+    /// lockfile.
+    ///
+    /// By default, the output directory is laid out as a PEP 503 simple index, which can be used
+    /// with `--index-url file:///path/to/mirror`. With `--format flat`, the distributions are
+    /// written to a single directory, which can be used with `--find-links /path/to/mirror`.
+    ///
+    /// Distributions that are already present in the output directory with a matching hash are
+    /// not downloaded again.
+    Mirror(IndexMirrorArgs),
+}
+
+#[derive(Args)]
+pub struct AuthNamespace {
+    #[command(subcommand)]
+    pub command: AuthCommand,
+}
+
+#[derive(Subcommand)]
+pub enum AuthCommand {
+    /// Store credentials for a service.
+    ///
+    /// By default, credentials are stored in a uv-managed credentials file in the uv state
+    /// directory, and used for any request to the service (and any URL below it). With
+    /// `--keyring-provider`, credentials are stored in the keyring instead.
+    Login(AuthLoginArgs),
+    /// Remove the stored credentials for a service.
+    Logout(AuthLogoutArgs),
+    /// Show the password or token that uv would use to authenticate requests to a service.
+    ///
+    /// Searches the uv credentials file, the netrc file, and the keyring (if enabled), in that
+    /// order.
+    ///
+    /// If the service matches a configured index, the index's `authenticate` and
+    /// `credential-provider` settings are respected.
+    Token(AuthTokenArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct AuthLoginArgs {
+    /// The service to store credentials for.
+    ///
+    /// Either the name of an index defined in the project configuration (e.g., `internal`), or a
+    /// URL (e.g., `https://pypi.example.com/simple`).
+    pub service: String,
+
+    /// The username to store.
+    #[arg(short, long)]
+    pub username: Option<String>,
+
+    /// The password to store.
+    ///
+    /// If not provided, uv will prompt for the password.
+    #[arg(short, long)]
+    pub password: Option<String>,
+
+    /// The token to store.
+    ///
+    /// Using a token is equivalent to passing `__token__` as `--username` and the token as
+    /// `--password`.
+    #[arg(short, long, conflicts_with = "username", conflicts_with = "password")]
+    pub token: Option<String>,
+
+    /// Store the credentials in the keyring, rather than the uv credentials file.
+    ///
+    /// Use `--keyring-provider subprocess` to store credentials with the `keyring` CLI, or
+    /// `--keyring-provider native` to store credentials in the operating system's credential
+    /// store.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
+#[derive(Args, Debug)]
+pub struct AuthLogoutArgs {
+    /// The service to remove credentials for.
+    ///
+    /// Either the name of an index defined in the project configuration, or a URL.
+    pub service: String,
+
+    /// The username to remove credentials for.
+    ///
+    /// If not provided, credentials for all usernames are removed from the uv credentials file.
+    /// Required when removing credentials from the keyring.
+    #[arg(short, long)]
+    pub username: Option<String>,
+
+    /// Remove the credentials from the keyring, rather than the uv credentials file.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
+#[derive(Args, Debug)]
+pub struct AuthTokenArgs {
+    /// The service to show credentials for.
+    ///
+    /// Either the name of an index defined in the project configuration, or a URL.
+    pub service: String,
+
+    /// The username to show credentials for.
+    #[arg(short, long)]
+    pub username: Option<String>,
+
+    /// Search the keyring for credentials, in addition to the uv credentials file and the netrc
+    /// file.
+    ///
+    /// Keyring lookups require a `--username`.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PublishFormat {
+    /// Display the summary of the uploads as a human-readable table.
+    #[default]
+    Text,
+    /// Display the summary of the uploads as JSON.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PythonListFormat {
+    /// Display the list of Python installations in a human-readable table.
+    #[default]
+    Text,
+    /// Display the list of Python installations as JSON.
+    Json,
+}
+
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum MirrorFormat {
+    /// Lay out the index as a PEP 503 simple index, with one directory per package.
+    #[default]
+    Simple,
+    /// Write all distributions to a single directory, for use with `--find-links`.
+    Flat,
+}
+
+#[derive(Args, Debug)]
+pub struct IndexMirrorArgs {
+    /// The directory to write the mirror to.
+    pub output_dir: PathBuf,
+
+    /// The layout of the mirror.
+    #[arg(long, value_enum, default_value_t = MirrorFormat::default())]
+    pub format: MirrorFormat,
+
+    /// Require a hash for every mirrored distribution.
+    ///
+    /// By default, distributions without a hash in the `uv.lock` are mirrored with a warning, and
+    /// their SHA-256 digest is computed and recorded in the generated index pages. With
+    /// `--require-hashes`, uv will instead refuse to mirror any distribution without a hash.
+    #[arg(
+        long,
+        env = EnvVars::UV_REQUIRE_HASHES,
+        value_parser = clap::builder::BoolishValueParser::new(),
+    )]
+    pub require_hashes: bool,
+
+    /// Attempt to use `keyring` for authentication for index URLs.
+    ///
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to read credentials from the operating
+    /// system's credential store (e.g., the macOS Keychain or the Secret Service on Linux).
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+
+    /// Allow insecure connections to a host.
+    ///
+    /// Can be provided multiple times.
+    ///
+    /// Expects to receive either a hostname (e.g., `localhost`), a host-port pair (e.g.,
+    /// `localhost:8080`), or a URL (e.g., `https://localhost`).
+    ///
+    /// WARNING: Hosts included in this list will not be verified against the system's certificate
+    /// store. Only use `--allow-insecure-host` in a secure network with verified sources, as it
+    /// bypasses SSL verification and could expose you to MITM attacks.
+    #[arg(
+        long,
+        alias = "trusted-host",
+        env = EnvVars::UV_INSECURE_HOST,
+        value_delimiter = ' ',
+        value_parser = parse_insecure_host,
+    )]
+    pub allow_insecure_host: Option<Vec<Maybe<TrustedHost>>>,
+}
+
 #[derive(Args, Debug)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CleanArgs {
@@ -525,6 +756,19 @@ pub struct PruneArgs {
     /// that were built from source.
     #[arg(long)]
     pub ci: bool,
+
+    /// Only prune the cached environments used by `uv run --with` and `uv tool run` (i.e.,
+    /// `uvx`).
+    ///
+    /// Unlike a full prune, the rest of the cache is left untouched. Environments that are in use
+    /// by another uv process are never removed.
+    #[arg(long, conflicts_with = "ci")]
+    pub environments: bool,
+
+    /// Only prune cached environments that haven't been used within the given duration (e.g.,
+    /// `7d`, `12h`, or `30m`).
+    #[arg(long, requires = "environments", value_parser = parse_duration, value_name = "DURATION")]
+    pub older_than: Option<Duration>,
 }
 
 #[derive(Args)]
@@ -715,6 +959,26 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Sync(SyncArgs),
+    /// Launch a shell with the project environment activated.
+    ///
+    /// The project environment is created and updated before the shell is launched, as with
+    /// `uv run`.
+    ///
+    /// The user's shell is detected from the environment (e.g., via the `SHELL` environment
+    /// variable). Bash, Zsh, Ksh, Fish, Nushell, Csh, `PowerShell`, and Command Prompt are supported.
+    /// The environment's activation script is sourced in the shell, which prefixes the shell's
+    /// prompt with the name of the environment.
+    ///
+    /// Exit the shell to return to the original environment. The exit code of the shell is
+    /// propagated.
+    ///
+    /// uv will search for a project in the current directory or any parent directory. If a project
+    /// cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help shell` for more details.",
+        after_long_help = ""
+    )]
+    Shell(ShellArgs),
     /// Update the project's lockfile.
     ///
     /// If the project lockfile (`uv.lock`) does not exist, it will be created.
@@ -889,6 +1153,34 @@ fn parse_maybe_file_path(input: &str) -> Result<Maybe<PathBuf>, String> {
     }
 }
 
+/// Parse a duration, like `30s`, `15m`, `12h`, `7d`, or `2w`.
+fn parse_duration(input: &str) -> Result<Duration, String> {
+    let input = input.trim();
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (number, unit) = input.split_at(split);
+    let number = number
+        .parse::<u64>()
+        .map_err(|_| format!("invalid duration `{input}` (expected, e.g., `7d` or `12h`)"))?;
+    let seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 60 * 60 * 24,
+        "w" => 60 * 60 * 24 * 7,
+        _ => {
+            return Err(format!(
+                "invalid duration unit in `{input}` (expected one of: `s`, `m`, `h`, `d`, `w`)"
+            ))
+        }
+    };
+    number
+        .checked_mul(seconds)
+        .map(Duration::from_secs)
+        .ok_or_else(|| format!("duration `{input}` is too large"))
+}
+
 // Parse a string, mapping the empty string to `None`.
 #[allow(clippy::unnecessary_wraps)]
 fn parse_maybe_string(input: &str) -> Result<Maybe<String>, String> {
@@ -1761,8 +2053,9 @@ pub struct PipUninstallArgs {
 
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to read credentials from the operating
+    /// system's credential store (e.g., the macOS Keychain or the Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -2282,6 +2575,20 @@ pub struct VenvArgs {
     #[arg(long)]
     pub seed: bool,
 
+    /// Install the given packages into the virtual environment.
+    ///
+    /// The packages are resolved and installed as with `uv pip install`. If `--seed` is also
+    /// provided, the seed packages are included in the same resolution.
+    #[arg(long, value_delimiter = ',')]
+    pub with: Vec<String>,
+
+    /// Install all packages listed in the given `requirements.txt` files into the virtual
+    /// environment.
+    ///
+    /// The same semantics as `--with` apply.
+    #[arg(long, value_delimiter = ',', value_parser = parse_maybe_file_path)]
+    pub seed_with: Vec<Maybe<PathBuf>>,
+
     /// Preserve any existing files or directories at the target path.
     ///
     /// By default, `uv venv` will remove an existing virtual environment at the given path, and
@@ -2339,6 +2646,17 @@ pub struct VenvArgs {
     #[arg(long)]
     pub relocatable: bool,
 
+    /// Copy the managed Python installation into the virtual environment.
+    ///
+    /// The interpreter is copied into the `python` directory of the virtual environment, and the
+    /// environment is made relocatable, with its `python` executable symlinked to the bundled
+    /// interpreter via a relative path. The resulting environment is self-contained, and can be
+    /// moved to (e.g.) a container image or another machine with the same platform.
+    ///
+    /// Requires a Python installation managed by uv. Not supported on Windows.
+    #[arg(long, help_heading = "Python options")]
+    pub bundle_python: bool,
+
     #[command(flatten)]
     pub index_args: IndexArgs,
 
@@ -2353,8 +2671,9 @@ pub struct VenvArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to read credentials from the operating
+    /// system's credential store (e.g., the macOS Keychain or the Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -2687,6 +3006,18 @@ pub struct RunArgs {
     #[arg(long, value_delimiter = ',', value_parser = parse_maybe_file_path)]
     pub with_requirements: Vec<Maybe<PathBuf>>,
 
+    /// Load environment variables from a `.env` file.
+    ///
+    /// The file is parsed with dotenv syntax, and the variables it defines are set in the
+    /// environment of the command. Variables that are already set in the environment take
+    /// precedence over those in the file.
+    ///
+    /// May be provided more than once, in which case later files take precedence over earlier
+    /// ones. When set via `UV_ENV_FILE`, multiple files may be separated by the platform's path
+    /// separator (`:` on Unix, `;` on Windows).
+    #[arg(long, env = EnvVars::UV_ENV_FILE, value_delimiter = PATH_DELIMITER)]
+    pub env_file: Vec<PathBuf>,
+
     /// Run the command in an isolated virtual environment.
     ///
     /// Usually, the project environment is reused for performance. This option
@@ -2941,6 +3272,145 @@ pub struct SyncArgs {
         value_parser = parse_maybe_string,
     )]
     pub python: Option<Maybe<String>>,
+
+    /// Copy the managed Python installation into the project environment.
+    ///
+    /// The interpreter is copied into `.venv/python`, and the environment is made relocatable,
+    /// with its `python` executable symlinked to the bundled interpreter via a relative path. The
+    /// resulting environment is self-contained, and can be moved to (e.g.) a container image or
+    /// another machine with the same platform.
+    ///
+    /// Requires that the project environment is based on a Python installation managed by uv. Not
+    /// supported on Windows.
+    #[arg(long, help_heading = "Python options")]
+    pub bundle_python: bool,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ShellArgs {
+    /// Include optional dependencies from the specified extra name.
+    ///
+    /// May be provided more than once.
+    ///
+    /// Note that all optional dependencies are always included in the resolution; this option only
+    /// affects the selection of packages to install.
+    #[arg(long, conflicts_with = "all_extras", value_parser = extra_name_with_clap_error)]
+    pub extra: Option<Vec<ExtraName>>,
+
+    /// Include all optional dependencies.
+    ///
+    /// Note that all optional dependencies are always included in the resolution; this option only
+    /// affects the selection of packages to install.
+    #[arg(long, conflicts_with = "extra")]
+    pub all_extras: bool,
+
+    #[arg(long, overrides_with("all_extras"), hide = true)]
+    pub no_all_extras: bool,
+
+    /// Include the development dependency group.
+    ///
+    /// This option is an alias for `--group dev`.
+    #[arg(long, overrides_with("no_dev"), hide = true)]
+    pub dev: bool,
+
+    /// Omit the development dependency group.
+    ///
+    /// This option is an alias for `--no-group dev`.
+    #[arg(long, overrides_with("dev"))]
+    pub no_dev: bool,
+
+    /// Only include the development dependency group.
+    ///
+    /// Omit other dependencies. The project itself will also be omitted.
+    ///
+    /// This option is an alias for `--only-group dev`.
+    #[arg(long, conflicts_with("no_dev"))]
+    pub only_dev: bool,
+
+    /// Include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long, conflicts_with("only_group"))]
+    pub group: Vec<GroupName>,
+
+    /// Exclude dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    #[arg(long)]
+    pub no_group: Vec<GroupName>,
+
+    /// Only include dependencies from the specified dependency group.
+    ///
+    /// May be provided multiple times.
+    ///
+    /// The project itself will also be omitted.
+    #[arg(long, conflicts_with("group"))]
+    pub only_group: Vec<GroupName>,
+
+    /// Install any editable dependencies, including the project and any workspace members, as
+    /// non-editable.
+    #[arg(long)]
+    pub no_editable: bool,
+
+    /// Avoid syncing the virtual environment.
+    ///
+    /// Implies `--frozen`, as the project dependencies will be ignored (i.e., the lockfile will not
+    /// be updated, since the environment will not be synced regardless).
+    ///
+    /// The project environment must already exist.
+    #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub no_sync: bool,
+
+    /// Assert that the `uv.lock` will remain unchanged.
+    ///
+    /// Requires that the lockfile is up-to-date. If the lockfile is missing or
+    /// needs to be updated, uv will exit with an error.
+    #[arg(long, env = EnvVars::UV_LOCKED, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub locked: bool,
+
+    /// Sync without updating the `uv.lock` file.
+    ///
+    /// Instead of checking if the lockfile is up-to-date, uses the versions in
+    /// the lockfile as the source of truth. If the lockfile is missing, uv will
+    /// exit with an error.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Activate the environment for a specific package in the workspace.
+    ///
+    /// The workspace's environment (`.venv`) is updated to reflect the subset
+    /// of dependencies declared by the specified workspace member package.
+    ///
+    /// If the workspace member does not exist, uv will exit with an error.
+    #[arg(long)]
+    pub package: Option<PackageName>,
+
+    /// The Python interpreter to use for the project environment.
+    ///
+    /// By default, the first interpreter that meets the project's
+    /// `requires-python` constraint is used.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
 }
 
 #[derive(Args)]
@@ -2964,6 +3434,21 @@ pub struct LockArgs {
     #[arg(long, conflicts_with = "frozen", conflicts_with = "locked")]
     pub dry_run: bool,
 
+    /// Write a profile of the resolution to the given path.
+    ///
+    /// The profile includes the number of decisions and backtracks for each package, the forks
+    /// created during resolution, and the time spent waiting on metadata, prefetching versions,
+    /// and building source distributions.
+    ///
+    /// If the lockfile is already up-to-date, no resolution is performed, and no profile is
+    /// written; use `--upgrade` to force a resolution.
+    #[arg(long, value_name = "PATH", conflicts_with = "frozen")]
+    pub profile_resolution: Option<PathBuf>,
+
+    /// The format in which to write the resolution profile.
+    #[arg(long, value_enum, default_value_t = ProfileFormat::default(), requires = "profile_resolution")]
+    pub profile_format: ProfileFormat,
+
     #[command(flatten)]
     pub resolver: ResolverArgs,
 
@@ -3537,6 +4022,24 @@ pub enum ToolCommand {
     /// installation, it will continue to be respected in upgrades.
     #[command(alias = "update")]
     Upgrade(ToolUpgradeArgs),
+    /// Install, upgrade, and uninstall tools to match a tool manifest.
+    ///
+    /// The manifest, `uv-tools.toml` in the current directory by default, declares the tools to
+    /// install in a `[tools]` table, with the same options as `uv tool install`:
+    ///
+    /// ```toml
+    /// [tools.ruff]
+    /// from = "ruff==0.6.9"
+    ///
+    /// [tools.pre-commit]
+    /// with = ["pre-commit-uv"]
+    /// python = "3.12"
+    /// ```
+    ///
+    /// Tools that are missing or whose requirements differ from the manifest are
+    /// (re)installed. Installed tools that are not listed in the manifest are
+    /// uninstalled.
+    Sync(ToolSyncArgs),
     /// List installed tools.
     List(ToolListArgs),
     /// Uninstall a tool.
@@ -3599,6 +4102,51 @@ pub struct ToolRunArgs {
     #[arg(long, value_delimiter = ',', value_parser = parse_maybe_file_path)]
     pub with_requirements: Vec<Maybe<PathBuf>>,
 
+    /// Constrain versions using the given requirements files.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. However, including a package in a constraints file will _not_
+    /// trigger the installation of that package.
+    ///
+    /// This is equivalent to pip's `--constraint` option.
+    #[arg(long = "constraints", alias = "constraint", short, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub constraints: Vec<Maybe<PathBuf>>,
+
+    /// Override versions using the given requirements files.
+    ///
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be installed, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
+    ///
+    /// While constraints are _additive_, in that they're combined with the requirements of the
+    /// constituent packages, overrides are _absolute_, in that they completely replace the
+    /// requirements of the constituent packages.
+    #[arg(long = "overrides", alias = "override", value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub overrides: Vec<Maybe<PathBuf>>,
+
+    /// Run the version of the tool that's locked in the project in the current directory.
+    ///
+    /// If the tool is present in the project's `uv.lock` (e.g., as a development dependency), the
+    /// locked version of the tool and its dependencies are installed into an ephemeral environment,
+    /// without syncing the project itself. If the tool isn't locked, it's resolved as usual.
+    #[arg(
+        long,
+        conflicts_with_all = ["with", "with_editable", "with_requirements", "constraints", "overrides"]
+    )]
+    pub from_project: bool,
+
+    /// Load environment variables from a `.env` file.
+    ///
+    /// The file is parsed with dotenv syntax, and the variables it defines are set in the
+    /// environment of the tool. Variables that are already set in the environment take precedence
+    /// over those in the file.
+    ///
+    /// May be provided more than once, in which case later files take precedence over earlier
+    /// ones. When set via `UV_ENV_FILE`, multiple files may be separated by the platform's path
+    /// separator (`:` on Unix, `;` on Windows).
+    #[arg(long, env = EnvVars::UV_ENV_FILE, value_delimiter = PATH_DELIMITER)]
+    pub env_file: Vec<PathBuf>,
+
     /// Run the tool in an isolated virtual environment, ignoring any already-installed tools.
     #[arg(long)]
     pub isolated: bool,
@@ -3659,10 +4207,38 @@ pub struct ToolInstallArgs {
     #[arg(long, value_delimiter = ',')]
     pub with: Vec<String>,
 
+    /// Install the executables of the given packages, in addition to those of the tool package.
+    ///
+    /// The packages must be included in the tool environment, e.g., via `--with`.
+    #[arg(long, value_delimiter = ',')]
+    pub with_executables_from: Vec<PackageName>,
+
     /// Run all requirements listed in the given `requirements.txt` files.
     #[arg(long, value_delimiter = ',', value_parser = parse_maybe_file_path)]
     pub with_requirements: Vec<Maybe<PathBuf>>,
 
+    /// Constrain versions using the given requirements files.
+    ///
+    /// Constraints files are `requirements.txt`-like files that only control the _version_ of a
+    /// requirement that's installed. However, including a package in a constraints file will _not_
+    /// trigger the installation of that package.
+    ///
+    /// This is equivalent to pip's `--constraint` option.
+    #[arg(long = "constraints", alias = "constraint", short, value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub constraints: Vec<Maybe<PathBuf>>,
+
+    /// Override versions using the given requirements files.
+    ///
+    /// Overrides files are `requirements.txt`-like files that force a specific version of a
+    /// requirement to be installed, regardless of the requirements declared by any constituent
+    /// package, and regardless of whether this would be considered an invalid resolution.
+    ///
+    /// While constraints are _additive_, in that they're combined with the requirements of the
+    /// constituent packages, overrides are _absolute_, in that they completely replace the
+    /// requirements of the constituent packages.
+    #[arg(long = "overrides", alias = "override", value_delimiter = ' ', value_parser = parse_maybe_file_path)]
+    pub overrides: Vec<Maybe<PathBuf>>,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -3678,6 +4254,14 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub force: bool,
 
+    /// Assert that the tool's lockfile will remain unchanged.
+    ///
+    /// The tool environment is re-created with the exact versions from the `uv.lock` next to the
+    /// tool's receipt. If the lockfile is missing or needs to be updated, uv will exit with an
+    /// error.
+    #[arg(long)]
+    pub locked: bool,
+
     /// The Python interpreter to use to build the tool environment.
     ///
     /// See `uv help python` for details on Python discovery and supported
@@ -3693,6 +4277,40 @@ pub struct ToolInstallArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ToolSyncArgs {
+    /// The path to the tool manifest.
+    ///
+    /// Defaults to `uv-tools.toml` in the current directory.
+    #[arg(long, value_parser = parse_file_path)]
+    pub manifest: Option<PathBuf>,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use to build the tool environments that don't declare a Python
+    /// version in the manifest.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct ToolListArgs {
@@ -3816,6 +4434,18 @@ pub enum PythonCommand {
     /// See `uv help python` to view supported request formats.
     Install(PythonInstallArgs),
 
+    /// Upgrade managed Python versions to the latest patch release.
+    ///
+    /// For each requested minor version, e.g., `3.12`, the latest available patch release is
+    /// installed. If no versions are provided, all managed Python installations are upgraded.
+    ///
+    /// Outdated patch releases are removed, unless they are still used by a tool environment, the
+    /// virtual environment of the current project, or the active virtual environment. Use `--keep`
+    /// to retain all outdated patch releases.
+    ///
+    /// See `uv help python` to view supported request formats.
+    Upgrade(PythonUpgradeArgs),
+
     /// Search for a Python installation.
     ///
     /// Displays the path to the Python executable.
@@ -3868,6 +4498,10 @@ pub struct PythonListArgs {
     /// By default, available downloads for the current platform are shown.
     #[arg(long)]
     pub only_installed: bool,
+
+    /// Select the output format.
+    #[arg(long, value_enum, default_value_t = PythonListFormat::default())]
+    pub output_format: PythonListFormat,
 }
 
 #[derive(Args)]
@@ -3909,6 +4543,22 @@ pub struct PythonInstallArgs {
     pub reinstall: bool,
 }
 
+#[derive(Args)]
+pub struct PythonUpgradeArgs {
+    /// The Python version(s) to upgrade.
+    ///
+    /// If not provided, all managed Python installations are upgraded.
+    ///
+    /// See `uv help python` to view supported request formats.
+    pub targets: Vec<String>,
+
+    /// Keep the outdated patch releases after upgrading.
+    ///
+    /// By default, outdated patch releases are removed unless they are still in use.
+    #[arg(long)]
+    pub keep: bool,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PythonUninstallArgs {
@@ -4079,7 +4729,9 @@ pub struct IndexArgs {
     /// indexes.
     ///
     /// If a path, the target must be a directory that contains packages as wheel files (`.whl`) or
-    /// source distributions (e.g., `.tar.gz` or `.zip`) at the top level.
+    /// source distributions (e.g., `.tar.gz` or `.zip`) at the top level. The directory may also
+    /// include a PEP 691 `index.json` that provides metadata for those files, such as their hashes,
+    /// upload times, `requires-python`, and yanked status.
     ///
     /// If a URL, the page must contain a flat list of links to package files adhering to the
     /// formats described above.
@@ -4213,8 +4865,9 @@ pub struct InstallerArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to read credentials from the operating
+    /// system's credential store (e.g., the macOS Keychain or the Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -4375,8 +5028,9 @@ pub struct ResolverArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to read credentials from the operating
+    /// system's credential store (e.g., the macOS Keychain or the Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -4567,8 +5221,9 @@ pub struct ResolverInstallerArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to read credentials from the operating
+    /// system's credential store (e.g., the macOS Keychain or the Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -4759,10 +5414,25 @@ pub struct PublishArgs {
     /// Note that there are typically different URLs for index access (e.g., `https:://.../simple`)
     /// and index upload.
     ///
+    /// To publish to a static index instead, use a `file://` URL for a local directory, or an
+    /// `s3://bucket/prefix` URL for an S3-compatible bucket, with the credentials in the standard
+    /// `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY` environment variables. See `--layout`.
+    ///
     /// Defaults to PyPI's publish URL (<https://upload.pypi.org/legacy/>).
     #[arg(long, env = EnvVars::UV_PUBLISH_URL)]
     pub publish_url: Option<Url>,
 
+    /// The name of an index in the configuration to publish to.
+    ///
+    /// Uploads to the `publish-url` of the index, and applies the index's authentication and
+    /// TLS settings to the upload. Unless `--check-url` is provided, the index URL is also used to
+    /// check for existing files.
+    ///
+    /// The index must be defined via `[[tool.uv.index]]` with a matching `name` and a
+    /// `publish-url`.
+    #[arg(long, conflicts_with = "publish_url")]
+    pub index: Option<String>,
+
     /// The username for the upload.
     #[arg(short, long, env = EnvVars::UV_PUBLISH_USERNAME)]
     pub username: Option<String>,
@@ -4794,8 +5464,9 @@ pub struct PublishArgs {
 
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to read credentials from the operating
+    /// system's credential store (e.g., the macOS Keychain or the Secret Service on Linux).
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -4835,6 +5506,43 @@ pub struct PublishArgs {
     /// The index must provide one of the supported hashes (SHA-256, SHA-384, or SHA-512).
     #[arg(long,env = EnvVars::UV_PUBLISH_CHECK_URL)]
     pub check_url: Option<IndexUrl>,
+
+    /// A directory containing pre-made attestations to upload alongside each file (PEP 740).
+    ///
+    /// The attestation for a file is read from `<filename>.publish.attestation` in the directory,
+    /// e.g., `dist/foo-1.0.0-py3-none-any.whl.publish.attestation`. Files without an attestation
+    /// are uploaded without one.
+    #[arg(long, env = EnvVars::UV_PUBLISH_ATTESTATIONS)]
+    pub attestations: Option<PathBuf>,
+
+    /// The maximum number of files to upload concurrently.
+    ///
+    /// Defaults to 1, i.e., files are uploaded one after the other.
+    #[arg(long, env = EnvVars::UV_CONCURRENT_UPLOADS)]
+    pub concurrent_uploads: Option<NonZeroUsize>,
+
+    /// Perform a dry run, i.e., perform all checks (including `--check-url`) and report which
+    /// files would be uploaded, without uploading anything.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// The format of the summary of the uploads.
+    ///
+    /// With `json`, the summary is written to stdout.
+    #[arg(long, value_enum, default_value_t = PublishFormat::default())]
+    pub format: PublishFormat,
+
+    /// The layout of the index when publishing to a local directory (`file://`) or an
+    /// S3-compatible bucket (`s3://`).
+    ///
+    /// The distributions are copied into the index, and its index pages are regenerated to
+    /// include both the new and the existing distributions.
+    ///
+    /// Distributions that already exist in the index are skipped if they're identical, and are
+    /// an error otherwise. In S3 buckets, existing objects are compared using the SHA-256
+    /// checksum stored on upload or, for objects uploaded by other tools, their MD5 `ETag`.
+    #[arg(long, value_enum, default_value_t = MirrorFormat::default())]
+    pub layout: MirrorFormat,
 }
 
 /// See [PEP 517](https://peps.python.org/pep-0517/) and
@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use reqwest::{Client, ClientBuilder, Response};
+use reqwest::{Certificate, Client, ClientBuilder, Response};
 use reqwest_middleware::ClientWithMiddleware;
 use reqwest_retry::policies::ExponentialBackoff;
 use reqwest_retry::{
@@ -7,13 +7,14 @@ use reqwest_retry::{
 };
 use std::error::Error;
 use std::fmt::Debug;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use std::{env, iter};
 use tracing::debug;
 use url::Url;
-use uv_auth::AuthMiddleware;
+use uv_auth::{AuthMiddleware, AuthPolicies};
 use uv_configuration::{KeyringProviderType, TrustedHost};
+use uv_distribution_types::Index;
 use uv_fs::Simplified;
 use uv_pep508::MarkerEnvironment;
 use uv_platform_tags::Platform;
@@ -54,6 +55,12 @@ pub struct BaseClientBuilder<'a> {
     platform: Option<&'a Platform>,
     auth_integration: AuthIntegration,
     default_timeout: Duration,
+    /// The per-index authentication policies.
+    auth_policies: AuthPolicies,
+    /// Hosts that are trusted to use the insecure client, as configured on individual indexes.
+    index_insecure_hosts: Vec<TrustedHost>,
+    /// Additional certificate bundles to trust for individual indexes.
+    index_certificates: Vec<(Url, PathBuf)>,
 }
 
 impl Default for BaseClientBuilder<'_> {
@@ -75,6 +82,9 @@ impl BaseClientBuilder<'_> {
             platform: None,
             auth_integration: AuthIntegration::default(),
             default_timeout: Duration::from_secs(30),
+            auth_policies: AuthPolicies::default(),
+            index_insecure_hosts: vec![],
+            index_certificates: vec![],
         }
     }
 }
@@ -98,6 +108,30 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Apply the per-index settings (e.g., authentication policies and TLS configuration) of the
+    /// given indexes.
+    #[must_use]
+    pub fn indexes<'b>(mut self, indexes: impl IntoIterator<Item = &'b Index>) -> Self {
+        for index in indexes {
+            let url = index.raw_url();
+            self.auth_policies.insert(url.clone(), index.auth_policy());
+            if index.allow_insecure {
+                if let Some(host) = url.host_str() {
+                    self.index_insecure_hosts.push(TrustedHost::Host {
+                        scheme: Some(url.scheme().to_string()),
+                        host: host.to_string(),
+                        port: url.port(),
+                    });
+                }
+            }
+            if let Some(ca_bundle) = index.ca_bundle.as_ref() {
+                self.index_certificates
+                    .push((url.clone(), ca_bundle.clone()));
+            }
+        }
+        self
+    }
+
     #[must_use]
     pub fn retries(mut self, retries: u32) -> Self {
         self.retries = retries;
@@ -191,6 +225,7 @@ impl<'a> BaseClientBuilder<'a> {
             timeout,
             ssl_cert_file_exists,
             Security::Secure,
+            &[],
         );
 
         // Create an insecure client that accepts invalid certificates.
@@ -199,19 +234,48 @@ impl<'a> BaseClientBuilder<'a> {
             timeout,
             ssl_cert_file_exists,
             Security::Insecure,
+            &[],
         );
 
+        // Create a secure client for each index with a custom certificate bundle.
+        let raw_index_clients = self
+            .index_certificates
+            .iter()
+            .filter_map(|(url, ca_bundle)| {
+                let certificates = match read_certificates(ca_bundle) {
+                    Ok(certificates) => certificates,
+                    Err(err) => {
+                        warn_user_once!(
+                            "Ignoring invalid `ca-bundle` for index {url}: {err}",
+                            url = url.cyan()
+                        );
+                        return None;
+                    }
+                };
+                let client = self.create_client(
+                    &user_agent_string,
+                    timeout,
+                    ssl_cert_file_exists,
+                    Security::Secure,
+                    &certificates,
+                );
+                Some((url.clone(), client))
+            })
+            .collect::<Vec<_>>();
+
         // Wrap in any relevant middleware and handle connectivity.
         let client = self.apply_middleware(raw_client.clone());
         let dangerous_client = self.apply_middleware(raw_dangerous_client.clone());
+        let index_clients = self.apply_index_middleware(raw_index_clients);
 
         BaseClient {
             connectivity: self.connectivity,
-            allow_insecure_host: self.allow_insecure_host.clone(),
+            allow_insecure_host: self.insecure_hosts(),
             client,
             raw_client,
             dangerous_client,
             raw_dangerous_client,
+            index_clients,
             timeout,
         }
     }
@@ -221,24 +285,55 @@ impl<'a> BaseClientBuilder<'a> {
         // Wrap in any relevant middleware and handle connectivity.
         let client = self.apply_middleware(existing.raw_client.clone());
         let dangerous_client = self.apply_middleware(existing.raw_dangerous_client.clone());
+        let index_clients = self.apply_index_middleware(
+            existing
+                .index_clients
+                .iter()
+                .map(|index_client| (index_client.url.clone(), index_client.raw_client.clone()))
+                .collect(),
+        );
 
         BaseClient {
             connectivity: self.connectivity,
-            allow_insecure_host: self.allow_insecure_host.clone(),
+            allow_insecure_host: self.insecure_hosts(),
             client,
             dangerous_client,
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
+            index_clients,
             timeout: existing.timeout,
         }
     }
 
+    /// The hosts that are trusted to use the insecure client, including those configured on
+    /// individual indexes.
+    fn insecure_hosts(&self) -> Vec<TrustedHost> {
+        self.allow_insecure_host
+            .iter()
+            .chain(&self.index_insecure_hosts)
+            .cloned()
+            .collect()
+    }
+
+    /// Wrap the per-index clients in any relevant middleware.
+    fn apply_index_middleware(&self, raw_clients: Vec<(Url, Client)>) -> Vec<IndexClient> {
+        raw_clients
+            .into_iter()
+            .map(|(url, raw_client)| IndexClient {
+                url,
+                client: self.apply_middleware(raw_client.clone()),
+                raw_client,
+            })
+            .collect()
+    }
+
     fn create_client(
         &self,
         user_agent: &str,
         timeout: Duration,
         ssl_cert_file_exists: bool,
         security: Security,
+        certificates: &[Certificate],
     ) -> Client {
         // Configure the builder.
         let client_builder = ClientBuilder::new()
@@ -260,6 +355,12 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder.tls_built_in_webpki_certs(true)
         };
 
+        // Trust any additional certificates.
+        let client_builder = certificates
+            .iter()
+            .cloned()
+            .fold(client_builder, ClientBuilder::add_root_certificate);
+
         // Configure mTLS.
         let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
             match read_identity(&ssl_client_cert) {
@@ -298,13 +399,17 @@ impl<'a> BaseClientBuilder<'a> {
                 // Initialize the authentication middleware to set headers.
                 match self.auth_integration {
                     AuthIntegration::Default => {
-                        client = client
-                            .with(AuthMiddleware::new().with_keyring(self.keyring.to_provider()));
+                        client = client.with(
+                            AuthMiddleware::new()
+                                .with_keyring(self.keyring.to_provider())
+                                .with_policies(self.auth_policies.clone()),
+                        );
                     }
                     AuthIntegration::OnlyAuthenticated => {
                         client = client.with(
                             AuthMiddleware::new()
                                 .with_keyring(self.keyring.to_provider())
+                                .with_policies(self.auth_policies.clone())
                                 .with_only_authenticated(true),
                         );
                     }
@@ -333,6 +438,8 @@ pub struct BaseClient {
     raw_client: Client,
     /// The HTTP client that accepts invalid certificates without middleware.
     raw_dangerous_client: Client,
+    /// The HTTP clients for indexes with a custom certificate bundle.
+    index_clients: Vec<IndexClient>,
     /// The connectivity mode to use.
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
@@ -341,6 +448,17 @@ pub struct BaseClient {
     allow_insecure_host: Vec<TrustedHost>,
 }
 
+/// An HTTP client for an index that trusts a custom certificate bundle.
+#[derive(Debug, Clone)]
+struct IndexClient {
+    /// The URL of the index.
+    url: Url,
+    /// The underlying HTTP client.
+    client: ClientWithMiddleware,
+    /// The HTTP client without middleware.
+    raw_client: Client,
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Security {
     /// The client should use secure settings, i.e., valid certificates.
@@ -358,6 +476,12 @@ impl BaseClient {
             .any(|allow_insecure_host| allow_insecure_host.matches(url))
         {
             &self.dangerous_client
+        } else if let Some(index_client) = self.index_clients.iter().find(|index_client| {
+            index_client.url.scheme() == url.scheme()
+                && index_client.url.host_str() == url.host_str()
+                && index_client.url.port() == url.port()
+        }) {
+            &index_client.client
         } else {
             &self.client
         }
@@ -374,6 +498,13 @@ impl BaseClient {
     }
 }
 
+/// Read the certificates from a PEM-encoded certificate bundle.
+fn read_certificates(path: &Path) -> Result<Vec<Certificate>, std::io::Error> {
+    let bytes = fs_err::read(path)?;
+    Certificate::from_pem_bundle(&bytes)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
+
 /// Extends [`DefaultRetryableStrategy`], to log transient request failures and additional retry cases.
 pub struct UvRetryableStrategy;
 
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use futures::{FutureExt, StreamExt};
@@ -9,11 +10,15 @@ use uv_cache::{Cache, CacheBucket};
 use uv_cache_key::cache_digest;
 use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{File, FileLocation, IndexUrl, UrlString};
+use uv_pypi_types::SimpleJson;
 
 use crate::cached_client::{CacheControl, CachedClientError};
 use crate::html::SimpleHtml;
 use crate::{Connectivity, Error, ErrorKind, OwnedArchive, RegistryClient};
 
+/// The name of the optional PEP 691 metadata file in a `--find-links` directory.
+const FIND_LINKS_METADATA: &str = "index.json";
+
 #[derive(Debug, thiserror::Error)]
 pub enum FlatIndexError {
     #[error("Expected a file URL, but received: {0}")]
@@ -32,6 +37,8 @@ pub enum FindLinksDirectoryError {
     Io(#[from] std::io::Error),
     #[error(transparent)]
     VerbatimUrl(#[from] uv_pep508::VerbatimUrlError),
+    #[error("Failed to parse `--find-links` metadata file: {0}")]
+    Sidecar(PathBuf, #[source] serde_json::Error),
 }
 
 #[derive(Debug, Default, Clone)]
@@ -227,11 +234,51 @@ impl<'a> FlatIndexClient<'a> {
         }
     }
 
+    /// Read the optional [PEP 691](https://peps.python.org/pep-0691/) metadata file from a
+    /// `--find-links` directory, indexed by filename.
+    ///
+    /// The metadata file (`index.json`) uses the PEP 691 project detail format, and enables the
+    /// directory to provide hashes, `requires-python`, yanked statuses, and upload times for its
+    /// distributions.
+    fn read_sidecar(path: &Path) -> Result<BTreeMap<String, File>, FindLinksDirectoryError> {
+        let sidecar = path.join(FIND_LINKS_METADATA);
+        let contents = match fs_err::read(&sidecar) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(BTreeMap::default());
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let SimpleJson { files } = serde_json::from_slice(&contents)
+            .map_err(|err| FindLinksDirectoryError::Sidecar(sidecar.clone(), err))?;
+
+        // SAFETY: The index path is itself constructed from a URL.
+        let base = Url::from_directory_path(path).unwrap();
+
+        debug!(
+            "Reading `--find-links` metadata from: {}",
+            sidecar.display()
+        );
+        Ok(files
+            .into_iter()
+            .filter_map(|file| match File::try_from(file, &base) {
+                Ok(file) => Some((file.filename.clone(), file)),
+                Err(err) => {
+                    // Ignore files with unparsable version specifiers.
+                    warn!("Skipping file in {}: {err}", sidecar.display());
+                    None
+                }
+            })
+            .collect())
+    }
+
     /// Read a flat remote index from a `--find-links` directory.
     fn read_from_directory(
         path: &Path,
         flat_index: &IndexUrl,
     ) -> Result<FlatIndexEntries, FindLinksDirectoryError> {
+        let mut sidecar = Self::read_sidecar(path)?;
+
         let mut dists = Vec::new();
         for entry in fs_err::read_dir(path)? {
             let entry = entry?;
@@ -265,15 +312,25 @@ impl<'a> FlatIndexClient<'a> {
             // SAFETY: The index path is itself constructed from a URL.
             let url = Url::from_file_path(entry.path()).unwrap();
 
-            let file = File {
-                dist_info_metadata: false,
-                filename: filename.to_string(),
-                hashes: Vec::new(),
-                requires_python: None,
-                size: None,
-                upload_time_utc_ms: None,
-                url: FileLocation::AbsoluteUrl(UrlString::from(url)),
-                yanked: None,
+            // If the metadata file describes this distribution, use its metadata, but always
+            // point to the file on disk.
+            let file = if let Some(file) = sidecar.remove(&filename) {
+                File {
+                    dist_info_metadata: false,
+                    url: FileLocation::AbsoluteUrl(UrlString::from(url)),
+                    ..file
+                }
+            } else {
+                File {
+                    dist_info_metadata: false,
+                    filename: filename.to_string(),
+                    hashes: Vec::new(),
+                    requires_python: None,
+                    size: None,
+                    upload_time_utc_ms: None,
+                    url: FileLocation::AbsoluteUrl(UrlString::from(url)),
+                    yanked: None,
+                }
             };
 
             let Some(filename) = DistFilename::try_from_normalized_filename(&filename) else {
@@ -285,6 +342,14 @@ impl<'a> FlatIndexClient<'a> {
             };
             dists.push((filename, file, flat_index.clone()));
         }
+
+        for filename in sidecar.keys() {
+            warn!(
+                "Skipping missing file listed in `{}`: {filename}",
+                path.join(FIND_LINKS_METADATA).display()
+            );
+        }
+
         Ok(FlatIndexEntries::from_entries(dists))
     }
 }
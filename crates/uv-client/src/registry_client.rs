@@ -56,6 +56,9 @@ impl RegistryClientBuilder<'_> {
 impl<'a> RegistryClientBuilder<'a> {
     #[must_use]
     pub fn index_urls(mut self, index_urls: IndexUrls) -> Self {
+        self.base_client_builder = self
+            .base_client_builder
+            .indexes(index_urls.defined_indexes());
         self.index_urls = index_urls;
         self
     }
@@ -406,16 +409,32 @@ impl RegistryClient {
 
     /// Fetch the [`SimpleMetadata`] from a local file, using a PEP 503-compatible directory
     /// structure.
+    ///
+    /// If the directory contains a PEP 691 `index.json`, it's preferred over the `index.html`,
+    /// as it can carry additional metadata (e.g., upload times).
     async fn fetch_local_index(
         &self,
         package_name: &PackageName,
         url: &Url,
     ) -> Result<OwnedArchive<SimpleMetadata>, Error> {
-        let path = url
+        let directory = url
             .to_file_path()
-            .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?
-            .join("index.html");
-        let text = match fs_err::tokio::read_to_string(&path).await {
+            .map_err(|()| ErrorKind::NonFileUrl(url.clone()))?;
+
+        match fs_err::tokio::read(directory.join("index.json")).await {
+            Ok(bytes) => {
+                let data: SimpleJson = serde_json::from_slice(&bytes)
+                    .map_err(|err| Error::from_json_err(err, url.clone()))?;
+                let metadata = SimpleMetadata::from_files(data.files, package_name, url);
+                return OwnedArchive::from_unarchived(&metadata);
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(Error::from(ErrorKind::Io(err)));
+            }
+        }
+
+        let text = match fs_err::tokio::read_to_string(directory.join("index.html")).await {
             Ok(text) => text,
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
                 return Err(Error::from(ErrorKind::FileNotFound(
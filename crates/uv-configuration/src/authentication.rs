@@ -11,6 +11,11 @@ pub enum KeyringProviderType {
     Disabled,
     /// Use the `keyring` command for credential lookup.
     Subprocess,
+    /// Use the operating system's credential store for credential lookup.
+    ///
+    /// Uses the macOS Keychain, the Windows Credential Manager, or the Secret Service API
+    /// (e.g., GNOME Keyring) on Linux, without requiring the `keyring` package.
+    Native,
     // /// Not yet implemented
     // Auto,
     // /// Not implemented yet. Maybe use <https://docs.rs/keyring/latest/keyring/> for this?
@@ -23,6 +28,7 @@ impl KeyringProviderType {
         match self {
             Self::Disabled => None,
             Self::Subprocess => Some(KeyringProvider::subprocess()),
+            Self::Native => Some(KeyringProvider::native()),
         }
     }
 }
@@ -1,15 +1,17 @@
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use thiserror::Error;
 use url::Url;
 
-use uv_auth::Credentials;
+use uv_auth::{AuthPolicy, CredentialProvider, Credentials, IndexPolicy};
 
 use crate::index_name::{IndexName, IndexNameError};
 use crate::origin::Origin;
 use crate::{IndexUrl, IndexUrlError};
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Index {
     /// The name of the index.
@@ -57,6 +59,61 @@ pub struct Index {
     /// is given the highest priority when resolving packages.
     #[serde(default)]
     pub default: bool,
+    /// When to authenticate requests to the index.
+    ///
+    /// By default (`auto`), uv sends an unauthenticated request first, and only searches for
+    /// credentials if the index responds with an authentication error. With `always`, uv never
+    /// sends an unauthenticated request, and errors if no credentials can be found. With `never`,
+    /// uv strips any credentials from requests to the index.
+    ///
+    /// The policy applies to all requests to the index's host (e.g., to distributions hosted
+    /// alongside the index), such that credentials are never sent to a host that shouldn't
+    /// receive them.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.example.com/simple"
+    /// authenticate = "always"
+    /// ```
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub authenticate: AuthPolicy,
+    /// Where to search for credentials for the index, in addition to the index URL and the
+    /// `UV_INDEX_{name}_USERNAME` and `UV_INDEX_{name}_PASSWORD` environment variables.
+    ///
+    /// By default (`auto`), uv searches the netrc file, followed by the credential helper (if
+    /// `UV_CREDENTIAL_HELPER` is set) and the keyring (if a `keyring-provider` is configured). Set
+    /// to `netrc`, `helper`, or `keyring` to limit the search to a single provider.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub credential_provider: CredentialProvider,
+    /// The path to a PEM-encoded certificate bundle to trust when connecting to the index, in
+    /// addition to the default root certificates.
+    ///
+    /// Relative paths are resolved relative to the directory containing the configuration file
+    /// in which the index is defined.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_bundle: Option<PathBuf>,
+    /// Skip certificate verification when connecting to the index's host.
+    ///
+    /// Equivalent to passing the index's host to `--allow-insecure-host`.
+    ///
+    /// WARNING: Hosts included in this list will not be verified against the system's certificate
+    /// store. Only use this for trusted hosts on secure networks.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub allow_insecure: bool,
+    /// The URL to which distributions should be published, if it differs from the index URL.
+    ///
+    /// Used by `uv publish --index <name>`, for indexes that accept uploads at a different
+    /// endpoint than the one used to serve the Simple API.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.example.com/simple"
+    /// publish-url = "https://pypi.example.com/legacy/"
+    /// ```
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publish_url: Option<Url>,
     /// The origin of the index (e.g., a CLI flag, a user-level configuration file, etc.).
     #[serde(skip)]
     pub origin: Option<Origin>,
@@ -89,6 +146,11 @@ impl Index {
             name: None,
             explicit: false,
             default: true,
+            authenticate: AuthPolicy::default(),
+            credential_provider: CredentialProvider::default(),
+            ca_bundle: None,
+            allow_insecure: false,
+            publish_url: None,
             origin: None,
         }
     }
@@ -100,6 +162,11 @@ impl Index {
             name: None,
             explicit: false,
             default: false,
+            authenticate: AuthPolicy::default(),
+            credential_provider: CredentialProvider::default(),
+            ca_bundle: None,
+            allow_insecure: false,
+            publish_url: None,
             origin: None,
         }
     }
@@ -111,6 +178,11 @@ impl Index {
             name: None,
             explicit: false,
             default: false,
+            authenticate: AuthPolicy::default(),
+            credential_provider: CredentialProvider::default(),
+            ca_bundle: None,
+            allow_insecure: false,
+            publish_url: None,
             origin: None,
         }
     }
@@ -122,6 +194,14 @@ impl Index {
         self
     }
 
+    /// Resolve any relative paths in the index definition (e.g., the `ca-bundle`) against the
+    /// given root directory.
+    #[must_use]
+    pub fn relative_to(mut self, root: &Path) -> Self {
+        self.ca_bundle = self.ca_bundle.map(|ca_bundle| root.join(ca_bundle));
+        self
+    }
+
     /// Return the [`IndexUrl`] of the index.
     pub fn url(&self) -> &IndexUrl {
         &self.url
@@ -149,6 +229,18 @@ impl Index {
         // Otherwise, extract the credentials from the URL.
         Credentials::from_url(self.url.url())
     }
+
+    /// Return the [`IndexPolicy`] used to authenticate requests to the index.
+    pub fn auth_policy(&self) -> IndexPolicy {
+        IndexPolicy {
+            authenticate: self.authenticate,
+            credential_provider: self.credential_provider,
+        }
+    }
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
 }
 
 impl FromStr for Index {
@@ -165,6 +257,11 @@ impl FromStr for Index {
                     url,
                     explicit: false,
                     default: false,
+                    authenticate: AuthPolicy::default(),
+                    credential_provider: CredentialProvider::default(),
+                    ca_bundle: None,
+                    allow_insecure: false,
+                    publish_url: None,
                     origin: None,
                 });
             }
@@ -177,6 +274,11 @@ impl FromStr for Index {
             url,
             explicit: false,
             default: false,
+            authenticate: AuthPolicy::default(),
+            credential_provider: CredentialProvider::default(),
+            ca_bundle: None,
+            allow_insecure: false,
+            publish_url: None,
             origin: None,
         })
     }
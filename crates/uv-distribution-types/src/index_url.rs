@@ -391,6 +391,15 @@ impl<'a> IndexUrls {
     pub fn indexes(&'a self) -> impl Iterator<Item = &'a Index> + 'a {
         self.implicit_indexes().chain(self.default_index())
     }
+
+    /// Return an iterator over every [`Index`] definition, including explicit indexes.
+    ///
+    /// Unlike [`IndexUrls::indexes`], this includes indexes that are only used for pinned
+    /// packages, and ignores `--no-index`. It's intended for applying per-index settings (e.g.,
+    /// authentication policies) to any request that may target the index.
+    pub fn defined_indexes(&'a self) -> impl Iterator<Item = &'a Index> + 'a {
+        self.indexes.iter()
+    }
 }
 
 bitflags::bitflags! {
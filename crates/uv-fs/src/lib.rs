@@ -372,6 +372,76 @@ impl LockedFile {
         }
     }
 
+    /// Inner implementation for [`LockedFile::acquire_shared_blocking`] and
+    /// [`LockedFile::acquire_shared`].
+    fn lock_file_shared_blocking(
+        file: fs_err::File,
+        resource: &str,
+    ) -> Result<Self, std::io::Error> {
+        trace!(
+            "Checking shared lock for `{resource}` at `{}`",
+            file.path().user_display()
+        );
+        match file.file().try_lock_shared() {
+            Ok(()) => {
+                debug!("Acquired shared lock for `{resource}`");
+                Ok(Self(file))
+            }
+            Err(err) => {
+                // Log error code and enum kind to help debugging more exotic failures.
+                if err.kind() != std::io::ErrorKind::WouldBlock {
+                    debug!("Try lock error: {err:?}");
+                }
+                info!(
+                    "Waiting to acquire shared lock for `{resource}` at `{}`",
+                    file.path().user_display(),
+                );
+                file.file().lock_shared().map_err(|err| {
+                    // Not an fs_err method, we need to build our own path context
+                    std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        format!(
+                            "Could not acquire shared lock for `{resource}` at `{}`: {}",
+                            file.path().user_display(),
+                            err
+                        ),
+                    )
+                })?;
+
+                debug!("Acquired shared lock for `{resource}`");
+                Ok(Self(file))
+            }
+        }
+    }
+
+    /// Acquire an exclusive lock for a resource, if it isn't held by another process.
+    ///
+    /// Returns `None` if the lock is held (in shared or exclusive mode) by another process.
+    pub fn try_acquire(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Option<Self>, std::io::Error> {
+        let file = fs_err::File::create(path.as_ref())?;
+        match file.file().try_lock_exclusive() {
+            Ok(()) => {
+                debug!("Acquired lock for `{resource}`");
+                Ok(Some(Self(file)))
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                debug!("Lock for `{resource}` is held by another process");
+                Ok(None)
+            }
+            Err(err) => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "Could not acquire lock for `{resource}` at `{}`: {}",
+                    file.path().user_display(),
+                    err
+                ),
+            )),
+        }
+    }
+
     /// The same as [`LockedFile::acquire`], but for synchronous contexts. Do not use from an async
     /// context, as this can block the runtime while waiting for another process to release the
     /// lock.
@@ -384,6 +454,18 @@ impl LockedFile {
         Self::lock_file_blocking(file, &resource)
     }
 
+    /// The same as [`LockedFile::acquire_shared`], but for synchronous contexts. Do not use from
+    /// an async context, as this can block the runtime while waiting for another process to
+    /// release the lock.
+    pub fn acquire_shared_blocking(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Self, std::io::Error> {
+        let file = fs_err::File::create(path.as_ref())?;
+        let resource = resource.to_string();
+        Self::lock_file_shared_blocking(file, &resource)
+    }
+
     /// Acquire a cross-process lock for a resource using a file at the provided path.
     #[cfg(feature = "tokio")]
     pub async fn acquire(
@@ -394,6 +476,26 @@ impl LockedFile {
         let resource = resource.to_string();
         tokio::task::spawn_blocking(move || Self::lock_file_blocking(file, &resource)).await?
     }
+
+    /// Acquire a shared cross-process lock for a resource using a file at the provided path.
+    ///
+    /// Any number of processes can hold a shared lock at the same time, but a shared lock can't be
+    /// held alongside an exclusive lock.
+    #[cfg(feature = "tokio")]
+    pub async fn acquire_shared(
+        path: impl AsRef<Path>,
+        resource: impl Display,
+    ) -> Result<Self, std::io::Error> {
+        let file = fs_err::File::create(path.as_ref())?;
+        let resource = resource.to_string();
+        tokio::task::spawn_blocking(move || Self::lock_file_shared_blocking(file, &resource))
+            .await?
+    }
+
+    /// Return the underlying [`fs_err::File`].
+    pub fn file(&self) -> &fs_err::File {
+        &self.0
+    }
 }
 
 impl Drop for LockedFile {
@@ -102,8 +102,9 @@ impl Tags {
         implementation_version: (u8, u8),
         manylinux_compatible: bool,
         gil_disabled: bool,
+        debug: bool,
     ) -> Result<Self, TagsError> {
-        let implementation = Implementation::parse(implementation_name, gil_disabled)?;
+        let implementation = Implementation::parse(implementation_name, gil_disabled, debug)?;
 
         // Determine the compatible tags for the current platform.
         let platform_tags = {
@@ -124,12 +125,37 @@ impl Tags {
                 platform_tag.clone(),
             ));
         }
+        // Debug builds can also load extension modules built for the release ABI, as of
+        // Python 3.8; before, debug builds had an incompatible ABI (e.g., `cp37dm` vs. `cp37m`)
+        if let Implementation::CPython {
+            gil_disabled,
+            debug: true,
+        } = implementation
+        {
+            if python_version >= (3, 8) {
+                let release = Implementation::CPython {
+                    gil_disabled,
+                    debug: false,
+                };
+                for platform_tag in &platform_tags {
+                    tags.push((
+                        release.language_tag(python_version),
+                        release.abi_tag(python_version, implementation_version),
+                        platform_tag.clone(),
+                    ));
+                }
+            }
+        }
         // 2. abi3 and no abi (e.g. executable binary)
-        if let Implementation::CPython { gil_disabled } = implementation {
+        if let Implementation::CPython {
+            gil_disabled,
+            debug,
+        } = implementation
+        {
             // For some reason 3.2 is the minimum python for the cp abi
             for minor in (2..=python_version.1).rev() {
-                // No abi3 for freethreading python
-                if !gil_disabled {
+                // No abi3 for freethreading python, nor for debug builds before Python 3.8
+                if !gil_disabled && (!debug || python_version >= (3, 8)) {
                     for platform_tag in &platform_tags {
                         tags.push((
                             implementation.language_tag((python_version.0, minor)),
@@ -311,7 +337,7 @@ impl std::fmt::Display for Tags {
 
 #[derive(Debug, Clone, Copy)]
 enum Implementation {
-    CPython { gil_disabled: bool },
+    CPython { gil_disabled: bool, debug: bool },
     PyPy,
     GraalPy,
     Pyston,
@@ -336,20 +362,21 @@ impl Implementation {
     fn abi_tag(self, python_version: (u8, u8), implementation_version: (u8, u8)) -> String {
         match self {
             // Ex) `cp39`
-            Self::CPython { gil_disabled } => {
+            Self::CPython {
+                gil_disabled,
+                debug,
+            } => {
+                // Ex) `cp313d` for a debug build, see
+                // https://docs.python.org/3/using/configure.html#debug-build
+                let debug = if debug { "d" } else { "" };
                 if python_version.1 <= 7 {
-                    format!("cp{}{}m", python_version.0, python_version.1)
+                    format!("cp{}{}{debug}m", python_version.0, python_version.1)
                 } else if gil_disabled {
                     // https://peps.python.org/pep-0703/#build-configuration-changes
                     // Python 3.13+ only, but it makes more sense to just rely on the sysconfig var.
-                    format!("cp{}{}t", python_version.0, python_version.1)
+                    format!("cp{}{}t{debug}", python_version.0, python_version.1)
                 } else {
-                    format!(
-                        "cp{}{}{}",
-                        python_version.0,
-                        python_version.1,
-                        if gil_disabled { "t" } else { "" }
-                    )
+                    format!("cp{}{}{debug}", python_version.0, python_version.1)
                 }
             }
             // Ex) `pypy39_pp73`
@@ -381,13 +408,16 @@ impl Implementation {
         }
     }
 
-    fn parse(name: &str, gil_disabled: bool) -> Result<Self, TagsError> {
+    fn parse(name: &str, gil_disabled: bool, debug: bool) -> Result<Self, TagsError> {
         if gil_disabled && name != "cpython" {
             return Err(TagsError::GilIsACPythonProblem(name.to_string()));
         }
         match name {
             // Known and supported implementations.
-            "cpython" => Ok(Self::CPython { gil_disabled }),
+            "cpython" => Ok(Self::CPython {
+                gil_disabled,
+                debug,
+            }),
             "pypy" => Ok(Self::PyPy),
             "graalpy" => Ok(Self::GraalPy),
             "pyston" => Ok(Self::Pyston),
@@ -380,6 +380,7 @@ fn test_manylinux_incompatible() {
         (3, 9),
         false,
         false,
+        false,
     )
     .unwrap();
     assert_snapshot!(
@@ -443,6 +444,7 @@ fn test_system_tags_manylinux() {
         (3, 9),
         true,
         false,
+        false,
     )
     .unwrap();
     assert_snapshot!(
@@ -1067,6 +1069,7 @@ fn test_system_tags_macos() {
         (3, 9),
         false,
         false,
+        false,
     )
     .unwrap();
     assert_snapshot!(
@@ -1528,3 +1531,45 @@ fn test_system_tags_macos() {
     "###
     );
 }
+
+/// Debug builds prefer the debug ABI, but can also load extension modules built for the release
+/// ABI.
+#[test]
+fn test_system_tags_debug() {
+    let platform = Platform::new(
+        Os::Manylinux {
+            major: 2,
+            minor: 28,
+        },
+        Arch::X86_64,
+    );
+    let compatible = |tags: &Tags, python: &str, abi: &str| {
+        tags.compatibility(
+            &[python.to_string()],
+            &[abi.to_string()],
+            &["manylinux_2_28_x86_64".to_string()],
+        )
+    };
+
+    let tags = Tags::from_env(&platform, (3, 13), "cpython", (3, 13), true, false, true).unwrap();
+    assert!(compatible(&tags, "cp313", "cp313d") > compatible(&tags, "cp313", "cp313"));
+    assert!(compatible(&tags, "cp313", "cp313") > compatible(&tags, "cp313", "abi3"));
+    assert!(!compatible(&tags, "cp313", "cp313t").is_compatible());
+    assert!(!compatible(&tags, "cp313", "cp313td").is_compatible());
+
+    let tags = Tags::from_env(&platform, (3, 13), "cpython", (3, 13), true, true, true).unwrap();
+    assert!(compatible(&tags, "cp313", "cp313td") > compatible(&tags, "cp313", "cp313t"));
+    assert!(compatible(&tags, "cp313", "cp313t").is_compatible());
+    assert!(!compatible(&tags, "cp313", "cp313d").is_compatible());
+    assert!(!compatible(&tags, "cp313", "abi3").is_compatible());
+
+    let tags = Tags::from_env(&platform, (3, 13), "cpython", (3, 13), true, false, false).unwrap();
+    assert!(!compatible(&tags, "cp313", "cp313d").is_compatible());
+
+    // Before Python 3.8, the debug ABI is incompatible with both the release ABI and abi3.
+    let tags = Tags::from_env(&platform, (3, 7), "cpython", (3, 7), true, false, true).unwrap();
+    assert!(compatible(&tags, "cp37", "cp37dm").is_compatible());
+    assert!(!compatible(&tags, "cp37", "cp37m").is_compatible());
+    assert!(!compatible(&tags, "cp37", "abi3").is_compatible());
+    assert!(!compatible(&tags, "cp36", "abi3").is_compatible());
+}
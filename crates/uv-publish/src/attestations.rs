@@ -0,0 +1,61 @@
+//! PEP 740 attestations for uploaded distributions.
+//!
+//! uv doesn't generate attestations; they're read from pre-made `<filename>.publish.attestation`
+//! files, e.g., as created by `pypi-attestations sign`. Generating attestations during trusted
+//! publishing would require a Sigstore client (Fulcio for the signing certificate, Rekor for the
+//! transparency log), which is out of scope for now.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::debug;
+
+use uv_fs::Simplified;
+
+/// The suffix of pre-made attestation files, appended to the distribution filename.
+pub(crate) const ATTESTATION_SUFFIX: &str = ".publish.attestation";
+
+#[derive(Debug, Error)]
+pub enum AttestationError {
+    #[error("Failed to read attestation: `{}`", _0.user_display())]
+    Read(PathBuf, #[source] io::Error),
+    #[error("Invalid attestation: `{}`", _0.user_display())]
+    Parse(PathBuf, #[source] serde_json::Error),
+}
+
+/// A PEP 740 attestation object.
+///
+/// We don't verify attestations ourselves, the index does that on upload; we only check that
+/// the document has the expected shape, and pass it through as-is.
+///
+/// See: <https://peps.python.org/pep-0740/#attestation-objects>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attestation {
+    version: u8,
+    verification_material: serde_json::Value,
+    envelope: serde_json::Value,
+}
+
+/// Read the pre-made attestation for a distribution from `directory`, if any.
+///
+/// The attestation for `foo-1.0.0-py3-none-any.whl` is expected at
+/// `<directory>/foo-1.0.0-py3-none-any.whl.publish.attestation`.
+pub fn read_attestations(
+    directory: &Path,
+    raw_filename: &str,
+) -> Result<Vec<Attestation>, AttestationError> {
+    let path = directory.join(format!("{raw_filename}{ATTESTATION_SUFFIX}"));
+    let contents = match fs_err::read(&path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            debug!("No attestation found at: {}", path.user_display());
+            return Ok(Vec::new());
+        }
+        Err(err) => return Err(AttestationError::Read(path, err)),
+    };
+    let attestation =
+        serde_json::from_slice(&contents).map_err(|err| AttestationError::Parse(path, err))?;
+    Ok(vec![attestation])
+}
@@ -1,5 +1,9 @@
+mod attestations;
+mod s3;
+mod static_index;
 mod trusted_publishing;
 
+use crate::attestations::ATTESTATION_SUFFIX;
 use crate::trusted_publishing::TrustedPublishingError;
 use base64::prelude::BASE64_STANDARD;
 use base64::Engine;
@@ -12,9 +16,10 @@ use reqwest::multipart::Part;
 use reqwest::{Body, Response, StatusCode};
 use reqwest_middleware::RequestBuilder;
 use reqwest_retry::{Retryable, RetryableStrategy};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::Deserialize;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
 use std::{env, fmt, io};
 use thiserror::Error;
@@ -27,10 +32,15 @@ use uv_configuration::{KeyringProviderType, TrustedPublishing};
 use uv_distribution_filename::{DistFilename, SourceDistExtension, SourceDistFilename};
 use uv_fs::{ProgressReader, Simplified};
 use uv_metadata::read_metadata_async_seek;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
 use uv_pypi_types::{HashAlgorithm, HashDigest, Metadata23, MetadataError};
 use uv_static::EnvVars;
 use uv_warnings::{warn_user, warn_user_once};
 
+pub use attestations::{read_attestations, Attestation, AttestationError};
+pub use s3::S3Error;
+pub use static_index::{publish_static, IndexLayout, StaticIndex, StaticIndexError};
 pub use trusted_publishing::TrustedPublishingToken;
 use uv_cache::{Cache, Refresh};
 use uv_distribution_types::{IndexCapabilities, IndexUrl};
@@ -55,6 +65,8 @@ pub enum PublishError {
     PublishSend(PathBuf, Url, #[source] PublishSendError),
     #[error("Failed to obtain token for trusted publishing")]
     TrustedPublishing(#[from] TrustedPublishingError),
+    #[error("Failed to obtain attestation for `{}`", _0.user_display())]
+    Attestation(PathBuf, #[source] AttestationError),
     #[error("{0} are not allowed when using trusted publishing")]
     MixedCredentials(String),
     #[error("Failed to query check URL")]
@@ -71,6 +83,10 @@ pub enum PublishError {
     },
     #[error("Hash is missing in index for {0}")]
     MissingHash(Box<DistFilename>),
+    #[error("Found multiple files for {0}: `{}` and `{}`", _1.user_display(), _2.user_display())]
+    DuplicateDistribution(Box<DistFilename>, PathBuf, PathBuf),
+    #[error("Found multiple source distributions for {0} {1}: `{}` and `{}`", _2.user_display(), _3.user_display())]
+    DuplicateSourceDist(PackageName, Version, PathBuf, PathBuf),
 }
 
 /// Failure to get the metadata for a specific file.
@@ -90,6 +106,26 @@ pub enum PublishPrepareError {
     MultiplePkgInfo(String),
     #[error("Failed to read: `{0}`")]
     Read(String, #[source] io::Error),
+    #[error("Invalid package name in metadata: `{0}`")]
+    InvalidName(String, #[source] uv_normalize::InvalidNameError),
+    #[error("Invalid version in metadata: `{0}`")]
+    InvalidVersion(String, #[source] uv_pep440::VersionParseError),
+    #[error("The package name in the metadata (`{metadata}`) does not match the filename (`{filename}`)")]
+    NameMismatch {
+        metadata: PackageName,
+        filename: PackageName,
+    },
+    #[error(
+        "The version in the metadata (`{metadata}`) does not match the filename (`{filename}`)"
+    )]
+    VersionMismatch {
+        metadata: Version,
+        filename: Version,
+    },
+    #[error("Unsupported metadata version `{0}`, expected one of: {}", SUPPORTED_METADATA_VERSIONS.join(", "))]
+    UnsupportedMetadataVersion(String),
+    #[error("Failed to serialize attestations")]
+    Attestations(#[from] serde_json::Error),
 }
 
 /// Failure in or after (HTTP) transport for a specific file.
@@ -224,6 +260,12 @@ impl PublishSendError {
     }
 }
 
+/// The core metadata versions accepted by PyPI.
+///
+/// See: <https://packaging.python.org/en/latest/specifications/core-metadata/>
+const SUPPORTED_METADATA_VERSIONS: &[&str] =
+    &["1.0", "1.1", "1.2", "2.0", "2.1", "2.2", "2.3", "2.4"];
+
 /// Collect the source distributions and wheels for publishing.
 ///
 /// Returns the path, the raw filename and the parsed filename. The raw filename is a fixup for
@@ -254,6 +296,10 @@ pub fn files_for_publishing(
             if filename == ".gitignore" {
                 continue;
             }
+            // Attestations are uploaded alongside the distribution they belong to.
+            if filename.ends_with(ATTESTATION_SUFFIX) {
+                continue;
+            }
             let dist_filename = DistFilename::try_from_normalized_filename(&filename)
                 .ok_or_else(|| PublishError::InvalidFilename(dist.clone()))?;
             files.push((dist, filename, dist_filename));
@@ -265,6 +311,77 @@ pub fn files_for_publishing(
     Ok(files)
 }
 
+/// Validate the files before uploading any of them.
+///
+/// Reads the metadata of each file and checks that it matches the filename and uses a metadata
+/// version the index accepts, and that the batch doesn't contain the same distribution twice. This
+/// runs before any network requests, so that an invalid file doesn't leave a release
+/// half-published.
+pub async fn validate_files(files: &[(PathBuf, String, DistFilename)]) -> Result<(), PublishError> {
+    let mut filenames: FxHashMap<String, &Path> = FxHashMap::default();
+    let mut source_dists: FxHashMap<(&PackageName, &Version), &Path> = FxHashMap::default();
+    for (file, _raw_filename, filename) in files {
+        if let Some(existing) = filenames.insert(filename.to_string(), file) {
+            return Err(PublishError::DuplicateDistribution(
+                Box::new(filename.clone()),
+                existing.to_path_buf(),
+                file.clone(),
+            ));
+        }
+        if let DistFilename::SourceDistFilename(source_dist) = filename {
+            if let Some(existing) =
+                source_dists.insert((&source_dist.name, &source_dist.version), file)
+            {
+                return Err(PublishError::DuplicateSourceDist(
+                    source_dist.name.clone(),
+                    source_dist.version.clone(),
+                    existing.to_path_buf(),
+                    file.clone(),
+                ));
+            }
+        }
+
+        validate_metadata(file, filename)
+            .await
+            .map_err(|err| PublishError::PublishPrepare(file.clone(), Box::new(err)))?;
+    }
+    Ok(())
+}
+
+/// Check that the metadata of a file matches its filename and is in a supported version.
+async fn validate_metadata(
+    file: &Path,
+    filename: &DistFilename,
+) -> Result<(), PublishPrepareError> {
+    let metadata = metadata(file, filename).await?;
+
+    if !SUPPORTED_METADATA_VERSIONS.contains(&metadata.metadata_version.as_str()) {
+        return Err(PublishPrepareError::UnsupportedMetadataVersion(
+            metadata.metadata_version,
+        ));
+    }
+
+    let name = PackageName::from_str(&metadata.name)
+        .map_err(|err| PublishPrepareError::InvalidName(metadata.name.clone(), err))?;
+    if &name != filename.name() {
+        return Err(PublishPrepareError::NameMismatch {
+            metadata: name,
+            filename: filename.name().clone(),
+        });
+    }
+
+    let version = Version::from_str(&metadata.version)
+        .map_err(|err| PublishPrepareError::InvalidVersion(metadata.version.clone(), err))?;
+    if &version != filename.version() {
+        return Err(PublishPrepareError::VersionMismatch {
+            metadata: version,
+            filename: filename.version().clone(),
+        });
+    }
+
+    Ok(())
+}
+
 pub enum TrustedPublishResult {
     /// We didn't check for trusted publishing.
     Skipped,
@@ -344,6 +461,8 @@ pub async fn check_trusted_publishing(
 ///
 /// Returns `true` if the file was newly uploaded and `false` if it already existed.
 ///
+/// The `attestations` are uploaded alongside the file, if any (PEP 740).
+///
 /// Implements a custom retry flow since the request isn't cloneable.
 pub async fn upload(
     file: &Path,
@@ -354,6 +473,7 @@ pub async fn upload(
     retries: u32,
     username: Option<&str>,
     password: Option<&str>,
+    attestations: &[Attestation],
     check_url_client: Option<&CheckUrlClient<'_>>,
     reporter: Arc<impl Reporter>,
 ) -> Result<bool, PublishError> {
@@ -374,6 +494,7 @@ pub async fn upload(
             username,
             password,
             &form_metadata,
+            attestations,
             reporter.clone(),
         )
         .await
@@ -671,12 +792,17 @@ async fn build_request(
     username: Option<&str>,
     password: Option<&str>,
     form_metadata: &[(&'static str, String)],
+    attestations: &[Attestation],
     reporter: Arc<impl Reporter>,
 ) -> Result<(RequestBuilder, usize), PublishPrepareError> {
     let mut form = reqwest::multipart::Form::new();
     for (key, value) in form_metadata {
         form = form.text(*key, value.clone());
     }
+    // PEP 740: The attestations are sent as a JSON array in a single form field.
+    if !attestations.is_empty() {
+        form = form.text("attestations", serde_json::to_string(attestations)?);
+    }
 
     let file = File::open(file).await?;
     let idx = reporter.on_download_start(&filename.to_string(), Some(file.metadata().await?.len()));
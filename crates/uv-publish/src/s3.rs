@@ -0,0 +1,449 @@
+//! A minimal client for S3-compatible object storage.
+//!
+//! We only need to list and upload objects, so instead of pulling in an SDK, we sign requests
+//! with AWS Signature Version 4 ourselves.
+//! See: <https://docs.aws.amazon.com/AmazonS3/latest/API/sig-v4-authenticating-requests.html>
+
+use std::fmt::Write;
+
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use reqwest::{header, Method, StatusCode};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tracing::debug;
+use url::Url;
+
+use uv_client::BaseClient;
+use uv_static::EnvVars;
+
+#[derive(Debug, Error)]
+pub enum S3Error {
+    #[error("Missing bucket name in S3 URL: `{0}`")]
+    MissingBucket(Url),
+    #[error("Environment variable {0} must be set to publish to S3")]
+    MissingEnvVar(&'static str),
+    #[error("Invalid S3 endpoint URL in {}: `{1}`", EnvVars::AWS_ENDPOINT_URL)]
+    InvalidEndpoint(#[source] url::ParseError, String),
+    #[error("Failed to fetch: `{0}`")]
+    Reqwest(Url, #[source] reqwest::Error),
+    #[error("Failed to fetch: `{0}`")]
+    ReqwestMiddleware(Url, #[source] reqwest_middleware::Error),
+    #[error("S3 request to `{0}` failed with status code {1}. Server says: {2}")]
+    Status(Url, StatusCode, String),
+    #[error("Invalid S3 response from `{0}`")]
+    InvalidResponse(Url, #[source] Box<quick_xml::DeError>),
+}
+
+/// The response to a `ListObjectsV2` request.
+///
+/// See: <https://docs.aws.amazon.com/AmazonS3/latest/API/API_ListObjectsV2.html>
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListBucketResult {
+    #[serde(default)]
+    is_truncated: bool,
+    #[serde(default)]
+    contents: Vec<ListObject>,
+    next_continuation_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ListObject {
+    key: String,
+}
+
+/// The metadata of an object, from a `HeadObject` request.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct ObjectMetadata {
+    /// The hex-encoded SHA-256 checksum stored with the object, if any.
+    pub(crate) sha256: Option<String>,
+    /// The entity tag of the object, without quotes.
+    pub(crate) etag: Option<String>,
+}
+
+impl ObjectMetadata {
+    fn from_headers(headers: &header::HeaderMap) -> Self {
+        // Objects uploaded in multiple parts have a composite checksum (`<base64>-<parts>`), which
+        // isn't the checksum of the contents, and so fails to decode.
+        let sha256 = headers
+            .get("x-amz-checksum-sha256")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| BASE64_STANDARD.decode(value).ok())
+            .filter(|digest| digest.len() == 32)
+            .map(hex::encode);
+        let etag = headers
+            .get(header::ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim_matches('"').to_string());
+        Self { sha256, etag }
+    }
+
+    /// The hex-encoded MD5 of the contents, if known.
+    ///
+    /// The `ETag` of an object is the MD5 of its contents, unless the object was uploaded in
+    /// multiple parts (`<hash>-<parts>`) or is encrypted with a customer-provided or KMS key, in
+    /// which case it's opaque; we only trust an `ETag` with the shape of an MD5 digest.
+    pub(crate) fn md5(&self) -> Option<&str> {
+        self.etag
+            .as_deref()
+            .filter(|etag| etag.len() == 32 && etag.bytes().all(|byte| byte.is_ascii_hexdigit()))
+    }
+}
+
+/// Credentials for signing requests, read from the standard AWS environment variables.
+#[derive(Clone)]
+struct S3Credentials {
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: Option<String>,
+}
+
+impl std::fmt::Debug for S3Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("S3Credentials")
+            .field("access_key_id", &self.access_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A location in an S3-compatible bucket, e.g., `s3://bucket/prefix`.
+#[derive(Debug, Clone)]
+pub struct S3Bucket {
+    /// A custom endpoint for a service other than AWS, which is addressed with path-style URLs.
+    endpoint: Option<Url>,
+    bucket: String,
+    region: String,
+    /// The key prefix, without leading or trailing slashes.
+    prefix: String,
+    credentials: S3Credentials,
+}
+
+impl S3Bucket {
+    /// Parse an `s3://bucket/prefix` URL, reading the credentials, region, and endpoint from the
+    /// environment.
+    pub fn from_url(url: &Url) -> Result<Self, S3Error> {
+        let bucket = url
+            .host_str()
+            .filter(|bucket| !bucket.is_empty())
+            .ok_or_else(|| S3Error::MissingBucket(url.clone()))?
+            .to_string();
+        let prefix = url.path().trim_matches('/').to_string();
+
+        let endpoint = std::env::var(EnvVars::AWS_ENDPOINT_URL)
+            .ok()
+            .map(|endpoint| {
+                Url::parse(&endpoint).map_err(|err| S3Error::InvalidEndpoint(err, endpoint))
+            })
+            .transpose()?;
+        let region = std::env::var(EnvVars::AWS_REGION)
+            .or_else(|_| std::env::var(EnvVars::AWS_DEFAULT_REGION))
+            .unwrap_or_else(|_| "us-east-1".to_string());
+        let credentials = S3Credentials {
+            access_key_id: std::env::var(EnvVars::AWS_ACCESS_KEY_ID)
+                .map_err(|_| S3Error::MissingEnvVar(EnvVars::AWS_ACCESS_KEY_ID))?,
+            secret_access_key: std::env::var(EnvVars::AWS_SECRET_ACCESS_KEY)
+                .map_err(|_| S3Error::MissingEnvVar(EnvVars::AWS_SECRET_ACCESS_KEY))?,
+            session_token: std::env::var(EnvVars::AWS_SESSION_TOKEN).ok(),
+        };
+
+        Ok(Self {
+            endpoint,
+            bucket,
+            region,
+            prefix,
+            credentials,
+        })
+    }
+
+    /// Return the full key for a path relative to the prefix.
+    pub(crate) fn key(&self, relative: &str) -> String {
+        if self.prefix.is_empty() {
+            relative.to_string()
+        } else {
+            format!("{}/{relative}", self.prefix)
+        }
+    }
+
+    /// Return the URL for an object, or for the bucket itself if `key` is `None`.
+    fn url(&self, key: Option<&str>) -> Url {
+        let (mut url, mut path) = if let Some(endpoint) = &self.endpoint {
+            let path = format!(
+                "{}/{}",
+                endpoint.path().trim_end_matches('/'),
+                uri_encode(&self.bucket, true)
+            );
+            (endpoint.clone(), path)
+        } else {
+            let url = Url::parse(&format!(
+                "https://{}.s3.{}.amazonaws.com",
+                self.bucket, self.region
+            ))
+            .expect("bucket URLs are valid");
+            (url, String::new())
+        };
+        path.push('/');
+        if let Some(key) = key {
+            path.push_str(&uri_encode(key, false));
+        }
+        // The path is already encoded, and `set_path` preserves existing percent-encodings.
+        url.set_path(&path);
+        url
+    }
+
+    /// List the keys of all objects below the prefix, relative to the prefix.
+    pub(crate) async fn list(&self, client: &BaseClient) -> Result<Vec<String>, S3Error> {
+        let prefix = if self.prefix.is_empty() {
+            String::new()
+        } else {
+            format!("{}/", self.prefix)
+        };
+
+        let mut keys = Vec::new();
+        let mut continuation_token: Option<String> = None;
+        loop {
+            let mut url = self.url(None);
+            // The query parameters must be sorted for the canonical request.
+            let mut query = String::new();
+            if let Some(token) = &continuation_token {
+                write!(query, "continuation-token={}&", uri_encode(token, true)).unwrap();
+            }
+            write!(query, "list-type=2&prefix={}", uri_encode(&prefix, true)).unwrap();
+            url.set_query(Some(&query));
+
+            let request = client.for_host(&url).request(Method::GET, url.clone());
+            let (_, body) = self
+                .send_request(request, Method::GET, url.clone(), Vec::new(), &[])
+                .await?;
+            let result = parse_list_response(&String::from_utf8_lossy(&body))
+                .map_err(|err| S3Error::InvalidResponse(url, Box::new(err)))?;
+            keys.extend(
+                result
+                    .contents
+                    .into_iter()
+                    .filter_map(|object| object.key.strip_prefix(&prefix).map(ToString::to_string)),
+            );
+
+            if result.is_truncated {
+                continuation_token = result.next_continuation_token;
+                if continuation_token.is_some() {
+                    continue;
+                }
+            }
+            return Ok(keys);
+        }
+    }
+
+    /// Upload an object to the given key, relative to the prefix.
+    ///
+    /// The SHA-256 of the contents is stored with the object as its checksum, such that it can be
+    /// compared against local files later on (see [`S3Bucket::head`]).
+    pub(crate) async fn put(
+        &self,
+        client: &BaseClient,
+        relative: &str,
+        contents: Vec<u8>,
+        content_type: &str,
+    ) -> Result<(), S3Error> {
+        let url = self.url(Some(&self.key(relative)));
+        debug!("Uploading to S3: {url}");
+        let checksum = BASE64_STANDARD.encode(Sha256::digest(&contents));
+        let request = client
+            .for_host(&url)
+            .request(Method::PUT, url.clone())
+            .header(header::CONTENT_TYPE, content_type);
+        self.send_request(
+            request,
+            Method::PUT,
+            url,
+            contents,
+            &[("x-amz-checksum-sha256", &checksum)],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Return the metadata of the object at the given key, relative to the prefix, or `None` if
+    /// the object doesn't exist.
+    pub(crate) async fn head(
+        &self,
+        client: &BaseClient,
+        relative: &str,
+    ) -> Result<Option<ObjectMetadata>, S3Error> {
+        let url = self.url(Some(&self.key(relative)));
+        let request = client.for_host(&url).request(Method::HEAD, url.clone());
+        match self
+            .send_request(
+                request,
+                Method::HEAD,
+                url,
+                Vec::new(),
+                &[("x-amz-checksum-mode", "ENABLED")],
+            )
+            .await
+        {
+            Ok((headers, _)) => Ok(Some(ObjectMetadata::from_headers(&headers))),
+            Err(S3Error::Status(_, StatusCode::NOT_FOUND, _)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Sign and send a request, returning the response headers and body.
+    ///
+    /// The `headers` are added to the request and included in the signature, as S3 requires for
+    /// all `x-amz-*` headers.
+    async fn send_request(
+        &self,
+        request: reqwest_middleware::RequestBuilder,
+        method: Method,
+        url: Url,
+        payload: Vec<u8>,
+        headers: &[(&str, &str)],
+    ) -> Result<(header::HeaderMap, Vec<u8>), S3Error> {
+        let timestamp = jiff::Timestamp::now()
+            .strftime("%Y%m%dT%H%M%SZ")
+            .to_string();
+        let payload_hash = hex::encode(Sha256::digest(&payload));
+        let authorization =
+            self.authorization(method.as_str(), &url, &payload_hash, &timestamp, headers);
+
+        let mut request = request
+            .header("x-amz-date", &timestamp)
+            .header("x-amz-content-sha256", &payload_hash)
+            .header(header::AUTHORIZATION, authorization)
+            .body(payload);
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        if let Some(session_token) = &self.credentials.session_token {
+            request = request.header("x-amz-security-token", session_token);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|err| S3Error::ReqwestMiddleware(url.clone(), err))?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response
+            .bytes()
+            .await
+            .map_err(|err| S3Error::Reqwest(url.clone(), err))?;
+        if !status.is_success() {
+            return Err(S3Error::Status(
+                url,
+                status,
+                String::from_utf8_lossy(&body).to_string(),
+            ));
+        }
+        Ok((headers, body.to_vec()))
+    }
+
+    /// Compute the `Authorization` header for a request.
+    ///
+    /// `timestamp` is the request time in the `%Y%m%dT%H%M%SZ` format, which must match the
+    /// `x-amz-date` header. `extra_headers` are any additional headers to sign, with lowercase
+    /// names.
+    fn authorization(
+        &self,
+        method: &str,
+        url: &Url,
+        payload_hash: &str,
+        timestamp: &str,
+        extra_headers: &[(&str, &str)],
+    ) -> String {
+        let date = &timestamp[..8];
+        let host = match url.port() {
+            Some(port) => format!("{}:{port}", url.host_str().unwrap_or_default()),
+            None => url.host_str().unwrap_or_default().to_string(),
+        };
+
+        let mut headers = vec![
+            ("host", host.as_str()),
+            ("x-amz-content-sha256", payload_hash),
+            ("x-amz-date", timestamp),
+        ];
+        if let Some(session_token) = &self.credentials.session_token {
+            headers.push(("x-amz-security-token", session_token.as_str()));
+        }
+        headers.extend_from_slice(extra_headers);
+        // The canonical headers must be sorted by name.
+        headers.sort_unstable_by_key(|(name, _)| *name);
+        let signed_headers = headers.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        let signed_headers = signed_headers.join(";");
+
+        let mut canonical_request = format!(
+            "{method}\n{}\n{}\n",
+            url.path(),
+            url.query().unwrap_or_default()
+        );
+        for (name, value) in &headers {
+            writeln!(canonical_request, "{name}:{}", value.trim()).unwrap();
+        }
+        write!(canonical_request, "\n{signed_headers}\n{payload_hash}").unwrap();
+
+        let scope = format!("{date}/{}/s3/aws4_request", self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{timestamp}\n{scope}\n{}",
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let key = signing_key(
+            &self.credentials.secret_access_key,
+            date,
+            &self.region,
+            "s3",
+        );
+        let signature = hex::encode(hmac_sha256(&key, string_to_sign.as_bytes()));
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+            self.credentials.access_key_id
+        )
+    }
+}
+
+/// Derive the `SigV4` signing key for a date, region, and service.
+fn signing_key(secret_access_key: &str, date: &str, region: &str, service: &str) -> [u8; 32] {
+    let key = hmac_sha256(
+        format!("AWS4{secret_access_key}").as_bytes(),
+        date.as_bytes(),
+    );
+    let key = hmac_sha256(&key, region.as_bytes());
+    let key = hmac_sha256(&key, service.as_bytes());
+    hmac_sha256(&key, b"aws4_request")
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
+/// Percent-encode everything but unreserved characters, as `SigV4` requires.
+///
+/// If `encode_slash` is `false`, `/` is preserved, as in object keys in paths.
+fn uri_encode(value: &str, encode_slash: bool) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(char::from(byte));
+            }
+            b'/' if !encode_slash => encoded.push('/'),
+            _ => write!(encoded, "%{byte:02X}").unwrap(),
+        }
+    }
+    encoded
+}
+
+/// Parse the XML response to a `ListObjectsV2` request.
+fn parse_list_response(xml: &str) -> Result<ListBucketResult, quick_xml::DeError> {
+    quick_xml::de::from_str(xml)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,291 @@
+use super::*;
+
+fn bucket() -> S3Bucket {
+    S3Bucket {
+        endpoint: Some(Url::parse("http://127.0.0.1:9000").unwrap()),
+        bucket: "wheels".to_string(),
+        region: "us-east-1".to_string(),
+        prefix: "simple".to_string(),
+        credentials: S3Credentials {
+            access_key_id: "AKID".to_string(),
+            secret_access_key: "SECRET".to_string(),
+            session_token: None,
+        },
+    }
+}
+
+/// The signing key example from the AWS documentation.
+#[test]
+fn signing_key_aws_example() {
+    assert_eq!(
+        hex::encode(signing_key(
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "20120215",
+            "us-east-1",
+            "iam"
+        )),
+        "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d"
+    );
+}
+
+#[test]
+fn object_urls() {
+    let bucket = bucket();
+    assert_eq!(
+        bucket
+            .url(Some(&bucket.key("foo/foo-1.0.0+local-py3-none-any.whl")))
+            .as_str(),
+        "http://127.0.0.1:9000/wheels/simple/foo/foo-1.0.0%2Blocal-py3-none-any.whl"
+    );
+
+    let bucket = S3Bucket {
+        endpoint: None,
+        prefix: String::new(),
+        ..bucket
+    };
+    assert_eq!(
+        bucket.url(Some(&bucket.key("index.html"))).as_str(),
+        "https://wheels.s3.us-east-1.amazonaws.com/index.html"
+    );
+}
+
+#[test]
+fn authorization() {
+    let bucket = bucket();
+    let payload_hash = hex::encode(Sha256::digest(b""));
+
+    let url = bucket.url(Some(&bucket.key("foo/foo-1.0.0+local-py3-none-any.whl")));
+    assert_eq!(
+        bucket.authorization("PUT", &url, &payload_hash, "20240101T000000Z", &[]),
+        "AWS4-HMAC-SHA256 Credential=AKID/20240101/us-east-1/s3/aws4_request, \
+        SignedHeaders=host;x-amz-content-sha256;x-amz-date, \
+        Signature=593f3a806cce2ff2c9fb2400f875b307ba1fbcab0f99ab80b889617dcd0560bc"
+    );
+
+    let mut url = bucket.url(None);
+    url.set_query(Some("list-type=2&prefix=simple%2F"));
+    assert_eq!(
+        bucket.authorization("GET", &url, &payload_hash, "20240101T000000Z", &[]),
+        "AWS4-HMAC-SHA256 Credential=AKID/20240101/us-east-1/s3/aws4_request, \
+        SignedHeaders=host;x-amz-content-sha256;x-amz-date, \
+        Signature=4b58164f0106517ad7b6e888f68979879c13bca1bc93a95e7425d095902de958"
+    );
+}
+
+#[test]
+fn list_response() {
+    let xml = r"<?xml version='1.0' encoding='UTF-8'?>
+<ListBucketResult>
+  <IsTruncated>false</IsTruncated>
+  <Contents><Key>simple/index.html</Key></Contents>
+  <Contents><Key>simple/a&amp;b/a-1.0.tar.gz</Key></Contents>
+</ListBucketResult>";
+    let result = parse_list_response(xml).unwrap();
+    assert!(!result.is_truncated);
+    assert_eq!(
+        result
+            .contents
+            .into_iter()
+            .map(|object| object.key)
+            .collect::<Vec<_>>(),
+        vec!["simple/index.html", "simple/a&b/a-1.0.tar.gz"]
+    );
+    assert_eq!(result.next_continuation_token, None);
+
+    // An empty bucket has no `Contents`.
+    let result = parse_list_response(
+        r#"<ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+  <Name>wheels</Name>
+  <KeyCount>0</KeyCount>
+  <IsTruncated>false</IsTruncated>
+</ListBucketResult>"#,
+    )
+    .unwrap();
+    assert!(result.contents.is_empty());
+
+    assert!(parse_list_response("<Error>AccessDenied").is_err());
+}
+
+/// Additional `x-amz-*` headers are signed, sorted by name.
+#[test]
+fn authorization_extra_headers() {
+    let bucket = bucket();
+    let payload_hash = hex::encode(Sha256::digest(b""));
+    let url = bucket.url(Some(&bucket.key("index.html")));
+    let authorization = bucket.authorization(
+        "HEAD",
+        &url,
+        &payload_hash,
+        "20240101T000000Z",
+        &[("x-amz-checksum-mode", "ENABLED")],
+    );
+    assert!(
+        authorization
+            .contains("SignedHeaders=host;x-amz-checksum-mode;x-amz-content-sha256;x-amz-date,"),
+        "{authorization}"
+    );
+    assert_ne!(
+        authorization,
+        bucket.authorization("HEAD", &url, &payload_hash, "20240101T000000Z", &[])
+    );
+}
+
+/// List and upload objects against a local stand-in for an S3-compatible server.
+#[tokio::test]
+async fn list_and_put() {
+    use wiremock::matchers::{body_string, header, header_exists, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    // The first page of the listing is truncated.
+    Mock::given(method("GET"))
+        .and(path("/wheels/"))
+        .and(query_param("list-type", "2"))
+        .and(query_param("prefix", "simple/"))
+        .and(header_exists("authorization"))
+        .and(header_exists("x-amz-date"))
+        .and(|request: &wiremock::Request| !request.url.query().unwrap().contains("continuation"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            "<ListBucketResult><IsTruncated>true</IsTruncated>\
+            <Contents><Key>simple/foo/foo-1.0.0.tar.gz</Key></Contents>\
+            <NextContinuationToken>next/page</NextContinuationToken></ListBucketResult>",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/wheels/"))
+        .and(query_param("continuation-token", "next/page"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            "<ListBucketResult><IsTruncated>false</IsTruncated>\
+            <Contents><Key>simple/foo/index.html</Key></Contents></ListBucketResult>",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/wheels/simple/foo/foo-2.0.0.tar.gz"))
+        .and(header("content-type", "application/octet-stream"))
+        .and(header(
+            "x-amz-content-sha256",
+            hex::encode(Sha256::digest(b"contents")).as_str(),
+        ))
+        .and(header(
+            "x-amz-checksum-sha256",
+            BASE64_STANDARD.encode(Sha256::digest(b"contents")).as_str(),
+        ))
+        .and(body_string("contents"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let bucket = S3Bucket {
+        endpoint: Some(Url::parse(&server.uri()).unwrap()),
+        ..bucket()
+    };
+    let client = uv_client::BaseClientBuilder::new().build();
+
+    assert_eq!(
+        bucket.list(&client).await.unwrap(),
+        vec!["foo/foo-1.0.0.tar.gz", "foo/index.html"]
+    );
+    bucket
+        .put(
+            &client,
+            "foo/foo-2.0.0.tar.gz",
+            b"contents".to_vec(),
+            "application/octet-stream",
+        )
+        .await
+        .unwrap();
+}
+
+/// Read the checksum and ETag of an object, and treat missing objects as absent.
+#[tokio::test]
+async fn head() {
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("HEAD"))
+        .and(path("/wheels/simple/foo/foo-1.0.0.tar.gz"))
+        .and(header("x-amz-checksum-mode", "ENABLED"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "x-amz-checksum-sha256",
+                    BASE64_STANDARD.encode(Sha256::digest(b"contents")),
+                )
+                .insert_header("etag", "\"98bf7d8c15784f0a3d63204441e1e2aa\""),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("HEAD"))
+        .and(path("/wheels/simple/foo/foo-2.0.0.tar.gz"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"d41d8cd98f00b204e9800998ecf8427e-2\""),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("HEAD"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&server)
+        .await;
+
+    let bucket = S3Bucket {
+        endpoint: Some(Url::parse(&server.uri()).unwrap()),
+        ..bucket()
+    };
+    let client = uv_client::BaseClientBuilder::new().build();
+
+    let metadata = bucket
+        .head(&client, "foo/foo-1.0.0.tar.gz")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        metadata.sha256.as_deref(),
+        Some(hex::encode(Sha256::digest(b"contents")).as_str())
+    );
+    assert_eq!(metadata.md5(), Some("98bf7d8c15784f0a3d63204441e1e2aa"));
+
+    // Multipart ETags aren't content hashes.
+    let metadata = bucket
+        .head(&client, "foo/foo-2.0.0.tar.gz")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(metadata.sha256, None);
+    assert_eq!(metadata.md5(), None);
+
+    assert_eq!(bucket.head(&client, "foo/index.html").await.unwrap(), None);
+}
+
+/// Surface errors from the server.
+#[tokio::test]
+async fn put_error() {
+    use wiremock::matchers::method;
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(403).set_body_string("<Error>AccessDenied</Error>"))
+        .mount(&server)
+        .await;
+
+    let bucket = S3Bucket {
+        endpoint: Some(Url::parse(&server.uri()).unwrap()),
+        ..bucket()
+    };
+    let client = uv_client::BaseClientBuilder::new().build();
+    let err = bucket
+        .put(&client, "index.html", Vec::new(), "text/html")
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, S3Error::Status(_, status, _) if status == reqwest::StatusCode::FORBIDDEN),
+        "{err:?}"
+    );
+}
@@ -0,0 +1,338 @@
+//! Publishing to static indexes, i.e., local directories and S3-compatible buckets that are
+//! served as a `--find-links` or PEP 503 index, instead of using an upload API.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+use tracing::debug;
+use url::Url;
+
+use uv_client::BaseClient;
+use uv_distribution_filename::DistFilename;
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_pypi_types::{
+    simple_project_page, simple_root_page, HashAlgorithm, HashDigest, SimpleHtmlLink,
+};
+
+use crate::hash_file;
+use crate::s3::{S3Bucket, S3Error};
+
+#[derive(Debug, Error)]
+pub enum StaticIndexError {
+    #[error("Invalid file URL: `{0}`")]
+    InvalidFileUrl(Url),
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    #[error(transparent)]
+    S3(#[from] S3Error),
+    #[error("A different file with the same name already exists in the index: `{0}`")]
+    Conflict(String),
+    #[error("`{0}` already exists in the index, but it has neither a SHA-256 checksum nor an MD5 ETag to compare against")]
+    Unverifiable(String),
+}
+
+/// The layout of a static index.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum IndexLayout {
+    /// A PEP 503 simple index, with one directory per package.
+    #[default]
+    Simple,
+    /// All distributions in a single directory, for use with `--find-links`.
+    Flat,
+}
+
+impl IndexLayout {
+    /// The path of a distribution relative to the root of the index.
+    fn path(self, raw_filename: &str, filename: &DistFilename) -> String {
+        match self {
+            Self::Simple => format!("{}/{raw_filename}", filename.name()),
+            Self::Flat => raw_filename.to_string(),
+        }
+    }
+}
+
+/// A static index to publish to.
+#[derive(Debug)]
+pub enum StaticIndex {
+    /// A directory on the local filesystem, from a `file://` URL.
+    Directory(PathBuf),
+    /// An S3-compatible bucket, from an `s3://` URL.
+    S3(Box<S3Bucket>),
+}
+
+impl StaticIndex {
+    /// Determine the static index for a publish URL.
+    ///
+    /// Returns `None` if the URL refers to an upload endpoint instead.
+    pub fn from_url(url: &Url) -> Result<Option<Self>, StaticIndexError> {
+        match url.scheme() {
+            "file" => {
+                let path = url
+                    .to_file_path()
+                    .map_err(|()| StaticIndexError::InvalidFileUrl(url.clone()))?;
+                Ok(Some(Self::Directory(path)))
+            }
+            "s3" => Ok(Some(Self::S3(Box::new(S3Bucket::from_url(url)?)))),
+            _ => Ok(None),
+        }
+    }
+
+    /// List the files in the index, relative to its root and with `/` as separator.
+    async fn list(&self, client: &BaseClient) -> Result<BTreeSet<String>, StaticIndexError> {
+        match self {
+            Self::Directory(root) => Ok(list_directory(root)?),
+            Self::S3(bucket) => Ok(bucket.list(client).await?.into_iter().collect()),
+        }
+    }
+
+    /// Copy a distribution into the index.
+    async fn write_file(
+        &self,
+        client: &BaseClient,
+        relative: &str,
+        source: &Path,
+    ) -> Result<(), StaticIndexError> {
+        match self {
+            Self::Directory(root) => {
+                let target = root.join(relative);
+                debug!("Copying to: {}", target.user_display());
+                if let Some(parent) = target.parent() {
+                    fs_err::tokio::create_dir_all(parent).await?;
+                }
+                fs_err::tokio::copy(source, &target).await?;
+            }
+            Self::S3(bucket) => {
+                let contents = fs_err::tokio::read(source).await?;
+                bucket
+                    .put(client, relative, contents, "application/octet-stream")
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write an index page.
+    async fn write_page(
+        &self,
+        client: &BaseClient,
+        relative: &str,
+        html: String,
+    ) -> Result<(), StaticIndexError> {
+        match self {
+            Self::Directory(root) => {
+                let target = root.join(relative);
+                if let Some(parent) = target.parent() {
+                    fs_err::tokio::create_dir_all(parent).await?;
+                }
+                fs_err::tokio::write(target, html).await?;
+            }
+            Self::S3(bucket) => {
+                bucket
+                    .put(client, relative, html.into_bytes(), "text/html")
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Determine the SHA-256 of a file in the index, for the index pages.
+    ///
+    /// For S3, this is the checksum stored with the object, which is missing for objects that
+    /// weren't uploaded with one.
+    async fn sha256(
+        &self,
+        client: &BaseClient,
+        relative: &str,
+    ) -> Result<Option<String>, StaticIndexError> {
+        match self {
+            Self::Directory(root) => {
+                let digest =
+                    hash_file(root.join(relative), Hasher::from(HashAlgorithm::Sha256)).await?;
+                Ok(Some(digest.digest.to_string()))
+            }
+            Self::S3(bucket) => Ok(bucket
+                .head(client, relative)
+                .await?
+                .and_then(|metadata| metadata.sha256)),
+        }
+    }
+
+    /// Check that a file in the index is identical to a local file.
+    ///
+    /// For S3, we compare against the SHA-256 checksum of the object, or, if it has none, against
+    /// its `ETag` if that's an MD5 of the contents. If neither is available, we can't tell whether
+    /// the files match and error.
+    async fn verify_existing(
+        &self,
+        client: &BaseClient,
+        relative: &str,
+        source: &Path,
+    ) -> Result<(), StaticIndexError> {
+        let (algorithm, remote) = match self {
+            Self::Directory(_) => (HashAlgorithm::Sha256, self.sha256(client, relative).await?),
+            Self::S3(bucket) => {
+                let metadata = bucket.head(client, relative).await?.unwrap_or_default();
+                if let Some(sha256) = metadata.sha256 {
+                    (HashAlgorithm::Sha256, Some(sha256))
+                } else if let Some(md5) = metadata.md5() {
+                    (HashAlgorithm::Md5, Some(md5.to_ascii_lowercase()))
+                } else {
+                    return Err(StaticIndexError::Unverifiable(relative.to_string()));
+                }
+            }
+        };
+        let Some(remote) = remote else {
+            return Err(StaticIndexError::Unverifiable(relative.to_string()));
+        };
+        let local = hash_file(source, Hasher::from(algorithm)).await?;
+        if *local.digest != *remote {
+            return Err(StaticIndexError::Conflict(relative.to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// Publish files to a static index, and regenerate its index pages.
+///
+/// Returns, for each file, whether it was uploaded (or would be uploaded, in a dry run), or
+/// whether it already existed in the index. Identical files are skipped; a different file with
+/// the same name is an error.
+pub async fn publish_static(
+    target: &StaticIndex,
+    files: &[(PathBuf, String, DistFilename)],
+    layout: IndexLayout,
+    client: &BaseClient,
+    dry_run: bool,
+) -> Result<Vec<bool>, StaticIndexError> {
+    let mut existing = target.list(client).await?;
+
+    let mut uploaded = Vec::with_capacity(files.len());
+    for (file, raw_filename, filename) in files {
+        let relative = layout.path(raw_filename, filename);
+        if existing.contains(&relative) {
+            target.verify_existing(client, &relative, file).await?;
+            debug!("Found {relative} in the index, skipping");
+            uploaded.push(false);
+            continue;
+        }
+        if !dry_run {
+            target.write_file(client, &relative, file).await?;
+        }
+        existing.insert(relative);
+        uploaded.push(true);
+    }
+
+    if dry_run {
+        return Ok(uploaded);
+    }
+
+    // Regenerate the pages from all files in the index, including previously published ones.
+    let mut packages: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for path in &existing {
+        let (directory, name) = path.rsplit_once('/').unwrap_or(("", path));
+        if DistFilename::try_from_normalized_filename(name).is_none() {
+            continue;
+        }
+        match layout {
+            IndexLayout::Simple if !directory.is_empty() && !directory.contains('/') => {
+                packages.entry(directory).or_default().push(name);
+            }
+            IndexLayout::Flat if directory.is_empty() => {
+                packages.entry("").or_default().push(name);
+            }
+            _ => {}
+        }
+    }
+
+    match layout {
+        IndexLayout::Simple => {
+            for (package, names) in &packages {
+                let mut links = Vec::with_capacity(names.len());
+                for name in names {
+                    let sha256 = target.sha256(client, &format!("{package}/{name}")).await?;
+                    links.push((*name, sha256.map(sha256_digest)));
+                }
+                let html = links_page(&format!("Links for {package}"), &links);
+                target
+                    .write_page(client, &format!("{package}/index.html"), html)
+                    .await?;
+            }
+            target
+                .write_page(
+                    client,
+                    "index.html",
+                    simple_root_page(packages.keys().copied()),
+                )
+                .await?;
+        }
+        IndexLayout::Flat => {
+            let mut links = Vec::new();
+            for name in packages.values().flatten() {
+                let sha256 = target.sha256(client, name).await?;
+                links.push((*name, sha256.map(sha256_digest)));
+            }
+            target
+                .write_page(client, "index.html", links_page("Links", &links))
+                .await?;
+        }
+    }
+
+    Ok(uploaded)
+}
+
+/// List the files in a local static index, up to one directory deep.
+fn list_directory(root: &Path) -> Result<BTreeSet<String>, io::Error> {
+    let entries = match fs_err::read_dir(root) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(BTreeSet::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut files = BTreeSet::new();
+    for entry in entries {
+        let entry = entry?;
+        let Some(name) = entry.file_name().to_str().map(ToString::to_string) else {
+            continue;
+        };
+        if entry.file_type()?.is_dir() {
+            for child in fs_err::read_dir(entry.path())? {
+                let child = child?;
+                if !child.file_type()?.is_file() {
+                    continue;
+                }
+                if let Some(child_name) = child.file_name().to_str() {
+                    files.insert(format!("{name}/{child_name}"));
+                }
+            }
+        } else {
+            files.insert(name);
+        }
+    }
+    Ok(files)
+}
+
+/// Wrap a hex-encoded SHA-256 digest as a [`HashDigest`].
+fn sha256_digest(digest: String) -> HashDigest {
+    HashDigest {
+        algorithm: HashAlgorithm::Sha256,
+        digest: digest.into_boxed_str(),
+    }
+}
+
+/// Render a page linking to the given files, with their hash as fragment, if known.
+fn links_page(title: &str, links: &[(&str, Option<HashDigest>)]) -> String {
+    simple_project_page(
+        title,
+        links.iter().map(|(filename, hash)| SimpleHtmlLink {
+            filename,
+            hash: hash.as_ref(),
+            requires_python: None,
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests;
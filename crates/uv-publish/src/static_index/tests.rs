@@ -0,0 +1,134 @@
+use super::*;
+
+use crate::files_for_publishing;
+
+fn files() -> Vec<(PathBuf, String, DistFilename)> {
+    files_for_publishing(vec![
+        "../../scripts/links/ok-1.0.0-py3-none-any.whl".to_string(),
+        "../../scripts/links/tqdm-999.0.0.tar.gz".to_string(),
+    ])
+    .unwrap()
+}
+
+#[tokio::test]
+async fn publish_directory_simple() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let target = StaticIndex::Directory(temp_dir.path().to_path_buf());
+    let client = uv_client::BaseClientBuilder::new().build();
+
+    let uploaded = publish_static(&target, &files(), IndexLayout::Simple, &client, false)
+        .await
+        .unwrap();
+    assert_eq!(uploaded, vec![true, true]);
+
+    assert!(temp_dir
+        .path()
+        .join("ok")
+        .join("ok-1.0.0-py3-none-any.whl")
+        .is_file());
+    assert!(temp_dir
+        .path()
+        .join("tqdm")
+        .join("tqdm-999.0.0.tar.gz")
+        .is_file());
+    insta::assert_snapshot!(
+        fs_err::read_to_string(temp_dir.path().join("index.html")).unwrap(),
+        @r###"
+    <!DOCTYPE html>
+    <html>
+      <body>
+        <a href="ok/">ok</a><br/>
+        <a href="tqdm/">tqdm</a><br/>
+      </body>
+    </html>
+    "###
+    );
+    insta::assert_snapshot!(
+        fs_err::read_to_string(temp_dir.path().join("tqdm").join("index.html")).unwrap(),
+        @r###"
+    <!DOCTYPE html>
+    <html>
+      <head>
+        <title>Links for tqdm</title>
+      </head>
+      <body>
+        <h1>Links for tqdm</h1>
+        <a href="tqdm-999.0.0.tar.gz#sha256=89fa05cffa7f457658373b85de302d24d0c205ceda2819a8739e324b75e9430b">tqdm-999.0.0.tar.gz</a><br/>
+      </body>
+    </html>
+    "###
+    );
+
+    // Publishing the same files again skips them.
+    let uploaded = publish_static(&target, &files(), IndexLayout::Simple, &client, false)
+        .await
+        .unwrap();
+    assert_eq!(uploaded, vec![false, false]);
+}
+
+#[tokio::test]
+async fn publish_directory_flat() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let target = StaticIndex::Directory(temp_dir.path().to_path_buf());
+    let client = uv_client::BaseClientBuilder::new().build();
+
+    // A previously published distribution is retained in the index page.
+    fs_err::copy(
+        "../../scripts/links/ok-2.0.0-py3-none-any.whl",
+        temp_dir.path().join("ok-2.0.0-py3-none-any.whl"),
+    )
+    .unwrap();
+
+    let uploaded = publish_static(&target, &files(), IndexLayout::Flat, &client, false)
+        .await
+        .unwrap();
+    assert_eq!(uploaded, vec![true, true]);
+
+    let index = fs_err::read_to_string(temp_dir.path().join("index.html")).unwrap();
+    let links = index
+        .lines()
+        .filter(|line| line.contains("<a href"))
+        .map(|line| line.split('#').next().unwrap().trim())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        links,
+        vec![
+            "<a href=\"ok-1.0.0-py3-none-any.whl",
+            "<a href=\"ok-2.0.0-py3-none-any.whl",
+            "<a href=\"tqdm-999.0.0.tar.gz",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn publish_directory_dry_run() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let target = StaticIndex::Directory(temp_dir.path().join("index"));
+    let client = uv_client::BaseClientBuilder::new().build();
+
+    let uploaded = publish_static(&target, &files(), IndexLayout::Simple, &client, true)
+        .await
+        .unwrap();
+    assert_eq!(uploaded, vec![true, true]);
+    assert!(!temp_dir.path().join("index").exists());
+}
+
+/// A different file with the same name is an error.
+#[tokio::test]
+async fn publish_directory_conflict() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let target = StaticIndex::Directory(temp_dir.path().to_path_buf());
+    let client = uv_client::BaseClientBuilder::new().build();
+
+    fs_err::create_dir_all(temp_dir.path().join("tqdm")).unwrap();
+    fs_err::write(
+        temp_dir.path().join("tqdm").join("tqdm-999.0.0.tar.gz"),
+        "other",
+    )
+    .unwrap();
+
+    let err = publish_static(&target, &files(), IndexLayout::Simple, &client, false)
+        .await
+        .unwrap_err();
+    insta::assert_snapshot!(err.to_string(), @"A different file with the same name already exists in the index: `tqdm/tqdm-999.0.0.tar.gz`");
+}
@@ -1,4 +1,7 @@
-use crate::{build_request, form_metadata, Reporter};
+use crate::{
+    build_request, files_for_publishing, form_metadata, read_attestations, validate_files,
+    AttestationError, Reporter,
+};
 use insta::{assert_debug_snapshot, assert_snapshot};
 use itertools::Itertools;
 use std::path::PathBuf;
@@ -88,6 +91,7 @@ async fn upload_request_source_dist() {
         Some("ferris"),
         Some("F3RR!S"),
         &form_metadata,
+        &[],
         Arc::new(DummyReporter),
     )
     .await
@@ -233,6 +237,7 @@ async fn upload_request_wheel() {
         Some("ferris"),
         Some("F3RR!S"),
         &form_metadata,
+        &[],
         Arc::new(DummyReporter),
     )
     .await
@@ -271,3 +276,69 @@ async fn upload_request_wheel() {
         "###);
     });
 }
+
+/// An attestation with the shape of a PEP 740 attestation object.
+fn dummy_attestation() -> serde_json::Value {
+    serde_json::json!({
+        "version": 1,
+        "verification_material": {
+            "certificate": "MIIC...",
+            "transparency_entries": [],
+        },
+        "envelope": {
+            "statement": "eyJf...",
+            "signature": "MEUC...",
+        },
+    })
+}
+
+/// Read a pre-made attestation from the attestations directory.
+#[test]
+fn read_attestations_from_directory() {
+    let raw_filename = "tqdm-999.0.0.tar.gz";
+    let temp_dir = tempfile::tempdir().unwrap();
+
+    // A missing attestation is not an error.
+    assert_eq!(
+        read_attestations(temp_dir.path(), raw_filename).unwrap(),
+        Vec::new()
+    );
+
+    fs_err::write(
+        temp_dir
+            .path()
+            .join("tqdm-999.0.0.tar.gz.publish.attestation"),
+        dummy_attestation().to_string(),
+    )
+    .unwrap();
+    let attestations = read_attestations(temp_dir.path(), raw_filename).unwrap();
+    assert_eq!(
+        serde_json::to_value(&attestations).unwrap(),
+        serde_json::json!([dummy_attestation()])
+    );
+
+    // Reject documents that aren't attestations.
+    fs_err::write(
+        temp_dir
+            .path()
+            .join("tqdm-999.0.0.tar.gz.publish.attestation"),
+        r#"{"version": 1}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        read_attestations(temp_dir.path(), raw_filename),
+        Err(AttestationError::Parse(..))
+    ));
+}
+
+/// Files whose metadata matches the filename pass validation.
+#[tokio::test]
+async fn validate_matching_files() {
+    let files = files_for_publishing(vec![
+        "../../scripts/links/tqdm-999.0.0.tar.gz".to_string(),
+        "../../scripts/links/ok-*.whl".to_string(),
+    ])
+    .unwrap();
+    assert_eq!(files.len(), 3);
+    validate_files(&files).await.unwrap();
+}
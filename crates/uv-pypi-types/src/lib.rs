@@ -6,6 +6,7 @@ pub use metadata::*;
 pub use parsed_url::*;
 pub use requirement::*;
 pub use scheme::*;
+pub use simple_html::*;
 pub use simple_json::*;
 pub use supported_environments::*;
 
@@ -17,5 +18,6 @@ mod metadata;
 mod parsed_url;
 mod requirement;
 mod scheme;
+mod simple_html;
 mod simple_json;
 mod supported_environments;
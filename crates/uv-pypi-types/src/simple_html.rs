@@ -0,0 +1,64 @@
+//! Rendering of [PEP 503](https://peps.python.org/pep-0503/) simple repository pages, e.g., for
+//! mirrored or statically published indexes.
+
+use std::fmt::Write;
+
+use uv_pep440::VersionSpecifiers;
+
+use crate::HashDigest;
+
+/// A link to a distribution on a PEP 503 project page.
+#[derive(Debug, Clone, Copy)]
+pub struct SimpleHtmlLink<'a> {
+    /// The path to the file, relative to the page, which doubles as the link text.
+    pub filename: &'a str,
+    /// The hash of the file, included as the URL fragment.
+    pub hash: Option<&'a HashDigest>,
+    /// The `Requires-Python` specifiers of the distribution, if any.
+    pub requires_python: Option<&'a VersionSpecifiers>,
+}
+
+/// Render the PEP 503 root page, linking to the project page of each package.
+pub fn simple_root_page<'a>(names: impl IntoIterator<Item = &'a str>) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n  <body>\n");
+    for name in names {
+        writeln!(html, "    <a href=\"{name}/\">{name}</a><br/>").unwrap();
+    }
+    html.push_str("  </body>\n</html>\n");
+    html
+}
+
+/// Render a PEP 503 project page with the given title, linking to each distribution.
+pub fn simple_project_page<'a>(
+    title: &str,
+    links: impl IntoIterator<Item = SimpleHtmlLink<'a>>,
+) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html>\n  <head>\n");
+    writeln!(html, "    <title>{title}</title>").unwrap();
+    html.push_str("  </head>\n  <body>\n");
+    writeln!(html, "    <h1>{title}</h1>").unwrap();
+    for link in links {
+        write!(html, "    <a href=\"{}", link.filename).unwrap();
+        if let Some(hash) = link.hash {
+            write!(html, "#{}={}", hash.algorithm, hash.digest).unwrap();
+        }
+        html.push('"');
+        if let Some(requires_python) = link.requires_python {
+            // Specifiers can't contain quotes or ampersands, but comparison operators must be
+            // escaped.
+            let requires_python = requires_python
+                .to_string()
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            write!(html, " data-requires-python=\"{requires_python}\"").unwrap();
+        }
+        writeln!(html, ">{}</a><br/>", link.filename).unwrap();
+    }
+    html.push_str("  </body>\n</html>\n");
+    html
+}
+
+#[cfg(test)]
+mod tests;
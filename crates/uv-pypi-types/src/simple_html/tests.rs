@@ -0,0 +1,41 @@
+use std::str::FromStr;
+
+use uv_pep440::VersionSpecifiers;
+
+use crate::{simple_project_page, simple_root_page, HashDigest, SimpleHtmlLink};
+
+#[test]
+fn root_page() {
+    assert_eq!(
+        simple_root_page(["anyio", "idna"]),
+        "<!DOCTYPE html>\n<html>\n  <body>\n    <a href=\"anyio/\">anyio</a><br/>\n    <a href=\"idna/\">idna</a><br/>\n  </body>\n</html>\n"
+    );
+}
+
+#[test]
+fn project_page() {
+    let hash = HashDigest::from_str(
+        "sha256:40627dcf047dadb22cd25ea7ecfe9cbf3bbbad0482ee5920b582f3809c97654f",
+    )
+    .unwrap();
+    let requires_python = VersionSpecifiers::from_str(">=3.8, <4").unwrap();
+    let html = simple_project_page(
+        "Links for anyio",
+        [
+            SimpleHtmlLink {
+                filename: "anyio-4.3.0-py3-none-any.whl",
+                hash: Some(&hash),
+                requires_python: Some(&requires_python),
+            },
+            SimpleHtmlLink {
+                filename: "anyio-4.3.0.tar.gz",
+                hash: None,
+                requires_python: None,
+            },
+        ],
+    );
+    assert_eq!(
+        html,
+        "<!DOCTYPE html>\n<html>\n  <head>\n    <title>Links for anyio</title>\n  </head>\n  <body>\n    <h1>Links for anyio</h1>\n    <a href=\"anyio-4.3.0-py3-none-any.whl#sha256=40627dcf047dadb22cd25ea7ecfe9cbf3bbbad0482ee5920b582f3809c97654f\" data-requires-python=\"&gt;=3.8, &lt;4\">anyio-4.3.0-py3-none-any.whl</a><br/>\n    <a href=\"anyio-4.3.0.tar.gz\">anyio-4.3.0.tar.gz</a><br/>\n  </body>\n</html>\n"
+    );
+}
@@ -570,6 +570,10 @@ def main() -> None:
         # The `t` abiflag for freethreading Python.
         # https://peps.python.org/pep-0703/#build-configuration-changes
         "gil_disabled": bool(sysconfig.get_config_var("Py_GIL_DISABLED")),
+        # The `d` abiflag for debug builds, i.e., built with `--with-pydebug`. `Py_DEBUG` is not
+        # set on Windows, where `sys.gettotalrefcount` is only available in debug builds.
+        "debug_enabled": bool(sysconfig.get_config_var("Py_DEBUG"))
+        or hasattr(sys, "gettotalrefcount"),
         # Determine if the interpreter is 32-bit or 64-bit.
         # https://github.com/python/cpython/blob/b228655c227b2ca298a8ffac44d14ce3d22f6faa/Lib/venv/__init__.py#L136
         "pointer_size": "64" if sys.maxsize > 2**32 else "32",
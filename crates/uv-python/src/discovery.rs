@@ -138,6 +138,8 @@ pub enum PythonVariant {
     #[default]
     Default,
     Freethreaded,
+    /// A debug build of CPython, i.e., built with `--with-pydebug`.
+    Debug,
 }
 
 /// A Python discovery version request.
@@ -171,7 +173,8 @@ pub struct PythonNotFound {
 }
 
 /// A location for discovery of a Python installation or interpreter.
-#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, Copy, Hash, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum PythonSource {
     /// The path was provided directly
     ProvidedPath,
@@ -1232,8 +1235,9 @@ fn is_windows_store_shim(_path: &Path) -> bool {
 impl PythonVariant {
     fn matches_interpreter(self, interpreter: &Interpreter) -> bool {
         match self {
-            PythonVariant::Default => !interpreter.gil_disabled(),
+            PythonVariant::Default => !interpreter.gil_disabled() && !interpreter.debug_enabled(),
             PythonVariant::Freethreaded => interpreter.gil_disabled(),
+            PythonVariant::Debug => interpreter.debug_enabled() && !interpreter.gil_disabled(),
         }
     }
 
@@ -1244,6 +1248,7 @@ impl PythonVariant {
         match self {
             Self::Default => "",
             Self::Freethreaded => "t",
+            Self::Debug => "d",
         }
     }
 }
@@ -1725,10 +1730,11 @@ impl VersionRequest {
             }
         }
 
-        // Include free-threaded variants
-        if self.is_freethreaded() {
+        // Include free-threaded and debug variants
+        if let Some(variant @ (PythonVariant::Freethreaded | PythonVariant::Debug)) = self.variant()
+        {
             for i in 0..names.len() {
-                let name = names[i].with_variant(PythonVariant::Freethreaded);
+                let name = names[i].with_variant(variant);
                 names.push(name);
             }
         }
@@ -2060,16 +2066,21 @@ impl FromStr for VersionRequest {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // Check if the version request is for a free-threaded Python version
-        let (s, variant) = s
-            .strip_suffix('t')
-            .map_or((s, PythonVariant::Default), |s| {
-                (s, PythonVariant::Freethreaded)
-            });
+        // Check if the version request is for a free-threaded or debug Python version
+        let (s, variant) = if let Some(s) = s.strip_suffix('t') {
+            (s, PythonVariant::Freethreaded)
+        } else if let Some(s) = s.strip_suffix('d') {
+            (s, PythonVariant::Debug)
+        } else {
+            (s, PythonVariant::Default)
+        };
 
-        if variant == PythonVariant::Freethreaded && s.ends_with('t') {
-            // More than one trailing "t" is not allowed
-            return Err(Error::InvalidVersionRequest(format!("{s}t")));
+        if variant != PythonVariant::Default && (s.ends_with('t') || s.ends_with('d')) {
+            // More than one trailing variant suffix is not allowed
+            return Err(Error::InvalidVersionRequest(format!(
+                "{s}{}",
+                variant.suffix()
+            )));
         }
 
         let Ok(version) = Version::from_str(s) else {
@@ -2099,6 +2110,7 @@ impl FromStr for VersionRequest {
 
             match local.as_str() {
                 "freethreaded" => PythonVariant::Freethreaded,
+                "debug" => PythonVariant::Debug,
                 _ => return Err(Error::InvalidVersionRequest(s.to_string())),
             }
         };
@@ -2153,6 +2165,7 @@ impl FromStr for PythonVariant {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "t" | "freethreaded" => Ok(Self::Freethreaded),
+            "d" | "debug" => Ok(Self::Debug),
             "" => Ok(Self::Default),
             _ => Err(()),
         }
@@ -2164,6 +2177,7 @@ impl std::fmt::Display for PythonVariant {
         match self {
             Self::Default => f.write_str("default"),
             Self::Freethreaded => f.write_str("freethreaded"),
+            Self::Debug => f.write_str("debug"),
         }
     }
 }
@@ -2193,23 +2207,15 @@ impl fmt::Display for VersionRequest {
         match self {
             Self::Any => f.write_str("any"),
             Self::Default => f.write_str("default"),
-            Self::Major(major, PythonVariant::Default) => write!(f, "{major}"),
-            Self::Major(major, PythonVariant::Freethreaded) => write!(f, "{major}t"),
-            Self::MajorMinor(major, minor, PythonVariant::Default) => write!(f, "{major}.{minor}"),
-            Self::MajorMinor(major, minor, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}t")
-            }
-            Self::MajorMinorPatch(major, minor, patch, PythonVariant::Default) => {
-                write!(f, "{major}.{minor}.{patch}")
-            }
-            Self::MajorMinorPatch(major, minor, patch, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}.{patch}t")
+            Self::Major(major, variant) => write!(f, "{major}{}", variant.suffix()),
+            Self::MajorMinor(major, minor, variant) => {
+                write!(f, "{major}.{minor}{}", variant.suffix())
             }
-            Self::MajorMinorPrerelease(major, minor, prerelease, PythonVariant::Default) => {
-                write!(f, "{major}.{minor}{prerelease}")
+            Self::MajorMinorPatch(major, minor, patch, variant) => {
+                write!(f, "{major}.{minor}.{patch}{}", variant.suffix())
             }
-            Self::MajorMinorPrerelease(major, minor, prerelease, PythonVariant::Freethreaded) => {
-                write!(f, "{major}.{minor}{prerelease}t")
+            Self::MajorMinorPrerelease(major, minor, prerelease, variant) => {
+                write!(f, "{major}.{minor}{prerelease}{}", variant.suffix())
             }
             Self::Range(specifiers, _) => write!(f, "{specifiers}"),
         }
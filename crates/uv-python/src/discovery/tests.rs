@@ -440,6 +440,30 @@ fn version_request_from_str() {
         VersionRequest::from_str("3.13tt"),
         Err(Error::InvalidVersionRequest(_))
     ));
+    assert_eq!(
+        VersionRequest::from_str("3.13d").unwrap(),
+        VersionRequest::MajorMinor(3, 13, PythonVariant::Debug)
+    );
+    assert_eq!(
+        VersionRequest::from_str("3.13+debug").unwrap(),
+        VersionRequest::MajorMinor(3, 13, PythonVariant::Debug)
+    );
+    assert_eq!(
+        VersionRequest::from_str("3.13.1d").unwrap(),
+        VersionRequest::MajorMinorPatch(3, 13, 1, PythonVariant::Debug)
+    );
+    assert_eq!(
+        VersionRequest::from_str("3.13d").unwrap().to_string(),
+        "3.13d"
+    );
+    assert!(matches!(
+        VersionRequest::from_str("3.13td"),
+        Err(Error::InvalidVersionRequest(_))
+    ));
+    assert!(matches!(
+        VersionRequest::from_str("3.13d+debug"),
+        Err(Error::InvalidVersionRequest(_))
+    ));
 }
 
 #[test]
@@ -513,6 +537,18 @@ fn executable_names_from_request() {
         ],
     );
 
+    case(
+        "3.13d",
+        &[
+            "python",
+            "python3",
+            "python3.13",
+            "pythond",
+            "python3d",
+            "python3.13d",
+        ],
+    );
+
     case(
         "3.13.2",
         &["python", "python3", "python3.13", "python3.13.2"],
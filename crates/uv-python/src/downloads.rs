@@ -1,10 +1,14 @@
 use futures::TryStreamExt;
 use owo_colors::OwoColorize;
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::io;
 use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::str::FromStr;
+use std::sync::OnceLock;
 use std::task::{Context, Poll};
 use thiserror::Error;
 use tokio::io::{AsyncRead, ReadBuf};
@@ -22,7 +26,7 @@ use uv_static::EnvVars;
 use crate::implementation::{
     Error as ImplementationError, ImplementationName, LenientImplementationName,
 };
-use crate::installation::PythonInstallationKey;
+use crate::installation::{PythonInstallationKey, PythonInstallationKeyError};
 use crate::libc::LibcDetectionError;
 use crate::platform::{self, Arch, Libc, Os};
 use crate::{Interpreter, PythonRequest, PythonVersion, VersionRequest};
@@ -75,10 +79,16 @@ pub enum Error {
     InvalidRequestPlatform(#[from] platform::Error),
     #[error("No download found for request: {}", _0.green())]
     NoDownloadFound(PythonDownloadRequest),
+    #[error("Failed to read Python downloads from `{}` (set via `{}`)", _0.user_display(), EnvVars::UV_PYTHON_DOWNLOADS_JSON_PATH)]
+    ReadDownloadsJson(PathBuf, #[source] io::Error),
+    #[error("Failed to parse Python downloads from `{}` (set via `{}`)", _0.user_display(), EnvVars::UV_PYTHON_DOWNLOADS_JSON_PATH)]
+    ParseDownloadsJson(PathBuf, #[source] serde_json::Error),
+    #[error("Invalid Python download `{0}` in `{}`", _1.user_display())]
+    InvalidDownloadsJsonEntry(String, PathBuf, #[source] PythonInstallationKeyError),
     #[error(
-        "A mirror was provided via `{0}`, but the URL does not match the expected format: {0}"
+        "A mirror was provided via `{0}`, but the URL does not match the expected format: {1}"
     )]
-    Mirror(&'static str, &'static str),
+    Mirror(&'static str, String),
     #[error(transparent)]
     LibcDetection(#[from] LibcDetectionError),
 }
@@ -86,10 +96,30 @@ pub enum Error {
 #[derive(Debug, PartialEq)]
 pub struct ManagedPythonDownload {
     key: PythonInstallationKey,
-    url: &'static str,
-    sha256: Option<&'static str>,
+    url: Cow<'static, str>,
+    sha256: Option<Cow<'static, str>>,
 }
 
+/// An entry in a Python downloads JSON file, in the format of `download-metadata.json`.
+#[derive(Debug, Deserialize)]
+struct JsonPythonDownload {
+    name: String,
+    arch: String,
+    os: String,
+    libc: String,
+    major: u8,
+    minor: u8,
+    patch: u8,
+    #[serde(default)]
+    prerelease: String,
+    url: String,
+    sha256: Option<String>,
+    variant: Option<String>,
+}
+
+/// The Python downloads read from [`EnvVars::UV_PYTHON_DOWNLOADS_JSON_PATH`], if any.
+static CUSTOM_PYTHON_DOWNLOADS: OnceLock<Vec<ManagedPythonDownload>> = OnceLock::new();
+
 #[derive(Debug, Clone, Default, Eq, PartialEq)]
 pub struct PythonDownloadRequest {
     version: Option<VersionRequest>,
@@ -234,9 +264,11 @@ impl PythonDownloadRequest {
     }
 
     /// Iterate over all [`PythonDownload`]'s that match this request.
-    pub fn iter_downloads(&self) -> impl Iterator<Item = &'static ManagedPythonDownload> + '_ {
-        ManagedPythonDownload::iter_all()
-            .filter(move |download| self.satisfied_by_download(download))
+    pub fn iter_downloads(
+        &self,
+    ) -> Result<impl Iterator<Item = &'static ManagedPythonDownload> + '_, Error> {
+        Ok(ManagedPythonDownload::iter_all()?
+            .filter(move |download| self.satisfied_by_download(download)))
     }
 
     /// Whether this request is satisfied by the key of an existing installation.
@@ -428,22 +460,115 @@ impl ManagedPythonDownload {
         request: &PythonDownloadRequest,
     ) -> Result<&'static ManagedPythonDownload, Error> {
         request
-            .iter_downloads()
+            .iter_downloads()?
             .next()
             .ok_or(Error::NoDownloadFound(request.clone()))
     }
 
     /// Iterate over all [`ManagedPythonDownload`]s.
-    pub fn iter_all() -> impl Iterator<Item = &'static ManagedPythonDownload> {
-        PYTHON_DOWNLOADS
+    ///
+    /// If a Python downloads JSON file is provided via [`EnvVars::UV_PYTHON_DOWNLOADS_JSON_PATH`],
+    /// its downloads take precedence over the built-in downloads. The built-in downloads are
+    /// omitted if [`EnvVars::UV_PYTHON_DOWNLOADS_JSON_ONLY`] is set.
+    pub fn iter_all() -> Result<impl Iterator<Item = &'static ManagedPythonDownload>, Error> {
+        let custom = Self::custom_downloads()?;
+        let builtin = if custom.is_empty() || !only_custom_downloads() {
+            PYTHON_DOWNLOADS
+        } else {
+            &[]
+        };
+        Ok(custom
             .iter()
+            .chain(builtin)
             // TODO(konsti): musl python-build-standalone builds are currently broken (statically
             // linked), so we pretend they don't exist. https://github.com/astral-sh/uv/issues/4242
-            .filter(|download| download.key.libc != Libc::Some(target_lexicon::Environment::Musl))
+            .filter(|download| download.key.libc != Libc::Some(target_lexicon::Environment::Musl)))
+    }
+
+    /// Read the Python downloads from the JSON file set via
+    /// [`EnvVars::UV_PYTHON_DOWNLOADS_JSON_PATH`], if any.
+    ///
+    /// The file is read once and cached for the lifetime of the process.
+    fn custom_downloads() -> Result<&'static [ManagedPythonDownload], Error> {
+        if let Some(downloads) = CUSTOM_PYTHON_DOWNLOADS.get() {
+            return Ok(downloads);
+        }
+        let Some(path) = std::env::var_os(EnvVars::UV_PYTHON_DOWNLOADS_JSON_PATH)
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+        else {
+            return Ok(&[]);
+        };
+        let downloads = Self::read_downloads_json(&path)?;
+        Ok(CUSTOM_PYTHON_DOWNLOADS.get_or_init(|| downloads))
+    }
+
+    /// Read a Python downloads JSON file, in the format of `download-metadata.json`.
+    fn read_downloads_json(path: &Path) -> Result<Vec<ManagedPythonDownload>, Error> {
+        debug!("Reading Python downloads from: {}", path.user_display());
+        let contents =
+            fs_err::read(path).map_err(|err| Error::ReadDownloadsJson(path.to_path_buf(), err))?;
+        let entries: BTreeMap<String, JsonPythonDownload> = serde_json::from_slice(&contents)
+            .map_err(|err| Error::ParseDownloadsJson(path.to_path_buf(), err))?;
+
+        let mut downloads = entries
+            .into_iter()
+            .map(|(name, entry)| {
+                let variant = entry
+                    .variant
+                    .map(|variant| format!("+{variant}"))
+                    .unwrap_or_default();
+                let key = PythonInstallationKey::from_str(&format!(
+                    "{}-{}.{}.{}{}{variant}-{}-{}-{}",
+                    entry.name,
+                    entry.major,
+                    entry.minor,
+                    entry.patch,
+                    entry.prerelease,
+                    entry.os,
+                    entry.arch,
+                    entry.libc,
+                ))
+                .map_err(|err| Error::InvalidDownloadsJsonEntry(name, path.to_path_buf(), err))?;
+                Ok(ManagedPythonDownload {
+                    key,
+                    url: Cow::Owned(entry.url),
+                    sha256: entry.sha256.map(Cow::Owned),
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // Match the order of the built-in downloads, which lists the latest versions first.
+        downloads.sort_by(|a, b| {
+            a.key
+                .implementation
+                .cmp(&b.key.implementation)
+                .then_with(|| b.key.version().version().cmp(a.key.version().version()))
+        });
+        Ok(downloads)
+    }
+
+    /// Return the latest patch release for the minor version of an installation, if it is newer
+    /// than the installed version.
+    ///
+    /// The download must match the implementation, platform, and variant of the installation.
+    /// Pre-releases are only considered if the installation is a pre-release.
+    pub fn latest_patch(
+        key: &PythonInstallationKey,
+    ) -> Result<Option<&'static ManagedPythonDownload>, Error> {
+        let installed = key.version();
+        Ok(Self::iter_all()?
+            .filter(|download| {
+                let candidate = download.key();
+                candidate.is_same_minor(key)
+                    && (candidate.prerelease.is_none() || key.prerelease.is_some())
+            })
+            .max_by(|a, b| a.key().version().version().cmp(b.key().version().version()))
+            .filter(|download| download.key().version().version() > installed.version()))
     }
 
     pub fn url(&self) -> &str {
-        self.url
+        &self.url
     }
 
     pub fn key(&self) -> &PythonInstallationKey {
@@ -455,7 +580,7 @@ impl ManagedPythonDownload {
     }
 
     pub fn sha256(&self) -> Option<&str> {
-        self.sha256
+        self.sha256.as_deref()
     }
 
     /// Download and extract
@@ -495,7 +620,7 @@ impl ManagedPythonDownload {
 
         let mut hashers = self
             .sha256
-            .into_iter()
+            .iter()
             .map(|_| Hasher::from(HashAlgorithm::Sha256))
             .collect::<Vec<_>>();
         let mut hasher = uv_extract::hash::HashReader::new(reader, &mut hashers);
@@ -523,7 +648,7 @@ impl ManagedPythonDownload {
         }
 
         // Check the hash
-        if let Some(expected) = self.sha256 {
+        if let Some(expected) = self.sha256() {
             let actual = HashDigest::from(hashers.pop().unwrap()).digest;
             if !actual.eq_ignore_ascii_case(expected) {
                 return Err(Error::HashMismatch {
@@ -592,7 +717,10 @@ impl ManagedPythonDownload {
                     let Some(suffix) = self.url.strip_prefix(
                         "https://github.com/indygreg/python-build-standalone/releases/download/",
                     ) else {
-                        return Err(Error::Mirror(EnvVars::UV_PYTHON_INSTALL_MIRROR, self.url));
+                        return Err(Error::Mirror(
+                            EnvVars::UV_PYTHON_INSTALL_MIRROR,
+                            self.url.to_string(),
+                        ));
                     };
                     return Ok(Url::parse(
                         format!("{}/{}", mirror.trim_end_matches('/'), suffix).as_str(),
@@ -604,7 +732,10 @@ impl ManagedPythonDownload {
                 if let Ok(mirror) = std::env::var(EnvVars::UV_PYPY_INSTALL_MIRROR) {
                     let Some(suffix) = self.url.strip_prefix("https://downloads.python.org/pypy/")
                     else {
-                        return Err(Error::Mirror(EnvVars::UV_PYPY_INSTALL_MIRROR, self.url));
+                        return Err(Error::Mirror(
+                            EnvVars::UV_PYPY_INSTALL_MIRROR,
+                            self.url.to_string(),
+                        ));
                     };
                     return Ok(Url::parse(
                         format!("{}/{}", mirror.trim_end_matches('/'), suffix).as_str(),
@@ -615,10 +746,16 @@ impl ManagedPythonDownload {
             _ => {}
         }
 
-        Ok(Url::parse(self.url)?)
+        Ok(Url::parse(&self.url)?)
     }
 }
 
+/// Whether to only use the downloads from [`EnvVars::UV_PYTHON_DOWNLOADS_JSON_PATH`].
+fn only_custom_downloads() -> bool {
+    std::env::var(EnvVars::UV_PYTHON_DOWNLOADS_JSON_ONLY)
+        .is_ok_and(|value| matches!(value.to_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+}
+
 impl Error {
     pub(crate) fn from_reqwest(url: Url, err: reqwest::Error) -> Self {
         Self::NetworkError(url, WrappedReqwestError::from(err))
@@ -722,3 +859,6 @@ async fn read_url(
         Ok((Either::Right(stream.compat()), size))
     }
 }
+
+#[cfg(test)]
+mod tests;
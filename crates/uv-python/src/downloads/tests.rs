@@ -0,0 +1,167 @@
+use assert_fs::prelude::*;
+use indoc::{formatdoc, indoc};
+
+use super::*;
+
+#[test]
+fn read_downloads_json() -> anyhow::Result<()> {
+    let temp_dir = assert_fs::TempDir::new()?;
+    let downloads_json = temp_dir.child("downloads.json");
+    downloads_json.write_str(indoc! {r#"
+        {
+          "cpython-3.12.6-linux-x86_64-gnu": {
+            "name": "cpython",
+            "arch": "x86_64",
+            "os": "linux",
+            "libc": "gnu",
+            "major": 3,
+            "minor": 12,
+            "patch": 6,
+            "prerelease": "",
+            "url": "https://example.com/cpython-3.12.6-x86_64-unknown-linux-gnu.tar.gz",
+            "sha256": "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+            "variant": null
+          },
+          "cpython-3.13.0-linux-x86_64-gnu-freethreaded": {
+            "name": "cpython",
+            "arch": "x86_64",
+            "os": "linux",
+            "libc": "gnu",
+            "major": 3,
+            "minor": 13,
+            "patch": 0,
+            "url": "file:///opt/python/cpython-3.13.0t-x86_64-unknown-linux-gnu.tar.gz",
+            "sha256": null,
+            "variant": "freethreaded"
+          }
+        }
+    "#})?;
+
+    let downloads = ManagedPythonDownload::read_downloads_json(downloads_json.path())?;
+    let keys = downloads
+        .iter()
+        .map(|download| download.key().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        keys,
+        [
+            "cpython-3.13.0+freethreaded-linux-x86_64-gnu",
+            "cpython-3.12.6-linux-x86_64-gnu"
+        ]
+    );
+    assert_eq!(
+        downloads[1].url(),
+        "https://example.com/cpython-3.12.6-x86_64-unknown-linux-gnu.tar.gz"
+    );
+    assert_eq!(
+        downloads[1].sha256(),
+        Some("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef")
+    );
+    assert_eq!(downloads[0].sha256(), None);
+
+    // A mirror can't be applied to downloads hosted elsewhere.
+    let err = temp_env::with_var(
+        EnvVars::UV_PYTHON_INSTALL_MIRROR,
+        Some("https://mirror.example.com"),
+        || downloads[1].download_url(),
+    )
+    .unwrap_err();
+    assert!(matches!(err, Error::Mirror(..)), "{err:?}");
+
+    Ok(())
+}
+
+#[test]
+fn read_downloads_json_errors() -> anyhow::Result<()> {
+    let temp_dir = assert_fs::TempDir::new()?;
+
+    let err =
+        ManagedPythonDownload::read_downloads_json(&temp_dir.join("missing.json")).unwrap_err();
+    assert!(matches!(err, Error::ReadDownloadsJson(..)));
+
+    let downloads_json = temp_dir.child("downloads.json");
+    downloads_json.write_str(indoc! {r#"
+        {
+          "cpython-3.12.6-unknown-x86_64-gnu": {
+            "name": "cpython",
+            "arch": "x86_64",
+            "os": "notanos",
+            "libc": "gnu",
+            "major": 3,
+            "minor": 12,
+            "patch": 6,
+            "url": "https://example.com/cpython.tar.gz",
+            "sha256": null,
+            "variant": null
+          }
+        }
+    "#})?;
+    let err = ManagedPythonDownload::read_downloads_json(downloads_json.path()).unwrap_err();
+    assert!(matches!(err, Error::InvalidDownloadsJsonEntry(..)));
+
+    Ok(())
+}
+
+/// The `sha256` of a custom download is verified, and a mismatch fails the download.
+#[tokio::test]
+async fn custom_download_hash_mismatch() -> anyhow::Result<()> {
+    let temp_dir = assert_fs::TempDir::new()?;
+    let archive = fs_err::canonicalize(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../scripts/links/tqdm-999.0.0.tar.gz"),
+    )?;
+    let url = Url::from_file_path(&archive).unwrap();
+
+    let downloads_json = temp_dir.child("downloads.json");
+    downloads_json.write_str(&formatdoc! {r#"
+        {{
+          "cpython-3.12.6-linux-x86_64-gnu": {{
+            "name": "cpython",
+            "arch": "x86_64",
+            "os": "linux",
+            "libc": "gnu",
+            "major": 3,
+            "minor": 12,
+            "patch": 6,
+            "url": "{url}",
+            "sha256": "0000000000000000000000000000000000000000000000000000000000000000",
+            "variant": null
+          }}
+        }}
+        "#,
+        url = url.as_str()
+    })?;
+    let downloads = ManagedPythonDownload::read_downloads_json(downloads_json.path())?;
+
+    let installations = temp_dir.child("installations");
+    let cache = temp_dir.child("cache");
+    fs_err::create_dir_all(&installations)?;
+    fs_err::create_dir_all(&cache)?;
+    let client = uv_client::BaseClientBuilder::new().build();
+    let Err(err) = downloads[0]
+        .fetch(&client, &installations, &cache, false, None)
+        .await
+    else {
+        panic!("Expected the download to fail");
+    };
+    let Error::HashMismatch {
+        expected, actual, ..
+    } = err
+    else {
+        panic!("Expected a hash mismatch, got: {err:?}");
+    };
+    assert_eq!(
+        expected,
+        "0000000000000000000000000000000000000000000000000000000000000000"
+    );
+    assert_eq!(
+        actual,
+        "89fa05cffa7f457658373b85de302d24d0c205ceda2819a8739e324b75e9430b"
+    );
+
+    // Nothing was installed.
+    assert!(!installations
+        .join("cpython-3.12.6-linux-x86_64-gnu")
+        .exists());
+
+    Ok(())
+}
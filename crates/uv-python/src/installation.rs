@@ -306,6 +306,22 @@ impl PythonInstallationKey {
         &self.libc
     }
 
+    pub fn variant(&self) -> &PythonVariant {
+        &self.variant
+    }
+
+    /// Whether the keys refer to the same minor version of the same Python implementation, variant,
+    /// and platform, i.e., if they only differ in the patch version.
+    pub fn is_same_minor(&self, other: &Self) -> bool {
+        self.implementation == other.implementation
+            && self.major == other.major
+            && self.minor == other.minor
+            && self.os == other.os
+            && self.arch == other.arch
+            && self.libc == other.libc
+            && self.variant == other.variant
+    }
+
     /// Return a canonical name for a versioned executable.
     pub fn versioned_executable_name(&self) -> String {
         format!(
@@ -322,7 +338,7 @@ impl fmt::Display for PythonInstallationKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let variant = match self.variant {
             PythonVariant::Default => String::new(),
-            PythonVariant::Freethreaded => format!("+{}", self.variant),
+            PythonVariant::Freethreaded | PythonVariant::Debug => format!("+{}", self.variant),
         };
         write!(
             f,
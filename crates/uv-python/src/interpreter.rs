@@ -50,6 +50,7 @@ pub struct Interpreter {
     prefix: Option<Prefix>,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    debug_enabled: bool,
 }
 
 impl Interpreter {
@@ -73,6 +74,7 @@ impl Interpreter {
             sys_base_exec_prefix: info.sys_base_exec_prefix,
             pointer_size: info.pointer_size,
             gil_disabled: info.gil_disabled,
+            debug_enabled: info.debug_enabled,
             sys_base_prefix: info.sys_base_prefix,
             sys_base_executable: info.sys_base_executable,
             sys_executable: info.sys_executable,
@@ -169,6 +171,8 @@ impl Interpreter {
     pub fn variant(&self) -> PythonVariant {
         if self.gil_disabled() {
             PythonVariant::Freethreaded
+        } else if self.debug_enabled() {
+            PythonVariant::Debug
         } else {
             PythonVariant::default()
         }
@@ -199,6 +203,7 @@ impl Interpreter {
                 self.implementation_tuple(),
                 self.manylinux_compatible,
                 self.gil_disabled,
+                self.debug_enabled,
             )?;
             self.tags.set(tags).expect("tags should not be set");
         }
@@ -414,6 +419,15 @@ impl Interpreter {
         self.gil_disabled
     }
 
+    /// Return whether this is a debug build of Python, i.e., built with `--with-pydebug`, as
+    /// specified by the sysconfig var `Py_DEBUG`.
+    ///
+    /// Debug builds use a `d` abiflag, e.g., `cp313d`, but can also load extension modules built
+    /// for the release ABI.
+    pub fn debug_enabled(&self) -> bool {
+        self.debug_enabled
+    }
+
     /// Return the `--target` directory for this interpreter, if any.
     pub fn target(&self) -> Option<&Target> {
         self.target.as_ref()
@@ -607,6 +621,7 @@ struct InterpreterInfo {
     stdlib: PathBuf,
     pointer_size: PointerSize,
     gil_disabled: bool,
+    debug_enabled: bool,
 }
 
 impl InterpreterInfo {
@@ -322,9 +322,12 @@ impl ManagedPythonInstallation {
             }
         };
 
-        // On Windows, the executable is just `python.exe` even for alternative variants
+        // On Windows, the executable is just `python.exe` even for alternative variants, except
+        // for debug builds, which use `python_d.exe`
         let variant = if cfg!(unix) {
             self.key.variant.suffix()
+        } else if self.key.variant == PythonVariant::Debug {
+            "_d"
         } else {
             ""
         };
@@ -461,7 +464,11 @@ impl ManagedPythonInstallation {
         let stdlib = if matches!(self.key.os, Os(target_lexicon::OperatingSystem::Windows)) {
             self.python_dir().join("Lib")
         } else {
-            let lib_suffix = self.key.variant.suffix();
+            // Debug builds share the standard library directory with release builds
+            let lib_suffix = match self.key.variant {
+                PythonVariant::Default | PythonVariant::Debug => "",
+                PythonVariant::Freethreaded => self.key.variant.suffix(),
+            };
             let python = if matches!(
                 self.key.implementation,
                 LenientImplementationName::Known(ImplementationName::PyPy)
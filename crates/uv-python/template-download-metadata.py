@@ -103,8 +103,6 @@ def main() -> None:
     data["versions"] = [
         {"key": key, "value": prepare_value(value)}
         for key, value in json.loads(VERSION_METADATA.read_text()).items()
-        # Exclude debug variants for now, we don't support them in the Rust side
-        if value["variant"] != "debug"
     ]
 
     # Render the template
@@ -15,9 +15,12 @@ use uv_distribution_types::{
 use uv_normalize::PackageName;
 use uv_pep440::Version;
 use uv_platform_tags::{TagCompatibility, Tags};
-use uv_pypi_types::HashDigest;
+use uv_pypi_types::{HashDigest, Yanked};
 use uv_types::HashStrategy;
 
+use crate::yanks::AllowedYanks;
+use crate::ExcludeNewer;
+
 /// A set of [`PrioritizedDist`] from a `--find-links` entry, indexed by [`PackageName`]
 /// and [`Version`].
 #[derive(Debug, Clone, Default)]
@@ -37,6 +40,7 @@ impl FlatIndex {
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        exclude_newer: Option<&ExcludeNewer>,
     ) -> Self {
         // Collect compatible distributions.
         let mut index = FxHashMap::default();
@@ -49,6 +53,7 @@ impl FlatIndex {
                 tags,
                 hasher,
                 build_options,
+                exclude_newer,
                 url,
             );
         }
@@ -66,22 +71,49 @@ impl FlatIndex {
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        exclude_newer: Option<&ExcludeNewer>,
         index: IndexUrl,
     ) {
-        // No `requires-python` here: for source distributions, we don't have that information;
-        // for wheels, we read it lazily only when selected.
+        // No `requires-python` here: it's only available if provided by the `--find-links`
+        // metadata, in which case it's read lazily when the distribution is selected.
+
+        // Support resolving as if it were an earlier timestamp. Unlike registry files, files
+        // without an upload time are retained, since `--find-links` entries rarely include one.
+        let excluded = exclude_newer.and_then(|exclude_newer| {
+            file.upload_time_utc_ms
+                .filter(|&upload_time| upload_time >= exclude_newer.timestamp_millis())
+        });
+
+        // Yanked files are set aside, since whether they're allowed depends on the requirements
+        // of the resolution (see: [`FlatIndex::get`]).
+        let yanked = file.yanked.clone().filter(Yanked::is_yanked);
+
         match filename {
             DistFilename::WheelFilename(filename) => {
                 let version = filename.version.clone();
 
-                let compatibility =
-                    Self::wheel_compatibility(&filename, &file.hashes, tags, hasher, build_options);
+                let compatibility = Self::wheel_compatibility(
+                    &filename,
+                    &file.hashes,
+                    tags,
+                    hasher,
+                    build_options,
+                    excluded,
+                );
                 let dist = RegistryBuiltWheel {
                     filename,
                     file: Box::new(file),
                     index,
                 };
-                match distributions.0.entry(version) {
+                if let Some(yanked) = yanked {
+                    distributions.yanked.push(YankedDist {
+                        version,
+                        yanked,
+                        dist: YankedDistKind::Built(dist, compatibility),
+                    });
+                    return;
+                }
+                match distributions.dists.entry(version) {
                     Entry::Occupied(mut entry) => {
                         entry.get_mut().insert_built(dist, vec![], compatibility);
                     }
@@ -91,8 +123,13 @@ impl FlatIndex {
                 }
             }
             DistFilename::SourceDistFilename(filename) => {
-                let compatibility =
-                    Self::source_dist_compatibility(&filename, &file.hashes, hasher, build_options);
+                let compatibility = Self::source_dist_compatibility(
+                    &filename,
+                    &file.hashes,
+                    hasher,
+                    build_options,
+                    excluded,
+                );
                 let dist = RegistrySourceDist {
                     name: filename.name.clone(),
                     version: filename.version.clone(),
@@ -101,7 +138,15 @@ impl FlatIndex {
                     index,
                     wheels: vec![],
                 };
-                match distributions.0.entry(filename.version) {
+                if let Some(yanked) = yanked {
+                    distributions.yanked.push(YankedDist {
+                        version: filename.version,
+                        yanked,
+                        dist: YankedDistKind::Source(dist, compatibility),
+                    });
+                    return;
+                }
+                match distributions.dists.entry(filename.version) {
                     Entry::Occupied(mut entry) => {
                         entry.get_mut().insert_source(dist, vec![], compatibility);
                     }
@@ -118,12 +163,20 @@ impl FlatIndex {
         hashes: &[HashDigest],
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        excluded: Option<i64>,
     ) -> SourceDistCompatibility {
         // Check if source distributions are allowed for this package.
         if build_options.no_build_package(&filename.name) {
             return SourceDistCompatibility::Incompatible(IncompatibleSource::NoBuild);
         }
 
+        // Check if after upload time cutoff.
+        if let Some(upload_time) = excluded {
+            return SourceDistCompatibility::Incompatible(IncompatibleSource::ExcludeNewer(Some(
+                upload_time,
+            )));
+        }
+
         // Check if hashes line up
         let hash = if let HashPolicy::Validate(required) =
             hasher.get_package(&filename.name, &filename.version)
@@ -148,12 +201,20 @@ impl FlatIndex {
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        excluded: Option<i64>,
     ) -> WheelCompatibility {
         // Check if binaries are allowed for this package.
         if build_options.no_binary_package(&filename.name) {
             return WheelCompatibility::Incompatible(IncompatibleWheel::NoBinary);
         }
 
+        // Check if after upload time cutoff.
+        if let Some(upload_time) = excluded {
+            return WheelCompatibility::Incompatible(IncompatibleWheel::ExcludeNewer(Some(
+                upload_time,
+            )));
+        }
+
         // Determine a compatibility for the wheel based on tags.
         let priority = match tags {
             Some(tags) => match filename.compatibility(tags) {
@@ -187,8 +248,62 @@ impl FlatIndex {
     }
 
     /// Get the [`FlatDistributions`] for the given package name.
-    pub fn get(&self, package_name: &PackageName) -> Option<&FlatDistributions> {
-        self.index.get(package_name)
+    ///
+    /// Yanked distributions are only considered compatible if the version is explicitly allowed
+    /// (e.g., via an exact pin), as with distributions from a registry.
+    pub fn get(
+        &self,
+        package_name: &PackageName,
+        allowed_yanks: &AllowedYanks,
+    ) -> Option<FlatDistributions> {
+        let mut distributions = self.index.get(package_name)?.clone();
+        for YankedDist {
+            version,
+            yanked,
+            dist,
+        } in std::mem::take(&mut distributions.yanked)
+        {
+            let allowed = allowed_yanks.contains(package_name, &version);
+            let entry = distributions.dists.entry(version);
+            match dist {
+                YankedDistKind::Built(dist, compatibility) => {
+                    // Other incompatibilities take precedence, as for registry distributions.
+                    let compatibility = match compatibility {
+                        WheelCompatibility::Compatible(..) if !allowed => {
+                            WheelCompatibility::Incompatible(IncompatibleWheel::Yanked(yanked))
+                        }
+                        compatibility => compatibility,
+                    };
+                    match entry {
+                        Entry::Occupied(mut entry) => {
+                            entry.get_mut().insert_built(dist, vec![], compatibility);
+                        }
+                        Entry::Vacant(entry) => {
+                            entry.insert(PrioritizedDist::from_built(dist, vec![], compatibility));
+                        }
+                    }
+                }
+                YankedDistKind::Source(dist, compatibility) => {
+                    let compatibility = match compatibility {
+                        SourceDistCompatibility::Compatible(..) if !allowed => {
+                            SourceDistCompatibility::Incompatible(IncompatibleSource::Yanked(
+                                yanked,
+                            ))
+                        }
+                        compatibility => compatibility,
+                    };
+                    match entry {
+                        Entry::Occupied(mut entry) => {
+                            entry.get_mut().insert_source(dist, vec![], compatibility);
+                        }
+                        Entry::Vacant(entry) => {
+                            entry.insert(PrioritizedDist::from_source(dist, vec![], compatibility));
+                        }
+                    }
+                }
+            }
+        }
+        Some(distributions)
     }
 
     /// Returns `true` if there are any offline `--find-links` entries.
@@ -200,15 +315,20 @@ impl FlatIndex {
 /// A set of [`PrioritizedDist`] from a `--find-links` entry for a single package, indexed
 /// by [`Version`].
 #[derive(Debug, Clone, Default)]
-pub struct FlatDistributions(BTreeMap<Version, PrioritizedDist>);
+pub struct FlatDistributions {
+    /// The distributions, indexed by version.
+    dists: BTreeMap<Version, PrioritizedDist>,
+    /// The yanked distributions, which are merged into `dists` once the allowed yanks are known.
+    yanked: Vec<YankedDist>,
+}
 
 impl FlatDistributions {
     pub fn iter(&self) -> impl Iterator<Item = (&Version, &PrioritizedDist)> {
-        self.0.iter()
+        self.dists.iter()
     }
 
     pub fn remove(&mut self, version: &Version) -> Option<PrioritizedDist> {
-        self.0.remove(version)
+        self.dists.remove(version)
     }
 }
 
@@ -217,12 +337,27 @@ impl IntoIterator for FlatDistributions {
     type IntoIter = std::collections::btree_map::IntoIter<Version, PrioritizedDist>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.dists.into_iter()
     }
 }
 
 impl From<FlatDistributions> for BTreeMap<Version, PrioritizedDist> {
     fn from(distributions: FlatDistributions) -> Self {
-        distributions.0
+        distributions.dists
     }
 }
+
+/// A yanked distribution from a `--find-links` entry, alongside its compatibility if it were
+/// not yanked.
+#[derive(Debug, Clone)]
+struct YankedDist {
+    version: Version,
+    yanked: Yanked,
+    dist: YankedDistKind,
+}
+
+#[derive(Debug, Clone)]
+enum YankedDistKind {
+    Built(RegistryBuiltWheel, WheelCompatibility),
+    Source(RegistrySourceDist, SourceDistCompatibility),
+}
@@ -18,8 +18,9 @@ pub use resolution::{
 };
 pub use resolution_mode::ResolutionMode;
 pub use resolver::{
-    BuildId, DefaultResolverProvider, InMemoryIndex, MetadataResponse, PackageVersionsResult,
-    Reporter as ResolverReporter, Resolver, ResolverMarkers, ResolverProvider, VersionsResponse,
+    BuildId, ChromeTrace, DefaultResolverProvider, InMemoryIndex, MetadataResponse,
+    PackageVersionsResult, Reporter as ResolverReporter, Resolver, ResolverMarkers,
+    ResolverProfile, ResolverProfileReport, ResolverProvider, VersionsResponse,
     WheelMetadataResult,
 };
 pub use version_map::VersionMap;
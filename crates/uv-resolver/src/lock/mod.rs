@@ -706,6 +706,84 @@ impl Lock {
         Ok(Resolution::new(map, hashes, diagnostics))
     }
 
+    /// Returns the [`Package`] with the given name that applies to the given marker environment.
+    ///
+    /// Returns `Ok(None)` if there is no matching package.
+    pub fn find_package(
+        &self,
+        name: &PackageName,
+        marker_env: &ResolverMarkerEnvironment,
+    ) -> Result<Option<&Package>, LockError> {
+        Ok(self
+            .find_by_markers(name, marker_env)
+            .map_err(|reason| LockErrorKind::FindPackage {
+                name: name.clone(),
+                reason,
+            })?)
+    }
+
+    /// Convert the dependency subgraph rooted at the given [`Package`] to a [`Resolution`] using
+    /// the given marker environment and tags.
+    ///
+    /// Unlike [`Lock::to_resolution`], the workspace members are only included if they're
+    /// dependencies of the root package.
+    pub fn to_package_resolution(
+        &self,
+        root: &Package,
+        extras: &[ExtraName],
+        install_path: &Path,
+        marker_env: &ResolverMarkerEnvironment,
+        tags: &Tags,
+        build_options: &BuildOptions,
+    ) -> Result<Resolution, LockError> {
+        let mut queue: VecDeque<(&Package, Option<&ExtraName>)> = VecDeque::new();
+        let mut seen = FxHashSet::default();
+
+        // Add the base package, along with any extras.
+        seen.insert((&root.id, None));
+        queue.push_back((root, None));
+        for extra in extras {
+            if root.optional_dependencies.contains_key(extra) {
+                seen.insert((&root.id, Some(extra)));
+                queue.push_back((root, Some(extra)));
+            }
+        }
+
+        let mut map = BTreeMap::default();
+        let mut hashes = BTreeMap::default();
+        while let Some((dist, extra)) = queue.pop_front() {
+            let deps = if let Some(extra) = extra {
+                Either::Left(dist.optional_dependencies.get(extra).into_iter().flatten())
+            } else {
+                Either::Right(dist.dependencies.iter())
+            };
+            for dep in deps {
+                if dep.complexified_marker.evaluate(marker_env, &[]) {
+                    let dep_dist = self.find_by_id(&dep.package_id);
+                    if seen.insert((&dep.package_id, None)) {
+                        queue.push_back((dep_dist, None));
+                    }
+                    for extra in &dep.extra {
+                        if seen.insert((&dep.package_id, Some(extra))) {
+                            queue.push_back((dep_dist, Some(extra)));
+                        }
+                    }
+                }
+            }
+            map.insert(
+                dist.id.name.clone(),
+                ResolvedDist::Installable(dist.to_dist(
+                    install_path,
+                    TagPolicy::Required(tags),
+                    build_options,
+                )?),
+            );
+            hashes.insert(dist.id.name.clone(), dist.hashes());
+        }
+        let diagnostics = vec![];
+        Ok(Resolution::new(map, hashes, diagnostics))
+    }
+
     /// Returns the TOML representation of this lockfile.
     pub fn to_toml(&self) -> anyhow::Result<String> {
         // We construct a TOML document manually instead of going through Serde to enable
@@ -2170,6 +2248,37 @@ impl Package {
         }
     }
 
+    /// Returns the [`File`](uv_distribution_types::File) for each wheel and source distribution
+    /// of this package, if the package was sourced from a registry.
+    ///
+    /// Returns an empty list for packages with any other source (e.g., Git or direct URL sources).
+    pub fn registry_files(
+        &self,
+        workspace_root: &Path,
+    ) -> Result<Vec<uv_distribution_types::File>, LockError> {
+        let Source::Registry(source) = &self.id.source else {
+            return Ok(vec![]);
+        };
+
+        let mut files = self
+            .wheels
+            .iter()
+            .map(|wheel| {
+                wheel
+                    .to_registry_dist(source, workspace_root)
+                    .map(|wheel| *wheel.file)
+            })
+            .collect::<Result<Vec<_>, LockError>>()?;
+
+        if let Some(uv_distribution_types::SourceDist::Registry(sdist)) =
+            self.to_source_dist(workspace_root)?
+        {
+            files.push(*sdist.file);
+        }
+
+        Ok(files)
+    }
+
     /// Returns the [`PackageName`] of the package.
     pub fn name(&self) -> &PackageName {
         &self.id.name
@@ -4129,6 +4238,15 @@ enum LockErrorKind {
         /// The ID of the package.
         name: PackageName,
     },
+    /// An error that occurs when a package can't be identified in the lockfile for a given
+    /// marker environment.
+    #[error("Failed to find `{name}` in the lockfile: {reason}")]
+    FindPackage {
+        /// The name of the package.
+        name: PackageName,
+        /// The reason the package couldn't be identified.
+        reason: String,
+    },
     /// An error that occurs when a root package can't be found.
     #[error("Could not find root package `{name}`")]
     MissingRootPackage {
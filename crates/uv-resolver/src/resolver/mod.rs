@@ -63,6 +63,7 @@ use crate::resolver::batch_prefetch::BatchPrefetcher;
 use crate::resolver::groups::Groups;
 pub use crate::resolver::index::InMemoryIndex;
 use crate::resolver::indexes::Indexes;
+pub use crate::resolver::profile::{ChromeTrace, ResolverProfile, ResolverProfileReport};
 pub use crate::resolver::provider::{
     DefaultResolverProvider, MetadataResponse, PackageVersionsResult, ResolverProvider,
     VersionsResponse, WheelMetadataResult,
@@ -79,6 +80,7 @@ mod groups;
 mod index;
 mod indexes;
 mod locals;
+mod profile;
 mod provider;
 mod reporter;
 mod resolver_markers;
@@ -121,6 +123,8 @@ struct ResolverState<InstalledPackages: InstalledPackagesProvider> {
     options: Options,
     /// The reporter to use for this resolver.
     reporter: Option<Arc<dyn Reporter>>,
+    /// The profile to record resolver statistics into, if any.
+    profile: Option<ResolverProfile>,
 }
 
 impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
@@ -228,6 +232,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             incomplete_packages: DashMap::default(),
             options,
             reporter: None,
+            profile: None,
         };
         Ok(Self { state, provider })
     }
@@ -238,11 +243,29 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
         let reporter = Arc::new(reporter);
 
         Self {
-            state: ResolverState {
+            provider: self.provider.with_reporter(Facade {
                 reporter: Some(reporter.clone()),
+                profile: self.state.profile.clone(),
+            }),
+            state: ResolverState {
+                reporter: Some(reporter),
+                ..self.state
+            },
+        }
+    }
+
+    /// Set the [`ResolverProfile`] to record resolver statistics into.
+    #[must_use]
+    pub fn with_profile(self, profile: ResolverProfile) -> Self {
+        Self {
+            provider: self.provider.with_reporter(Facade {
+                reporter: self.state.reporter.clone(),
+                profile: Some(profile.clone()),
+            }),
+            state: ResolverState {
+                profile: Some(profile),
                 ..self.state
             },
-            provider: self.provider.with_reporter(Facade { reporter }),
         }
     }
 
@@ -369,6 +392,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         state.markers,
                         start.elapsed().as_secs_f32()
                     );
+                    if let Some(profile) = &self.profile {
+                        profile.split(state.markers.to_string(), start);
+                    }
 
                     let resolution = state.into_resolution();
 
@@ -472,6 +498,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
 
                 // Only consider registry packages for prefetch.
                 if url.is_none() {
+                    let prefetch_start = Instant::now();
                     prefetcher.prefetch_batches(
                         &state.next,
                         index,
@@ -488,8 +515,16 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                         &self.selector,
                         &state.markers,
                     )?;
+                    if let (Some(profile), Some(name)) = (&self.profile, state.next.name()) {
+                        profile.prefetch(name, prefetch_start);
+                    }
                 }
 
+                // A package can only be decided again once conflict resolution has discarded its
+                // previous decision.
+                if state.added_dependencies.contains_key(&state.next) {
+                    self.on_backtrack(&state.next);
+                }
                 self.on_progress(&state.next, &version);
 
                 if !state
@@ -683,6 +718,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             forks.len()
         );
         assert!(forks.len() >= 2);
+        if let Some(profile) = &self.profile {
+            profile.fork(
+                &current_state.next.to_string(),
+                version,
+                diverging_packages,
+                forks.len(),
+            );
+        }
         // This is a somewhat tortured technique to ensure
         // that our resolver state is only cloned as much
         // as it needs to be. We basically move the state
@@ -1010,6 +1053,7 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
         request_sink: &Sender<Request>,
     ) -> Result<Option<ResolverVersion>, ResolveError> {
         // Wait for the metadata to be available.
+        let wait_start = Instant::now();
         let versions_response = if let Some(index) = index {
             self.index
                 .explicit()
@@ -1021,6 +1065,9 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 .wait_blocking(name)
                 .ok_or_else(|| ResolveError::UnregisteredTask(name.to_string()))?
         };
+        if let Some(profile) = &self.profile {
+            profile.metadata_wait(name, wait_start);
+        }
         visited.insert(name.clone());
 
         let version_maps = match *versions_response {
@@ -1274,11 +1321,15 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
                 }
 
                 // Wait for the metadata to be available.
+                let wait_start = Instant::now();
                 let response = self
                     .index
                     .distributions()
                     .wait_blocking(&version_id)
                     .ok_or_else(|| ResolveError::UnregisteredTask(version_id.to_string()))?;
+                if let Some(profile) = &self.profile {
+                    profile.metadata_wait(name, wait_start);
+                }
 
                 let metadata = match &*response {
                     MetadataResponse::Found(archive) => &archive.metadata,
@@ -2062,6 +2113,11 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
     }
 
     fn on_progress(&self, package: &PubGrubPackage, version: &Version) {
+        if let Some(profile) = self.profile.as_ref() {
+            if let PubGrubPackageInner::Package { name, .. } = &**package {
+                profile.decision(name, version);
+            }
+        }
         if let Some(reporter) = self.reporter.as_ref() {
             match &**package {
                 PubGrubPackageInner::Root(_) => {}
@@ -2081,6 +2137,14 @@ impl<InstalledPackages: InstalledPackagesProvider> ResolverState<InstalledPackag
             reporter.on_complete();
         }
     }
+
+    fn on_backtrack(&self, package: &PubGrubPackage) {
+        if let Some(profile) = self.profile.as_ref() {
+            if let Some(name) = package.name_no_root() {
+                profile.backtrack(name);
+            }
+        }
+    }
 }
 
 /// State that is used during unit propagation in the resolver, one instance per fork.
@@ -0,0 +1,377 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use uv_distribution_types::BuildableSource;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+
+/// The thread on which a profiled event occurred, used to lay out the Chrome trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lane {
+    /// The PubGrub solver thread.
+    Solver,
+    /// The asynchronous fetcher, which retrieves package metadata and builds source
+    /// distributions.
+    Fetcher,
+}
+
+impl Lane {
+    fn tid(self) -> u32 {
+        match self {
+            Lane::Solver => 1,
+            Lane::Fetcher => 2,
+        }
+    }
+}
+
+/// A single timed (or instantaneous) event recorded during resolution.
+#[derive(Debug, Clone)]
+struct Event {
+    name: String,
+    category: &'static str,
+    lane: Lane,
+    start: Duration,
+    duration: Option<Duration>,
+    args: BTreeMap<&'static str, String>,
+}
+
+#[derive(Debug, Default)]
+struct ProfileState {
+    packages: BTreeMap<PackageName, PackageStats>,
+    forks: Vec<ForkReport>,
+    builds: Vec<BuildReport>,
+    /// The start time of any in-progress source distribution builds.
+    pending_builds: BTreeMap<String, Instant>,
+    splits: Vec<SplitReport>,
+    events: Vec<Event>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+struct PackageStats {
+    decisions: usize,
+    backtracks: usize,
+    #[serde(serialize_with = "serialize_secs")]
+    metadata_wait: Duration,
+    #[serde(serialize_with = "serialize_secs")]
+    prefetch: Duration,
+}
+
+/// Collects decision counts and timings during resolution, to help diagnose slow resolutions.
+///
+/// The profile is cheap to clone and safe to share between the solver thread and the fetcher.
+#[derive(Debug, Clone)]
+pub struct ResolverProfile {
+    start: Instant,
+    state: Arc<Mutex<ProfileState>>,
+}
+
+impl Default for ResolverProfile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResolverProfile {
+    /// Create a new, empty profile. Timestamps are measured relative to this call.
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            state: Arc::new(Mutex::new(ProfileState::default())),
+        }
+    }
+
+    /// Record that the solver decided on a version of a package.
+    pub(crate) fn decision(&self, name: &PackageName, version: &Version) {
+        let now = self.start.elapsed();
+        let mut state = self.state.lock().unwrap();
+        state.packages.entry(name.clone()).or_default().decisions += 1;
+        state.events.push(Event {
+            name: format!("{name}=={version}"),
+            category: "decision",
+            lane: Lane::Solver,
+            start: now,
+            duration: None,
+            args: BTreeMap::default(),
+        });
+    }
+
+    /// Record that the solver backtracked past a package's decision, such that it had to be decided
+    /// again.
+    pub(crate) fn backtrack(&self, name: &PackageName) {
+        let now = self.start.elapsed();
+        let mut state = self.state.lock().unwrap();
+        state.packages.entry(name.clone()).or_default().backtracks += 1;
+        state.events.push(Event {
+            name: name.to_string(),
+            category: "backtrack",
+            lane: Lane::Solver,
+            start: now,
+            duration: None,
+            args: BTreeMap::default(),
+        });
+    }
+
+    /// Record that the resolution was split into multiple forks.
+    pub(crate) fn fork(
+        &self,
+        package: &str,
+        version: &Version,
+        diverging_packages: &[PackageName],
+        forks: usize,
+    ) {
+        let now = self.start.elapsed();
+        let mut state = self.state.lock().unwrap();
+        state.forks.push(ForkReport {
+            package: package.to_string(),
+            version: version.to_string(),
+            diverging_packages: diverging_packages.to_vec(),
+            forks,
+        });
+        state.events.push(Event {
+            name: format!("{package}=={version}"),
+            category: "fork",
+            lane: Lane::Solver,
+            start: now,
+            duration: None,
+            args: BTreeMap::from([
+                ("forks", forks.to_string()),
+                (
+                    "diverging_packages",
+                    diverging_packages
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+            ]),
+        });
+    }
+
+    /// Record the time spent solving a single fork (or the entire resolution, if it never forked).
+    pub(crate) fn split(&self, markers: String, start: Instant) {
+        let (offset, duration) = self.span(start);
+        let mut state = self.state.lock().unwrap();
+        state.splits.push(SplitReport {
+            markers: markers.clone(),
+            duration,
+        });
+        state.events.push(Event {
+            name: markers,
+            category: "split",
+            lane: Lane::Solver,
+            start: offset,
+            duration: Some(duration),
+            args: BTreeMap::default(),
+        });
+    }
+
+    /// Record the time the solver spent blocked on package or distribution metadata.
+    pub(crate) fn metadata_wait(&self, name: &PackageName, start: Instant) {
+        let (offset, duration) = self.span(start);
+        let mut state = self.state.lock().unwrap();
+        state
+            .packages
+            .entry(name.clone())
+            .or_default()
+            .metadata_wait += duration;
+        state.events.push(Event {
+            name: name.to_string(),
+            category: "metadata",
+            lane: Lane::Solver,
+            start: offset,
+            duration: Some(duration),
+            args: BTreeMap::default(),
+        });
+    }
+
+    /// Record the time the solver spent scheduling batch prefetches for a package.
+    pub(crate) fn prefetch(&self, name: &PackageName, start: Instant) {
+        let (offset, duration) = self.span(start);
+        let mut state = self.state.lock().unwrap();
+        state.packages.entry(name.clone()).or_default().prefetch += duration;
+        state.events.push(Event {
+            name: name.to_string(),
+            category: "prefetch",
+            lane: Lane::Solver,
+            start: offset,
+            duration: Some(duration),
+            args: BTreeMap::default(),
+        });
+    }
+
+    /// Record that a source distribution build started.
+    pub(crate) fn build_start(&self, source: &BuildableSource) {
+        let mut state = self.state.lock().unwrap();
+        state
+            .pending_builds
+            .insert(source.to_string(), Instant::now());
+    }
+
+    /// Record that a source distribution build completed.
+    pub(crate) fn build_complete(&self, source: &BuildableSource) {
+        let dist = source.to_string();
+        let mut state = self.state.lock().unwrap();
+        let Some(start) = state.pending_builds.remove(&dist) else {
+            return;
+        };
+        let (offset, duration) = self.span(start);
+        state.builds.push(BuildReport {
+            distribution: dist.clone(),
+            duration,
+        });
+        state.events.push(Event {
+            name: dist,
+            category: "build",
+            lane: Lane::Fetcher,
+            start: offset,
+            duration: Some(duration),
+            args: BTreeMap::default(),
+        });
+    }
+
+    /// Return the offset of `start` relative to the beginning of the profile, along with the time
+    /// elapsed since `start`.
+    fn span(&self, start: Instant) -> (Duration, Duration) {
+        (start.saturating_duration_since(self.start), start.elapsed())
+    }
+
+    /// Returns `true` if nothing was recorded, e.g., because no resolution was performed.
+    pub fn is_empty(&self) -> bool {
+        self.state.lock().unwrap().events.is_empty()
+    }
+
+    /// Summarize the profile as a [`ResolverProfileReport`].
+    pub fn report(&self) -> ResolverProfileReport {
+        let state = self.state.lock().unwrap();
+        ResolverProfileReport {
+            duration: self.start.elapsed(),
+            decisions: state.packages.values().map(|stats| stats.decisions).sum(),
+            backtracks: state.packages.values().map(|stats| stats.backtracks).sum(),
+            packages: state
+                .packages
+                .iter()
+                .map(|(name, stats)| PackageReport {
+                    name: name.clone(),
+                    stats: stats.clone(),
+                })
+                .collect(),
+            forks: state.forks.clone(),
+            splits: state.splits.clone(),
+            builds: state.builds.clone(),
+        }
+    }
+
+    /// Render the profile as a [`ChromeTrace`], viewable in `chrome://tracing` or Perfetto.
+    pub fn chrome_trace(&self) -> ChromeTrace {
+        let state = self.state.lock().unwrap();
+        let trace_events = state
+            .events
+            .iter()
+            .map(|event| TraceEvent {
+                name: event.name.clone(),
+                cat: event.category,
+                ph: if event.duration.is_some() { "X" } else { "i" },
+                ts: micros(event.start),
+                dur: event.duration.map(micros),
+                s: event.duration.is_none().then_some("t"),
+                pid: 1,
+                tid: event.lane.tid(),
+                args: event.args.clone(),
+            })
+            .collect();
+        ChromeTrace {
+            trace_events,
+            display_time_unit: "ms",
+        }
+    }
+}
+
+/// A summary of a profiled resolution.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolverProfileReport {
+    /// The total time elapsed since the profile was created.
+    #[serde(serialize_with = "serialize_secs")]
+    duration: Duration,
+    /// The total number of decisions made by the solver.
+    decisions: usize,
+    /// The total number of backtracks performed by the solver.
+    backtracks: usize,
+    /// Per-package decision counts and timings.
+    packages: Vec<PackageReport>,
+    /// The forks created during resolution.
+    forks: Vec<ForkReport>,
+    /// The time spent solving each fork.
+    splits: Vec<SplitReport>,
+    /// The source distributions that were built during resolution.
+    builds: Vec<BuildReport>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct PackageReport {
+    name: PackageName,
+    #[serde(flatten)]
+    stats: PackageStats,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ForkReport {
+    package: String,
+    version: String,
+    diverging_packages: Vec<PackageName>,
+    forks: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SplitReport {
+    markers: String,
+    #[serde(serialize_with = "serialize_secs")]
+    duration: Duration,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct BuildReport {
+    distribution: String,
+    #[serde(serialize_with = "serialize_secs")]
+    duration: Duration,
+}
+
+/// A profile in the Chrome trace event format.
+///
+/// See: <https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU>
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChromeTrace {
+    trace_events: Vec<TraceEvent>,
+    display_time_unit: &'static str,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct TraceEvent {
+    name: String,
+    cat: &'static str,
+    ph: &'static str,
+    ts: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dur: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    s: Option<&'static str>,
+    pid: u32,
+    tid: u32,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    args: BTreeMap<&'static str, String>,
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn micros(duration: Duration) -> u64 {
+    duration.as_micros() as u64
+}
+
+fn serialize_secs<S: serde::Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
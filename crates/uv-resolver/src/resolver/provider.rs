@@ -137,38 +137,41 @@ impl<'a, Context: BuildContext> ResolverProvider for DefaultResolverProvider<'a,
                             &self.allowed_yanks,
                             &self.hasher,
                             self.exclude_newer.as_ref(),
-                            self.flat_index.get(package_name).cloned(),
+                            self.flat_index.get(package_name, &self.allowed_yanks),
                             self.build_options,
                         )
                     })
                     .collect(),
             )),
-            Err(err) => match err.into_kind() {
-                uv_client::ErrorKind::PackageNotFound(_) => {
-                    if let Some(flat_index) = self.flat_index.get(package_name).cloned() {
-                        Ok(VersionsResponse::Found(vec![VersionMap::from(flat_index)]))
-                    } else {
-                        Ok(VersionsResponse::NotFound)
+            Err(err) => {
+                let allowed_yanks = &self.allowed_yanks;
+                match err.into_kind() {
+                    uv_client::ErrorKind::PackageNotFound(_) => {
+                        if let Some(flat_dists) = self.flat_index.get(package_name, allowed_yanks) {
+                            Ok(VersionsResponse::Found(vec![VersionMap::from(flat_dists)]))
+                        } else {
+                            Ok(VersionsResponse::NotFound)
+                        }
                     }
-                }
-                uv_client::ErrorKind::NoIndex(_) => {
-                    if let Some(flat_index) = self.flat_index.get(package_name).cloned() {
-                        Ok(VersionsResponse::Found(vec![VersionMap::from(flat_index)]))
-                    } else if self.flat_index.offline() {
-                        Ok(VersionsResponse::Offline)
-                    } else {
-                        Ok(VersionsResponse::NoIndex)
+                    uv_client::ErrorKind::NoIndex(_) => {
+                        if let Some(flat_dists) = self.flat_index.get(package_name, allowed_yanks) {
+                            Ok(VersionsResponse::Found(vec![VersionMap::from(flat_dists)]))
+                        } else if self.flat_index.offline() {
+                            Ok(VersionsResponse::Offline)
+                        } else {
+                            Ok(VersionsResponse::NoIndex)
+                        }
                     }
-                }
-                uv_client::ErrorKind::Offline(_) => {
-                    if let Some(flat_index) = self.flat_index.get(package_name).cloned() {
-                        Ok(VersionsResponse::Found(vec![VersionMap::from(flat_index)]))
-                    } else {
-                        Ok(VersionsResponse::Offline)
+                    uv_client::ErrorKind::Offline(_) => {
+                        if let Some(flat_dists) = self.flat_index.get(package_name, allowed_yanks) {
+                            Ok(VersionsResponse::Found(vec![VersionMap::from(flat_dists)]))
+                        } else {
+                            Ok(VersionsResponse::Offline)
+                        }
                     }
+                    kind => Err(kind.into()),
                 }
-                kind => Err(kind.into()),
-            },
+            }
         }
     }
 
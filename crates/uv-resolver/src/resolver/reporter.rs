@@ -5,6 +5,8 @@ use url::Url;
 use uv_distribution_types::{BuildableSource, VersionOrUrlRef};
 use uv_normalize::PackageName;
 
+use crate::ResolverProfile;
+
 pub type BuildId = usize;
 
 pub trait Reporter: Send + Sync {
@@ -38,36 +40,59 @@ pub trait Reporter: Send + Sync {
 }
 
 /// A facade for converting from [`Reporter`] to [`uv_distribution::Reporter`].
+///
+/// Source distribution builds are also recorded in the [`ResolverProfile`], if any.
 pub(crate) struct Facade {
-    pub(crate) reporter: Arc<dyn Reporter>,
+    pub(crate) reporter: Option<Arc<dyn Reporter>>,
+    pub(crate) profile: Option<ResolverProfile>,
 }
 
 impl uv_distribution::Reporter for Facade {
     fn on_build_start(&self, source: &BuildableSource) -> usize {
-        self.reporter.on_build_start(source)
+        if let Some(profile) = &self.profile {
+            profile.build_start(source);
+        }
+        self.reporter
+            .as_ref()
+            .map_or(0, |reporter| reporter.on_build_start(source))
     }
 
     fn on_build_complete(&self, source: &BuildableSource, id: usize) {
-        self.reporter.on_build_complete(source, id);
+        if let Some(profile) = &self.profile {
+            profile.build_complete(source);
+        }
+        if let Some(reporter) = &self.reporter {
+            reporter.on_build_complete(source, id);
+        }
     }
 
     fn on_checkout_start(&self, url: &Url, rev: &str) -> usize {
-        self.reporter.on_checkout_start(url, rev)
+        self.reporter
+            .as_ref()
+            .map_or(0, |reporter| reporter.on_checkout_start(url, rev))
     }
 
     fn on_checkout_complete(&self, url: &Url, rev: &str, id: usize) {
-        self.reporter.on_checkout_complete(url, rev, id);
+        if let Some(reporter) = &self.reporter {
+            reporter.on_checkout_complete(url, rev, id);
+        }
     }
 
     fn on_download_start(&self, name: &PackageName, size: Option<u64>) -> usize {
-        self.reporter.on_download_start(name, size)
+        self.reporter
+            .as_ref()
+            .map_or(0, |reporter| reporter.on_download_start(name, size))
     }
 
     fn on_download_progress(&self, id: usize, bytes: u64) {
-        self.reporter.on_download_progress(id, bytes);
+        if let Some(reporter) = &self.reporter {
+            reporter.on_download_progress(id, bytes);
+        }
     }
 
     fn on_download_complete(&self, name: &PackageName, id: usize) {
-        self.reporter.on_download_complete(name, id);
+        if let Some(reporter) = &self.reporter {
+            reporter.on_download_complete(name, id);
+        }
     }
 }
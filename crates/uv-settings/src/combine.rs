@@ -2,6 +2,7 @@ use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use url::Url;
 
+use uv_cache::CacheSize;
 use uv_configuration::{
     ConfigSettings, IndexStrategy, KeyringProviderType, TargetTriple, TrustedPublishing,
 };
@@ -71,6 +72,7 @@ macro_rules! impl_combine_or {
 }
 
 impl_combine_or!(AnnotationStyle);
+impl_combine_or!(CacheSize);
 impl_combine_or!(ExcludeNewer);
 impl_combine_or!(Index);
 impl_combine_or!(IndexStrategy);
@@ -124,7 +124,7 @@ impl FilesystemOptions {
                 }
 
                 debug!("Found workspace configuration at `{}`", path.display());
-                return Ok(Some(Self(options)));
+                return Ok(Some(Self(options.relative_to(dir))));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err.into()),
@@ -153,7 +153,7 @@ impl FilesystemOptions {
                 };
 
                 debug!("Found workspace configuration at `{}`", path.display());
-                return Ok(Some(Self(options)));
+                return Ok(Some(Self(options.relative_to(dir))));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err.into()),
@@ -250,6 +250,11 @@ fn read_file(path: &Path) -> Result<Options, Error> {
     let content = fs_err::read_to_string(path)?;
     let options: Options = toml::from_str(&content)
         .map_err(|err| Error::UvToml(path.user_display().to_string(), err))?;
+    let options = if let Some(root) = path.parent() {
+        options.relative_to(root)
+    } else {
+        options
+    };
     Ok(options)
 }
 
@@ -276,6 +281,8 @@ mod test {
     use assert_fs::prelude::*;
     use indoc::indoc;
 
+    use crate::FilesystemOptions;
+
     #[test]
     #[cfg(not(windows))]
     fn test_locate_system_config_xdg() -> Result<(), FixtureError> {
@@ -319,6 +326,46 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_index_ca_bundle_relative_to_config() -> anyhow::Result<()> {
+        // Write a `uv.toml` with a relative `ca-bundle` to a temporary directory.
+        let context = assert_fs::TempDir::new()?;
+        context.child("uv.toml").write_str(indoc! { r#"
+            [[index]]
+            url = "https://pypi.example.com/simple"
+            ca-bundle = "certs/ca.pem"
+
+            [pip]
+            index = [{ url = "https://pypi.example.com/simple", ca-bundle = "certs/pip.pem" }]
+        "#})?;
+
+        // The path should be resolved relative to the configuration file, rather than the current
+        // working directory.
+        let options = FilesystemOptions::from_directory(context.path())?
+            .expect("configuration should be found")
+            .into_options();
+        let index = &options.top_level.index.as_ref().unwrap()[0];
+        assert_eq!(
+            index.ca_bundle.as_deref(),
+            Some(context.child("certs").child("ca.pem").path())
+        );
+        let index = &options.pip.as_ref().unwrap().index.as_ref().unwrap()[0];
+        assert_eq!(
+            index.ca_bundle.as_deref(),
+            Some(context.child("certs").child("pip.pem").path())
+        );
+
+        // The same applies to `--config-file`.
+        let options = FilesystemOptions::from_file(context.child("uv.toml"))?.into_options();
+        let index = &options.top_level.index.as_ref().unwrap()[0];
+        assert_eq!(
+            index.ca_bundle.as_deref(),
+            Some(context.child("certs").child("ca.pem").path())
+        );
+
+        Ok(())
+    }
+
     #[test]
     #[cfg(windows)]
     fn test_windows_config() -> Result<(), FixtureError> {
@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
-use std::{fmt::Debug, num::NonZeroUsize, path::PathBuf};
+use std::{
+    fmt::Debug,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+};
 use url::Url;
+use uv_cache::CacheSize;
 use uv_cache_info::CacheKey;
 use uv_configuration::{
     ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier, TargetTriple,
@@ -106,6 +111,23 @@ impl Options {
             ..Default::default()
         }
     }
+
+    /// Resolve any relative paths in the [`Options`] against the given root directory (i.e., the
+    /// directory containing the configuration file).
+    #[must_use]
+    pub fn relative_to(mut self, root: &Path) -> Self {
+        let relative_to = |indexes: Vec<Index>| {
+            indexes
+                .into_iter()
+                .map(|index| index.relative_to(root))
+                .collect::<Vec<_>>()
+        };
+        self.top_level.index = self.top_level.index.map(relative_to);
+        if let Some(pip) = self.pip.as_mut() {
+            pip.index = pip.index.take().map(relative_to);
+        }
+        self
+    }
 }
 
 /// Global settings, relevant to all invocations.
@@ -161,6 +183,22 @@ pub struct GlobalOptions {
         "#
     )]
     pub cache_dir: Option<PathBuf>,
+    /// The maximum size of the cached environments used by `uv run --with` and `uv tool run`
+    /// (i.e., `uvx`).
+    ///
+    /// Accepts a number of bytes, or a size with a unit suffix (e.g., `"500MiB"` or `"10GB"`).
+    /// After creating a cached environment, uv evicts the least-recently-used environments until
+    /// the total size falls under the limit. Environments that are in use by another uv process
+    /// are never evicted.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            max-cache-size = "10GiB"
+        "#
+    )]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<String>"))]
+    pub max_cache_size: Option<CacheSize>,
     /// Whether to enable experimental, preview features.
     #[option(
         default = "false",
@@ -374,7 +412,9 @@ pub struct ResolverInstallerOptions {
     /// indexes.
     ///
     /// If a path, the target must be a directory that contains packages as wheel files (`.whl`) or
-    /// source distributions (e.g., `.tar.gz` or `.zip`) at the top level.
+    /// source distributions (e.g., `.tar.gz` or `.zip`) at the top level. The directory may also
+    /// include a PEP 691 `index.json` that provides metadata for those files, such as their hashes,
+    /// upload times, `requires-python`, and yanked status.
     ///
     /// If a URL, the page must contain a flat list of links to package files adhering to the
     /// formats described above.
@@ -403,8 +443,9 @@ pub struct ResolverInstallerOptions {
     pub index_strategy: Option<IndexStrategy>,
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to read credentials from the operating
+    /// system's credential store (e.g., the macOS Keychain or the Secret Service on Linux).
     #[option(
         default = "\"disabled\"",
         value_type = "str",
@@ -786,7 +827,9 @@ pub struct PipOptions {
     /// indexes.
     ///
     /// If a path, the target must be a directory that contains packages as wheel files (`.whl`) or
-    /// source distributions (e.g., `.tar.gz` or `.zip`) at the top level.
+    /// source distributions (e.g., `.tar.gz` or `.zip`) at the top level. The directory may also
+    /// include a PEP 691 `index.json` that provides metadata for those files, such as their hashes,
+    /// upload times, `requires-python`, and yanked status.
     ///
     /// If a URL, the page must contain a flat list of links to package files adhering to the
     /// formats described above.
@@ -815,8 +858,9 @@ pub struct PipOptions {
     pub index_strategy: Option<IndexStrategy>,
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to read credentials from the operating
+    /// system's credential store (e.g., the macOS Keychain or the Secret Service on Linux).
     #[option(
         default = "disabled",
         value_type = "str",
@@ -1402,7 +1446,8 @@ impl From<ResolverInstallerOptions> for InstallerOptions {
 /// The options persisted alongside an installed tool.
 ///
 /// A mirror of [`ResolverInstallerOptions`], without upgrades and reinstalls, which shouldn't be
-/// persisted in a tool receipt.
+/// persisted in a tool receipt, and with the constraints and overrides provided at installation
+/// time.
 #[derive(
     Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, CombineOptions, OptionsMetadata,
 )]
@@ -1431,6 +1476,10 @@ pub struct ToolOptions {
     pub no_build_package: Option<Vec<PackageName>>,
     pub no_binary: Option<bool>,
     pub no_binary_package: Option<Vec<PackageName>>,
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub constraints: Option<Vec<uv_pypi_types::Requirement>>,
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub overrides: Option<Vec<uv_pypi_types::Requirement>>,
 }
 
 impl From<ResolverInstallerOptions> for ToolOptions {
@@ -1458,6 +1507,8 @@ impl From<ResolverInstallerOptions> for ToolOptions {
             no_build_package: value.no_build_package,
             no_binary: value.no_binary,
             no_binary_package: value.no_binary_package,
+            constraints: None,
+            overrides: None,
         }
     }
 }
@@ -1506,6 +1557,7 @@ pub struct OptionsWire {
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    max_cache_size: Option<CacheSize>,
     preview: Option<bool>,
     python_preference: Option<PythonPreference>,
     python_downloads: Option<PythonDownloads>,
@@ -1567,6 +1619,8 @@ pub struct OptionsWire {
     #[allow(dead_code)]
     r#package: Option<serde::de::IgnoredAny>,
     #[allow(dead_code)]
+    relocatable: Option<serde::de::IgnoredAny>,
+    #[allow(dead_code)]
     default_groups: Option<serde::de::IgnoredAny>,
     #[allow(dead_code)]
     dev_dependencies: Option<serde::de::IgnoredAny>,
@@ -1579,6 +1633,7 @@ impl From<OptionsWire> for Options {
             offline,
             no_cache,
             cache_dir,
+            max_cache_size,
             preview,
             python_preference,
             python_downloads,
@@ -1622,6 +1677,7 @@ impl From<OptionsWire> for Options {
             sources: _,
             managed: _,
             package: _,
+            relocatable: _,
             default_groups: _,
             dev_dependencies: _,
         } = value;
@@ -1632,6 +1688,7 @@ impl From<OptionsWire> for Options {
                 offline,
                 no_cache,
                 cache_dir,
+                max_cache_size,
                 preview,
                 python_preference,
                 python_downloads,
@@ -27,6 +27,9 @@ impl EnvVars {
     /// Equivalent to the `--no-cache` argument. Disables cache usage.
     pub const UV_NO_CACHE: &'static str = "UV_NO_CACHE";
 
+    /// Equivalent to the `--max-cache-size` argument. Limits the size of cached environments.
+    pub const UV_MAX_CACHE_SIZE: &'static str = "UV_MAX_CACHE_SIZE";
+
     /// Equivalent to the `--resolution` argument. Controls dependency resolution strategy.
     pub const UV_RESOLUTION: &'static str = "UV_RESOLUTION";
 
@@ -72,6 +75,21 @@ impl EnvVars {
     /// Equivalent to the `--keyring-provider` argument. Specifies keyring provider.
     pub const UV_KEYRING_PROVIDER: &'static str = "UV_KEYRING_PROVIDER";
 
+    /// Specifies an external credential helper that speaks the `git-credential` protocol, e.g.,
+    /// `git credential-store` or `/path/to/helper --option`.
+    ///
+    /// The command is split into arguments following shell quoting rules.
+    pub const UV_CREDENTIAL_HELPER: &'static str = "UV_CREDENTIAL_HELPER";
+
+    /// Include the path of the request URL when querying the credential helper set via
+    /// `UV_CREDENTIAL_HELPER`, like Git's `credential.useHttpPath`. Set to `1` or `true` to
+    /// enable.
+    ///
+    /// By default, only the protocol and host are sent, such that a helper stores a single
+    /// credential per host.
+    pub const UV_CREDENTIAL_HELPER_USE_HTTP_PATH: &'static str =
+        "UV_CREDENTIAL_HELPER_USE_HTTP_PATH";
+
     /// Equivalent to the `--config-file` argument. Path to configuration file.
     pub const UV_CONFIG_FILE: &'static str = "UV_CONFIG_FILE";
 
@@ -105,6 +123,10 @@ impl EnvVars {
     /// Don't upload a file if it already exists on the index. The value is the URL of the index.
     pub const UV_PUBLISH_CHECK_URL: &'static str = "UV_PUBLISH_CHECK_URL";
 
+    /// Equivalent to the `--attestations` argument in `uv publish`. A directory of pre-made
+    /// attestations to upload.
+    pub const UV_PUBLISH_ATTESTATIONS: &'static str = "UV_PUBLISH_ATTESTATIONS";
+
     /// Equivalent to the `--no-sync` argument. Skips syncing the environment.
     pub const UV_NO_SYNC: &'static str = "UV_NO_SYNC";
 
@@ -114,6 +136,11 @@ impl EnvVars {
     /// Equivalent to the `--frozen` argument. Run without updating the `uv.lock` file.
     pub const UV_FROZEN: &'static str = "UV_FROZEN";
 
+    /// Equivalent to the `--env-file` argument in `uv run` and `uv tool run`. Loads environment
+    /// variables from one or more `.env` files, separated by the platform's path separator (`:` on
+    /// Unix, `;` on Windows).
+    pub const UV_ENV_FILE: &'static str = "UV_ENV_FILE";
+
     /// Equivalent to the `--preview` argument. Enables preview mode.
     pub const UV_PREVIEW: &'static str = "UV_PREVIEW";
 
@@ -135,9 +162,16 @@ impl EnvVars {
     /// Controls the number of threads used for concurrent installations.
     pub const UV_CONCURRENT_INSTALLS: &'static str = "UV_CONCURRENT_INSTALLS";
 
+    /// Equivalent to the `--concurrent-uploads` argument in `uv publish`. Sets the maximum number
+    /// of concurrent uploads.
+    pub const UV_CONCURRENT_UPLOADS: &'static str = "UV_CONCURRENT_UPLOADS";
+
     /// Specifies the directory where `uv` stores managed tools.
     pub const UV_TOOL_DIR: &'static str = "UV_TOOL_DIR";
 
+    /// Specifies the directory where `uv auth login` stores credentials.
+    pub const UV_CREDENTIALS_DIR: &'static str = "UV_CREDENTIALS_DIR";
+
     /// Specifies the "bin" directory for installing tool executables.
     pub const UV_TOOL_BIN_DIR: &'static str = "UV_TOOL_BIN_DIR";
 
@@ -156,6 +190,14 @@ impl EnvVars {
     /// Mirror URL for downloading managed PyPy installations.
     pub const UV_PYPY_INSTALL_MIRROR: &'static str = "UV_PYPY_INSTALL_MIRROR";
 
+    /// Path to a JSON file listing managed Python downloads, in the format of
+    /// `download-metadata.json`. Its downloads take precedence over the built-in downloads.
+    pub const UV_PYTHON_DOWNLOADS_JSON_PATH: &'static str = "UV_PYTHON_DOWNLOADS_JSON_PATH";
+
+    /// Only use the managed Python downloads from `UV_PYTHON_DOWNLOADS_JSON_PATH`, ignoring the
+    /// built-in downloads.
+    pub const UV_PYTHON_DOWNLOADS_JSON_ONLY: &'static str = "UV_PYTHON_DOWNLOADS_JSON_ONLY";
+
     /// Used to override `PATH` to limit Python executable availability in the test suite.
     pub const UV_TEST_PYTHON_PATH: &'static str = "UV_TEST_PYTHON_PATH";
 
@@ -277,6 +319,9 @@ impl EnvVars {
     /// Used to detect Ksh shell usage.
     pub const KSH_VERSION: &'static str = "KSH_VERSION";
 
+    /// Used to set the startup file for interactive Ksh shells launched by `uv shell`.
+    pub const ENV: &'static str = "ENV";
+
     /// Sets macOS deployment target when using `--python-platform macos`.
     pub const MACOSX_DEPLOYMENT_TARGET: &'static str = "MACOSX_DEPLOYMENT_TARGET";
 
@@ -341,6 +386,26 @@ impl EnvVars {
     /// Used for trusted publishing via `uv publish`. Contains the oidc request token.
     pub const ACTIONS_ID_TOKEN_REQUEST_TOKEN: &'static str = "ACTIONS_ID_TOKEN_REQUEST_TOKEN";
 
+    /// Used for publishing to S3-compatible storage via `uv publish`. The access key ID.
+    pub const AWS_ACCESS_KEY_ID: &'static str = "AWS_ACCESS_KEY_ID";
+
+    /// Used for publishing to S3-compatible storage via `uv publish`. The secret access key.
+    pub const AWS_SECRET_ACCESS_KEY: &'static str = "AWS_SECRET_ACCESS_KEY";
+
+    /// Used for publishing to S3-compatible storage via `uv publish`. An optional session token.
+    pub const AWS_SESSION_TOKEN: &'static str = "AWS_SESSION_TOKEN";
+
+    /// Used for publishing to S3-compatible storage via `uv publish`. The region of the bucket.
+    pub const AWS_REGION: &'static str = "AWS_REGION";
+
+    /// Used for publishing to S3-compatible storage via `uv publish`. The region of the bucket, if
+    /// `AWS_REGION` is not set.
+    pub const AWS_DEFAULT_REGION: &'static str = "AWS_DEFAULT_REGION";
+
+    /// Used for publishing to S3-compatible storage via `uv publish`. A custom endpoint for a
+    /// service other than AWS, which is addressed with path-style URLs.
+    pub const AWS_ENDPOINT_URL: &'static str = "AWS_ENDPOINT_URL";
+
     /// Sets the encoding for standard I/O streams (e.g., PYTHONIOENCODING=utf-8).
     pub const PYTHONIOENCODING: &'static str = "PYTHONIOENCODING";
 
@@ -16,6 +16,7 @@ use tracing::{debug, warn};
 
 use uv_install_wheel::read_record_file;
 
+pub use manifest::{ManifestTool, ToolManifest};
 pub use receipt::ToolReceipt;
 pub use tool::{Tool, ToolEntrypoint};
 use uv_cache::Cache;
@@ -25,6 +26,7 @@ use uv_python::{Interpreter, PythonEnvironment};
 use uv_state::{StateBucket, StateStore};
 use uv_static::EnvVars;
 
+mod manifest;
 mod receipt;
 mod tool;
 
@@ -36,6 +38,8 @@ pub enum Error {
     ReceiptWrite(PathBuf, #[source] Box<toml::ser::Error>),
     #[error("Failed to read `uv-receipt.toml` at {0}")]
     ReceiptRead(PathBuf, #[source] Box<toml::de::Error>),
+    #[error("Failed to parse tool manifest at {}", _0.user_display())]
+    ManifestRead(PathBuf, #[source] Box<toml::de::Error>),
     #[error(transparent)]
     VirtualEnvError(#[from] uv_virtualenv::Error),
     #[error("Failed to read package entry points {0}")]
@@ -0,0 +1,57 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use uv_pep508::PackageName;
+
+/// A `uv-tools.toml` file declaring the tools that should be installed.
+///
+/// For example:
+///
+/// ```toml
+/// [tools.ruff]
+/// from = "ruff==0.6.9"
+///
+/// [tools.pre-commit]
+/// with = ["pre-commit-uv"]
+/// constraints = ["virtualenv<21"]
+/// python = "3.12"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ToolManifest {
+    /// The tools to install, keyed by the name of the package that provides them.
+    #[serde(default)]
+    pub tools: BTreeMap<PackageName, ManifestTool>,
+}
+
+/// A tool entry in a [`ToolManifest`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ManifestTool {
+    /// The requirement to install the tool from, e.g., `ruff==0.6.9`, as with `--from`.
+    ///
+    /// Defaults to the name of the tool.
+    pub from: Option<String>,
+    /// Additional requirements to install into the tool environment, as with `--with`.
+    #[serde(default)]
+    pub with: Vec<String>,
+    /// Constraints to apply when installing the tool, as with `--constraints`.
+    #[serde(default)]
+    pub constraints: Vec<String>,
+    /// Overrides to apply when installing the tool, as with `--overrides`.
+    #[serde(default)]
+    pub overrides: Vec<String>,
+    /// The Python interpreter to use to build the tool environment, as with `--python`.
+    pub python: Option<String>,
+}
+
+impl ToolManifest {
+    /// Read a [`ToolManifest`] from the given path.
+    pub fn from_path(path: &Path) -> Result<Self, crate::Error> {
+        let contents = fs_err::read_to_string(path)?;
+        toml::from_str(&contents)
+            .map_err(|err| crate::Error::ManifestRead(path.to_owned(), Box::new(err)))
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 
 use serde::Deserialize;
@@ -7,6 +8,7 @@ use toml_edit::Value;
 use toml_edit::{Array, Item};
 
 use uv_fs::PortablePath;
+use uv_pep508::PackageName;
 use uv_pypi_types::{Requirement, VerbatimParsedUrl};
 use uv_settings::ToolOptions;
 
@@ -83,6 +85,9 @@ impl TryFrom<ToolWire> for Tool {
 pub struct ToolEntrypoint {
     pub name: String,
     pub install_path: PathBuf,
+    /// The package that provides the entrypoint, if other than the tool package (i.e., if it was
+    /// installed via `--with-executables-from`).
+    pub from: Option<PackageName>,
 }
 
 /// Format an array so that each element is on its own line and has a trailing comma.
@@ -203,12 +208,26 @@ impl Tool {
     pub fn options(&self) -> &ToolOptions {
         &self.options
     }
+
+    /// Returns the packages, other than the tool package, that provide entrypoints.
+    pub fn executables_from(&self) -> Vec<PackageName> {
+        self.entrypoints
+            .iter()
+            .filter_map(|entrypoint| entrypoint.from.clone())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
 }
 
 impl ToolEntrypoint {
     /// Create a new [`ToolEntrypoint`].
-    pub fn new(name: String, install_path: PathBuf) -> Self {
-        Self { name, install_path }
+    pub fn new(name: String, install_path: PathBuf, from: Option<PackageName>) -> Self {
+        Self {
+            name,
+            install_path,
+            from,
+        }
     }
 
     /// Returns the TOML table for this entrypoint.
@@ -220,6 +239,9 @@ impl ToolEntrypoint {
             // Use cross-platform slashes so the toml string type does not change
             value(PortablePath::from(&self.install_path).to_string()),
         );
+        if let Some(from) = &self.from {
+            table.insert("from", value(from.to_string()));
+        }
         table
     }
 }
@@ -71,3 +71,16 @@ pub fn create_venv(
     let interpreter = interpreter.with_virtualenv(virtualenv);
     Ok(PythonEnvironment::from_interpreter(interpreter))
 }
+
+/// Bundle a Python installation into an existing virtualenv.
+///
+/// The installation at `installation` is copied into the virtualenv, and the virtualenv's
+/// `pyvenv.cfg` and executables are updated to refer to the bundled interpreter, such that the
+/// virtualenv is self-contained. On Unix, the `python` executable refers to the bundled
+/// interpreter via a relative symlink.
+///
+/// The virtualenv's [`Interpreter`] should be re-queried afterwards, as its base interpreter has
+/// changed.
+pub fn bundle_python(venv: &PythonEnvironment, installation: &Path) -> Result<(), Error> {
+    virtualenv::bundle(venv.root(), venv.interpreter(), installation)
+}
@@ -3,7 +3,7 @@
 use std::env::consts::EXE_SUFFIX;
 use std::io;
 use std::io::{BufWriter, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use fs_err as fs;
 use fs_err::File;
@@ -53,36 +53,7 @@ pub(crate) fn create(
     relocatable: bool,
     seed: bool,
 ) -> Result<VirtualEnvironment, Error> {
-    // Determine the base Python executable; that is, the Python executable that should be
-    // considered the "base" for the virtual environment. This is typically the Python executable
-    // from the [`Interpreter`]; however, if the interpreter is a virtual environment itself, then
-    // the base Python executable is the Python executable of the interpreter's base interpreter.
-    let base_python = if cfg!(unix) {
-        // On Unix, follow symlinks to resolve the base interpreter, since the Python executable in
-        // a virtual environment is a symlink to the base interpreter.
-        uv_fs::canonicalize_executable(interpreter.sys_executable())?
-    } else if cfg!(windows) {
-        // On Windows, follow `virtualenv`. If we're in a virtual environment, use
-        // `sys._base_executable` if it exists; if not, use `sys.base_prefix`. For example, with
-        // Python installed from the Windows Store, `sys.base_prefix` is slightly "incorrect".
-        //
-        // If we're _not_ in a virtual environment, use the interpreter's executable, since it's
-        // already a "system Python". We canonicalize the path to ensure that it's real and
-        // consistent, though we don't expect any symlinks on Windows.
-        if interpreter.is_virtualenv() {
-            if let Some(base_executable) = interpreter.sys_base_executable() {
-                base_executable.to_path_buf()
-            } else {
-                // Assume `python.exe`, though the exact executable name is never used (below) on
-                // Windows, only its parent directory.
-                interpreter.sys_base_prefix().join("python.exe")
-            }
-        } else {
-            interpreter.sys_executable().to_path_buf()
-        }
-    } else {
-        unimplemented!("Only Windows and Unix are supported")
-    };
+    let base_python = base_python(interpreter)?;
 
     // Validate the existing location.
     match location.metadata() {
@@ -415,6 +386,150 @@ pub(crate) fn create(
     })
 }
 
+/// Copy the Python installation at `installation` into the virtual environment at `location`, and
+/// point the environment at the bundled interpreter via a relative symlink.
+///
+/// The installation must contain the environment's base interpreter.
+pub(crate) fn bundle(
+    location: &Path,
+    interpreter: &Interpreter,
+    installation: &Path,
+) -> Result<(), Error> {
+    let location = std::path::absolute(location)?;
+    let installation = uv_fs::canonicalize_executable(installation)?;
+
+    // Locate the base interpreter within the installation.
+    let base_python = base_python(interpreter)?;
+    let relative_python = base_python.strip_prefix(&installation).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "The Python interpreter at `{}` is not part of the installation at `{}`",
+                base_python.user_display(),
+                installation.user_display()
+            ),
+        )
+    })?;
+
+    // Copy the installation into the environment, replacing any existing bundle.
+    let bundle = location.join("python");
+    match fs::remove_dir_all(&bundle) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+    debug!(
+        "Copying Python installation from `{}` to `{}`",
+        installation.user_display(),
+        bundle.user_display()
+    );
+    copy_dir_all(&installation, &bundle)?;
+
+    let bundled_python = bundle.join(relative_python);
+    let python_home = bundled_python.parent().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            "The Python interpreter needs to have a parent directory",
+        )
+    })?;
+
+    // On Unix, the other executables in the environment are symlinks to `python`, so it's
+    // sufficient to re-point `python` at the bundled interpreter. On Windows, the launchers read
+    // the `home` key from `pyvenv.cfg`.
+    #[cfg(unix)]
+    {
+        let scripts = location.join(&interpreter.virtualenv().scripts);
+        let target = pathdiff::diff_paths(&bundled_python, &scripts).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Failed to determine relative path to the bundled Python interpreter",
+            )
+        })?;
+        uv_fs::replace_symlink(target, scripts.join(format!("python{EXE_SUFFIX}")))?;
+    }
+
+    // Rewrite `pyvenv.cfg` to point at the bundled interpreter. The `home` key remains absolute,
+    // as expected by the interpreter; once the environment is moved, the interpreter is located
+    // via the relative `python` symlink instead.
+    let content = fs::read_to_string(location.join("pyvenv.cfg"))?;
+    let mut pyvenv_cfg_data: Vec<(String, String)> = content
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .filter(|(key, _)| key != "relocatable")
+        .map(|(key, value)| {
+            if key == "home" {
+                (key, python_home.simplified_display().to_string())
+            } else {
+                (key, value)
+            }
+        })
+        .collect();
+    pyvenv_cfg_data.push(("relocatable".to_string(), "true".to_string()));
+
+    let mut pyvenv_cfg = BufWriter::new(File::create(location.join("pyvenv.cfg"))?);
+    write_cfg(&mut pyvenv_cfg, &pyvenv_cfg_data)?;
+    drop(pyvenv_cfg);
+
+    Ok(())
+}
+
+/// Recursively copy a directory, preserving symlinks on Unix.
+fn copy_dir_all(src: &Path, dst: &Path) -> io::Result<()> {
+    fs::create_dir_all(dst)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = dst.join(entry.file_name());
+        if file_type.is_dir() {
+            copy_dir_all(&entry.path(), &target)?;
+        } else if file_type.is_symlink() {
+            #[cfg(unix)]
+            fs::os::unix::fs::symlink(fs::read_link(entry.path())?, &target)?;
+            #[cfg(windows)]
+            fs::copy(entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Determine the base Python executable; that is, the Python executable that should be
+/// considered the "base" for the virtual environment.
+///
+/// This is typically the Python executable from the [`Interpreter`]; however, if the interpreter
+/// is a virtual environment itself, then the base Python executable is the Python executable of
+/// the interpreter's base interpreter.
+fn base_python(interpreter: &Interpreter) -> io::Result<PathBuf> {
+    if cfg!(unix) {
+        // On Unix, follow symlinks to resolve the base interpreter, since the Python executable in
+        // a virtual environment is a symlink to the base interpreter.
+        uv_fs::canonicalize_executable(interpreter.sys_executable())
+    } else if cfg!(windows) {
+        // On Windows, follow `virtualenv`. If we're in a virtual environment, use
+        // `sys._base_executable` if it exists; if not, use `sys.base_prefix`. For example, with
+        // Python installed from the Windows Store, `sys.base_prefix` is slightly "incorrect".
+        //
+        // If we're _not_ in a virtual environment, use the interpreter's executable, since it's
+        // already a "system Python". We canonicalize the path to ensure that it's real and
+        // consistent, though we don't expect any symlinks on Windows.
+        if interpreter.is_virtualenv() {
+            if let Some(base_executable) = interpreter.sys_base_executable() {
+                Ok(base_executable.to_path_buf())
+            } else {
+                // Assume `python.exe`, though the exact executable name is never used (below) on
+                // Windows, only its parent directory.
+                Ok(interpreter.sys_base_prefix().join("python.exe"))
+            }
+        } else {
+            Ok(interpreter.sys_executable().to_path_buf())
+        }
+    } else {
+        unimplemented!("Only Windows and Unix are supported")
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 enum WindowsExecutable {
     /// The `python.exe` executable (or `venvlauncher.exe` launcher shim).
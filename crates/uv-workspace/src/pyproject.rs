@@ -90,6 +90,15 @@ impl PyProjectToml {
         self.build_system.is_some()
     }
 
+    /// Returns `true` if the project environment should be relocatable.
+    pub fn is_relocatable(&self) -> bool {
+        self.tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.relocatable)
+            .unwrap_or(false)
+    }
+
     /// Returns whether the project manifest contains any script table.
     pub fn has_scripts(&self) -> bool {
         if let Some(ref project) = self.project {
@@ -313,6 +322,23 @@ pub struct ToolUv {
     )]
     pub package: Option<bool>,
 
+    /// Whether the project environment should be relocatable.
+    ///
+    /// If enabled, `uv sync` (and other commands that create the project environment) will create
+    /// the `.venv` as a relocatable environment, as with `uv venv --relocatable`. Entrypoints and
+    /// activation scripts use paths relative to the environment, such that the environment can be
+    /// moved (e.g., copied into a container image) without breaking.
+    ///
+    /// If an existing project environment is not relocatable, it will be recreated.
+    #[option(
+        default = r#"false"#,
+        value_type = "bool",
+        example = r#"
+            relocatable = true
+        "#
+    )]
+    pub relocatable: Option<bool>,
+
     /// The list of `dependency-groups` to install by default.
     #[option(
         default = r#"["dev"]"#,
@@ -237,6 +237,7 @@ async fn albatross_root_workspace() {
                   },
                   "managed": null,
                   "package": null,
+                  "relocatable": null,
                   "default-groups": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
@@ -328,6 +329,7 @@ async fn albatross_virtual_workspace() {
                   },
                   "managed": null,
                   "package": null,
+                  "relocatable": null,
                   "default-groups": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
@@ -533,6 +535,7 @@ async fn exclude_package() -> Result<()> {
                   },
                   "managed": null,
                   "package": null,
+                  "relocatable": null,
                   "default-groups": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
@@ -636,6 +639,7 @@ async fn exclude_package() -> Result<()> {
                   },
                   "managed": null,
                   "package": null,
+                  "relocatable": null,
                   "default-groups": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
@@ -752,6 +756,7 @@ async fn exclude_package() -> Result<()> {
                   },
                   "managed": null,
                   "package": null,
+                  "relocatable": null,
                   "default-groups": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
@@ -842,6 +847,7 @@ async fn exclude_package() -> Result<()> {
                   },
                   "managed": null,
                   "package": null,
+                  "relocatable": null,
                   "default-groups": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
@@ -0,0 +1,77 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Context, Result};
+use console::Term;
+use owo_colors::OwoColorize;
+
+use uv_auth::TextCredentialStore;
+use uv_configuration::KeyringProviderType;
+use uv_distribution_types::Index;
+use uv_fs::Simplified;
+
+use crate::commands::auth::resolve_service;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Store credentials for a service.
+pub(crate) async fn login(
+    service: &str,
+    username: Option<String>,
+    password: Option<String>,
+    keyring_provider: KeyringProviderType,
+    indexes: &[Index],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let (url, _) = resolve_service(service, indexes)?;
+
+    let term = Term::stderr();
+
+    // Prompt for any missing values, if possible.
+    let username = match username {
+        Some(username) => Some(username),
+        None if password.is_none() && term.is_term() => {
+            let username =
+                uv_console::input("Enter username ('__token__' if using a token): ", &term)
+                    .context("Failed to read username")?;
+            Some(username).filter(|username| !username.is_empty())
+        }
+        None => None,
+    };
+    let password = match password {
+        Some(password) => password,
+        None if term.is_term() => {
+            uv_console::password("Enter password: ", &term).context("Failed to read password")?
+        }
+        None => bail!(
+            "No password provided for {url}. Provide a password with `--password`, or use `--token` instead."
+        ),
+    };
+
+    if let Some(keyring) = keyring_provider.to_provider() {
+        let Some(username) = username.as_deref() else {
+            bail!("A username is required to store credentials in the keyring. Provide a username with `--username`, or use `--token` instead.");
+        };
+        keyring
+            .store(&url, username, &password)
+            .await
+            .with_context(|| format!("Failed to store credentials for {url} in the keyring"))?;
+        writeln!(
+            printer.stderr(),
+            "Stored credentials for {} in the keyring",
+            url.cyan()
+        )?;
+    } else {
+        let path = TextCredentialStore::default_path()?;
+        let mut store = TextCredentialStore::read(&path)?;
+        store.insert(url.clone(), username, password);
+        store.write(&path)?;
+        writeln!(
+            printer.stderr(),
+            "Stored credentials for {} in {}",
+            url.cyan(),
+            path.user_display().cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,49 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+
+use uv_auth::TextCredentialStore;
+use uv_configuration::KeyringProviderType;
+use uv_distribution_types::Index;
+
+use crate::commands::auth::resolve_service;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Remove the stored credentials for a service.
+pub(crate) async fn logout(
+    service: &str,
+    username: Option<&str>,
+    keyring_provider: KeyringProviderType,
+    indexes: &[Index],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let (url, _) = resolve_service(service, indexes)?;
+
+    let removed = if let Some(keyring) = keyring_provider.to_provider() {
+        let Some(username) = username else {
+            bail!("A username is required to remove credentials from the keyring. Provide a username with `--username`.");
+        };
+        keyring
+            .remove(&url, username)
+            .await
+            .with_context(|| format!("Failed to remove credentials for {url} from the keyring"))?
+    } else {
+        let path = TextCredentialStore::default_path()?;
+        let mut store = TextCredentialStore::read(&path)?;
+        let removed = store.remove(&url, username);
+        if removed {
+            store.write(&path)?;
+        }
+        removed
+    };
+
+    if removed {
+        writeln!(printer.stderr(), "Removed credentials for {}", url.cyan())?;
+    } else {
+        writeln!(printer.stderr(), "No credentials found for {}", url.cyan())?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,38 @@
+use anyhow::{bail, Result};
+use url::Url;
+
+use uv_distribution_types::Index;
+
+pub(crate) mod login;
+pub(crate) mod logout;
+pub(crate) mod token;
+
+/// Resolve a service, given as either the name of a configured index or a URL.
+///
+/// Returns the URL of the service, along with the matching [`Index`], if any.
+fn resolve_service<'a>(service: &str, indexes: &'a [Index]) -> Result<(Url, Option<&'a Index>)> {
+    // Prefer an index with a matching name.
+    if let Some(index) = indexes
+        .iter()
+        .find(|index| index.name.as_deref() == Some(service))
+    {
+        return Ok((index.raw_url().clone(), Some(index)));
+    }
+
+    // Otherwise, parse the service as a URL, defaulting to HTTPS if no scheme is provided.
+    let url = match Url::parse(service) {
+        Ok(url) => url,
+        Err(url::ParseError::RelativeUrlWithoutBase) => Url::parse(&format!("https://{service}"))?,
+        Err(err) => bail!("Invalid service `{service}`: {err}"),
+    };
+    if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+        bail!("Invalid service `{service}`: expected the name of an index or an HTTP(S) URL");
+    }
+
+    // If the URL matches a configured index, use it.
+    let index = indexes.iter().find(|index| {
+        index.raw_url().as_str().trim_end_matches('/') == url.as_str().trim_end_matches('/')
+    });
+
+    Ok((url, index))
+}
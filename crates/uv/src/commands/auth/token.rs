@@ -0,0 +1,64 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+
+use uv_auth::{AuthMiddleware, AuthPolicies, AuthPolicy, Credentials};
+use uv_configuration::KeyringProviderType;
+use uv_distribution_types::Index;
+
+use crate::commands::auth::resolve_service;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Show the password or token that uv would use to authenticate requests to a service.
+pub(crate) async fn token(
+    service: &str,
+    username: Option<&str>,
+    keyring_provider: KeyringProviderType,
+    indexes: &[Index],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let (url, index) = resolve_service(service, indexes)?;
+
+    // Apply the per-index authentication settings, as in any other request.
+    let mut policies = AuthPolicies::default();
+    for index in indexes {
+        policies.insert(index.raw_url().clone(), index.auth_policy());
+    }
+    if policies
+        .get(&url)
+        .is_some_and(|policy| policy.authenticate == AuthPolicy::Never)
+    {
+        bail!("Authentication is disabled for {url} (`authenticate = \"never\"`)");
+    }
+
+    // Credentials from the environment or the URL take precedence, as in any other request.
+    let credentials = index
+        .and_then(Index::credentials)
+        .or_else(|| Credentials::from_url(&url))
+        .filter(|credentials| credentials.password().is_some())
+        .filter(|credentials| {
+            username.map_or(true, |username| credentials.username() == Some(username))
+        });
+
+    let credentials = if let Some(credentials) = credentials {
+        Some(credentials)
+    } else {
+        AuthMiddleware::new()
+            .with_keyring(keyring_provider.to_provider())
+            .with_policies(policies)
+            .find_credentials(&url, username)
+            .await
+    };
+
+    let Some(password) = credentials
+        .as_ref()
+        .and_then(|credentials| credentials.password())
+    else {
+        bail!("No credentials found for {url}");
+    };
+
+    writeln!(printer.stdout(), "{password}")?;
+
+    Ok(ExitStatus::Success)
+}
@@ -481,7 +481,13 @@ async fn build_package(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, None, &hasher, build_options)
+        FlatIndex::from_entries(
+            entries,
+            None,
+            &hasher,
+            build_options,
+            exclude_newer.as_ref(),
+        )
     };
 
     // Initialize any shared state.
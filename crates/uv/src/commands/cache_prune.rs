@@ -1,4 +1,5 @@
 use std::fmt::Write;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use owo_colors::OwoColorize;
@@ -10,7 +11,16 @@ use crate::commands::{human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
 
 /// Prune all unreachable objects from the cache.
-pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<ExitStatus> {
+///
+/// If `environments` is set, only prune the cached environments, optionally limited to those that
+/// haven't been used within `older_than`.
+pub(crate) fn cache_prune(
+    ci: bool,
+    environments: bool,
+    older_than: Option<Duration>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
     if !cache.root().exists() {
         writeln!(
             printer.stderr(),
@@ -28,14 +38,22 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
 
     let mut summary = Removal::default();
 
-    // Prune the source distribution cache, which is tightly coupled to the builder crate.
-    summary += uv_distribution::prune(cache)
-        .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
+    if environments {
+        // Prune the cached environments, leaving the remaining cache buckets untouched.
+        summary += cache.prune_environments(older_than).with_context(|| {
+            format!("Failed to prune cache at: {}", cache.root().user_display())
+        })?;
+    } else {
+        // Prune the source distribution cache, which is tightly coupled to the builder crate.
+        summary += uv_distribution::prune(cache).with_context(|| {
+            format!("Failed to prune cache at: {}", cache.root().user_display())
+        })?;
 
-    // Prune the remaining cache buckets.
-    summary += cache
-        .prune(ci)
-        .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
+        // Prune the remaining cache buckets.
+        summary += cache.prune(ci).with_context(|| {
+            format!("Failed to prune cache at: {}", cache.root().user_display())
+        })?;
+    }
 
     // Write a summary of the number of files and directories removed.
     match (summary.num_files, summary.num_dirs) {
@@ -0,0 +1,295 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::Write as _;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use futures::StreamExt;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cli::MirrorFormat;
+use uv_client::{BaseClient, BaseClientBuilder, Connectivity};
+use uv_configuration::{Concurrency, KeyringProviderType, TrustedHost};
+use uv_distribution_types::File;
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pypi_types::{
+    simple_project_page, simple_root_page, HashAlgorithm, HashDigest, SimpleHtmlLink,
+};
+use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace};
+
+use crate::commands::project::lock::read;
+use crate::commands::project::ProjectError;
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+
+/// Download every registry distribution in the project's `uv.lock` into a local index.
+pub(crate) async fn mirror(
+    project_dir: &Path,
+    output_dir: &Path,
+    format: MirrorFormat,
+    require_hashes: bool,
+    keyring_provider: KeyringProviderType,
+    allow_insecure_host: Vec<TrustedHost>,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+
+    // Read the existing lockfile; we never resolve when mirroring.
+    let Some(lock) = read(&workspace).await? else {
+        return Err(ProjectError::MissingLockfile.into());
+    };
+
+    // Add all authenticated sources to the cache.
+    for index in workspace.indexes() {
+        if let Some(credentials) = index.credentials() {
+            uv_auth::store_credentials(index.raw_url(), credentials);
+        }
+    }
+
+    // Collect the files to mirror, grouped by package.
+    let mut packages: BTreeMap<&PackageName, Vec<File>> = BTreeMap::new();
+    for package in lock.packages() {
+        let files = package.registry_files(workspace.install_path())?;
+        if files.is_empty() {
+            continue;
+        }
+        packages.entry(package.name()).or_default().extend(files);
+    }
+
+    // Distributions without a hash can't be verified against the lockfile.
+    let unhashed = packages
+        .values()
+        .flatten()
+        .filter(|file| file.hashes.is_empty())
+        .map(|file| file.filename.as_str())
+        .collect::<Vec<_>>();
+    if !unhashed.is_empty() {
+        if require_hashes {
+            bail!(
+                "In `--require-hashes` mode, all distributions must have a hash, but none were found in `uv.lock` for: {}",
+                unhashed.iter().map(|filename| format!("`{filename}`")).collect::<Vec<_>>().join(", ")
+            );
+        }
+        for filename in &unhashed {
+            warn_user!(
+                "No hash found in `uv.lock` for `{filename}`; recording its computed SHA-256 digest instead"
+            );
+        }
+    }
+
+    if packages.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "No registry distributions found in `{}`",
+            "uv.lock".cyan()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let client = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .keyring(keyring_provider)
+        .allow_insecure_host(allow_insecure_host)
+        .build();
+
+    fs_err::create_dir_all(output_dir)?;
+
+    // Determine the destination of each file, based on the requested layout.
+    let downloads = packages
+        .iter()
+        .flat_map(|(name, files)| {
+            let directory = match format {
+                MirrorFormat::Simple => output_dir.join(name.as_ref()),
+                MirrorFormat::Flat => output_dir.to_path_buf(),
+            };
+            files
+                .iter()
+                .map(move |file| (file, directory.join(&file.filename)))
+        })
+        .collect::<Vec<_>>();
+
+    let mut downloaded = 0usize;
+    let mut computed = BTreeMap::new();
+    let mut results = futures::stream::iter(downloads)
+        .map(|(file, target)| {
+            let client = &client;
+            async move {
+                let result = download(client, file, &target).await;
+                (file, result)
+            }
+        })
+        .buffer_unordered(concurrency.downloads);
+    while let Some((file, result)) = results.next().await {
+        let (fetched, digests) = result?;
+        downloaded += usize::from(fetched);
+        if file.hashes.is_empty() {
+            computed.insert(file.filename.clone(), digests);
+        }
+    }
+    drop(results);
+
+    // Record the computed digests for any distributions that lacked a hash in the lockfile.
+    for file in packages.values_mut().flatten() {
+        if let Some(digests) = computed.remove(&file.filename) {
+            file.hashes = digests;
+        }
+    }
+
+    if matches!(format, MirrorFormat::Simple) {
+        for (name, files) in &packages {
+            fs_err::write(
+                output_dir.join(name.as_ref()).join("index.html"),
+                simple_project_page(
+                    &format!("Links for {name}"),
+                    files.iter().map(|file| SimpleHtmlLink {
+                        filename: &file.filename,
+                        hash: file.hashes.first(),
+                        requires_python: file.requires_python.as_ref(),
+                    }),
+                ),
+            )?;
+        }
+        fs_err::write(
+            output_dir.join("index.html"),
+            simple_root_page(packages.keys().copied().map(PackageName::as_str)),
+        )?;
+    }
+
+    let total = packages.values().map(Vec::len).sum::<usize>();
+    let s = if total == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Mirrored {} to {} {}",
+            format!("{total} file{s}").bold(),
+            output_dir.user_display().cyan(),
+            format!("in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+    if downloaded < total {
+        debug!(
+            "Skipped {} file(s) that were already present in the mirror",
+            total - downloaded
+        );
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Download a single file to `target`, verifying it against the hashes recorded in the lockfile.
+///
+/// Returns `false` if an up-to-date copy of the file already existed at `target`, alongside the
+/// computed digests of the file.
+async fn download(
+    client: &BaseClient,
+    file: &File,
+    target: &Path,
+) -> Result<(bool, Vec<HashDigest>)> {
+    // If the file already exists with the expected hashes, skip it. Files without a hash in the
+    // lockfile can't be verified, so are always downloaded again.
+    if target.is_file() && !file.hashes.is_empty() {
+        let contents = fs_err::tokio::read(target).await?;
+        let mut hashers = hashers(file);
+        for hasher in &mut hashers {
+            hasher.update(&contents);
+        }
+        if let Ok(digests) = verify_hashes(file, hashers) {
+            debug!("Found up-to-date file: {}", target.user_display());
+            return Ok((false, digests));
+        }
+    }
+
+    let url = file
+        .url
+        .to_url()
+        .with_context(|| format!("Invalid URL for `{}`", file.filename))?;
+    debug!("Downloading: {url}");
+
+    let directory = target
+        .parent()
+        .expect("mirrored files must have a parent directory");
+    fs_err::tokio::create_dir_all(directory).await?;
+
+    // Stream the file into a temporary location in the target directory, hashing it as we go.
+    let mut temp_file = tempfile::NamedTempFile::new_in(directory)?;
+    let mut hashers = hashers(file);
+    if url.scheme() == "file" {
+        let path = url
+            .to_file_path()
+            .map_err(|()| anyhow::anyhow!("Invalid file URL: {url}"))?;
+        let contents = fs_err::tokio::read(&path).await?;
+        for hasher in &mut hashers {
+            hasher.update(&contents);
+        }
+        temp_file.write_all(&contents)?;
+    } else {
+        let mut response = client
+            .for_host(&url)
+            .get(url.clone())
+            .send()
+            .await
+            .with_context(|| format!("Failed to download: {url}"))?
+            .error_for_status()
+            .with_context(|| format!("Failed to download: {url}"))?;
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .with_context(|| format!("Failed to download: {url}"))?
+        {
+            for hasher in &mut hashers {
+                hasher.update(&chunk);
+            }
+            temp_file.write_all(&chunk)?;
+        }
+    }
+
+    let digests = verify_hashes(file, hashers)?;
+
+    temp_file
+        .persist(target)
+        .with_context(|| format!("Failed to write: {}", target.user_display()))?;
+
+    Ok((true, digests))
+}
+
+/// Initialize a [`Hasher`] for each hash recorded for the file, or a SHA-256 [`Hasher`] if the
+/// file has no recorded hashes.
+fn hashers(file: &File) -> Vec<Hasher> {
+    if file.hashes.is_empty() {
+        return vec![Hasher::from(HashAlgorithm::Sha256)];
+    }
+    file.hashes
+        .iter()
+        .map(|digest| Hasher::from(digest.algorithm()))
+        .collect()
+}
+
+/// Verify that the computed digests match the hashes recorded for the file, returning the
+/// computed digests.
+fn verify_hashes(file: &File, hashers: Vec<Hasher>) -> Result<Vec<HashDigest>> {
+    let digests = hashers
+        .into_iter()
+        .map(HashDigest::from)
+        .collect::<Vec<_>>();
+    for (expected, actual) in file.hashes.iter().zip(&digests) {
+        if actual.digest != expected.digest {
+            bail!(
+                "Hash mismatch for `{}`\n\nExpected:\n  {expected}\n\nComputed:\n  {actual}",
+                file.filename
+            );
+        }
+    }
+    Ok(digests)
+}
@@ -7,11 +7,15 @@ use std::path::Path;
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
+pub(crate) use auth::login::login as auth_login;
+pub(crate) use auth::logout::logout as auth_logout;
+pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
 pub(crate) use help::help;
+pub(crate) use index::mirror::mirror as index_mirror;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
 pub(crate) use pip::freeze::pip_freeze;
@@ -27,6 +31,7 @@ pub(crate) use project::init::{init, InitKind, InitProjectKind};
 pub(crate) use project::lock::lock;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{run, RunCommand};
+pub(crate) use project::shell::shell;
 pub(crate) use project::sync::sync;
 pub(crate) use project::tree::tree;
 pub(crate) use publish::publish;
@@ -36,6 +41,7 @@ pub(crate) use python::install::install as python_install;
 pub(crate) use python::list::list as python_list;
 pub(crate) use python::pin::pin as python_pin;
 pub(crate) use python::uninstall::uninstall as python_uninstall;
+pub(crate) use python::upgrade::upgrade as python_upgrade;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
@@ -43,6 +49,7 @@ pub(crate) use tool::install::install as tool_install;
 pub(crate) use tool::list::list as tool_list;
 pub(crate) use tool::run::run as tool_run;
 pub(crate) use tool::run::ToolRunCommand;
+pub(crate) use tool::sync::sync as tool_sync;
 pub(crate) use tool::uninstall::uninstall as tool_uninstall;
 pub(crate) use tool::update_shell::update_shell as tool_update_shell;
 pub(crate) use tool::upgrade::upgrade as tool_upgrade;
@@ -53,13 +60,14 @@ use uv_git::GitResolver;
 use uv_installer::compile_tree;
 use uv_normalize::PackageName;
 use uv_python::PythonEnvironment;
-use uv_resolver::InMemoryIndex;
+use uv_resolver::{InMemoryIndex, ResolverProfile};
 use uv_types::InFlight;
 pub(crate) use venv::venv;
 pub(crate) use version::version;
 
 use crate::printer::Printer;
 
+mod auth;
 pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
@@ -67,6 +75,7 @@ mod cache_dir;
 mod cache_prune;
 mod diagnostics;
 mod help;
+mod index;
 pub(crate) mod pip;
 mod project;
 mod publish;
@@ -205,6 +214,8 @@ pub(crate) struct SharedState {
     pub(crate) in_flight: InFlight,
     /// The discovered capabilities for each registry index.
     pub(crate) capabilities: IndexCapabilities,
+    /// The profile to record resolver statistics into, if any.
+    pub(crate) profile: Option<ResolverProfile>,
 }
 
 /// A multicasting writer that writes to both the standard output and an output file, if present.
@@ -318,7 +318,13 @@ pub(crate) async fn pip_compile(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, tags.as_deref(), &hasher, &build_options)
+        FlatIndex::from_entries(
+            entries,
+            tags.as_deref(),
+            &hasher,
+            &build_options,
+            exclude_newer.as_ref(),
+        )
     };
 
     // Track in-flight downloads, builds, etc., across resolutions.
@@ -400,6 +406,7 @@ pub(crate) async fn pip_compile(
         &build_dispatch,
         concurrency,
         options,
+        None,
         Box::new(DefaultResolveLogger),
         printer,
     )
@@ -314,7 +314,13 @@ pub(crate) async fn pip_install(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(&tags),
+            &hasher,
+            &build_options,
+            exclude_newer.as_ref(),
+        )
     };
 
     // Determine whether to enable build isolation.
@@ -406,6 +412,7 @@ pub(crate) async fn pip_install(
         &build_dispatch,
         concurrency,
         options,
+        None,
         Box::new(DefaultResolveLogger),
         printer,
     )
@@ -49,6 +49,7 @@ pub(crate) fn resolution_tags<'env>(
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (Some(python_platform), None) => Cow::Owned(Tags::from_env(
             &python_platform.platform(),
@@ -57,6 +58,7 @@ pub(crate) fn resolution_tags<'env>(
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (None, Some(python_version)) => Cow::Owned(Tags::from_env(
             interpreter.platform(),
@@ -65,6 +67,7 @@ pub(crate) fn resolution_tags<'env>(
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (None, None) => Cow::Borrowed(interpreter.tags()?),
     })
@@ -84,6 +87,7 @@ pub(crate) fn resolution_environment(
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (Some(python_platform), None) => Cow::Owned(Tags::from_env(
             &python_platform.platform(),
@@ -92,6 +96,7 @@ pub(crate) fn resolution_environment(
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (None, Some(python_version)) => Cow::Owned(Tags::from_env(
             interpreter.platform(),
@@ -100,6 +105,7 @@ pub(crate) fn resolution_environment(
             interpreter.implementation_tuple(),
             interpreter.manylinux_compatible(),
             interpreter.gil_disabled(),
+            interpreter.debug_enabled(),
         )?),
         (None, None) => Cow::Borrowed(interpreter.tags()?),
     };
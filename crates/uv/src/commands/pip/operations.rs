@@ -37,7 +37,7 @@ use uv_requirements::{
 };
 use uv_resolver::{
     DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
-    Preferences, PythonRequirement, ResolutionGraph, Resolver, ResolverMarkers,
+    Preferences, PythonRequirement, ResolutionGraph, Resolver, ResolverMarkers, ResolverProfile,
 };
 use uv_types::{HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
@@ -110,6 +110,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     build_dispatch: &BuildDispatch<'_>,
     concurrency: Concurrency,
     options: Options,
+    profile: Option<&ResolverProfile>,
     logger: Box<dyn ResolveLogger>,
     printer: Printer,
 ) -> Result<ResolutionGraph, Error> {
@@ -285,7 +286,7 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
             }
         };
 
-        let resolver = Resolver::new(
+        let mut resolver = Resolver::new(
             manifest,
             options,
             &python_requirement,
@@ -300,6 +301,10 @@ pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
         )?
         .with_reporter(reporter);
 
+        if let Some(profile) = profile {
+            resolver = resolver.with_profile(profile.clone());
+        }
+
         resolver.resolve().await?
     };
 
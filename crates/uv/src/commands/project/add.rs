@@ -215,6 +215,7 @@ pub(crate) async fn add(
             python.as_deref().map(PythonRequest::parse),
             python_preference,
             python_downloads,
+            project.workspace().pyproject_toml().is_relocatable(),
             connectivity,
             native_tls,
             cache,
@@ -284,7 +285,13 @@ pub(crate) async fn add(
         let entries = client
             .fetch(settings.index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(&tags), &hasher, &settings.build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(&tags),
+            &hasher,
+            &settings.build_options,
+            settings.exclude_newer.as_ref(),
+        )
     };
 
     // Create a build dispatch.
@@ -1,4 +1,4 @@
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::project::{
@@ -7,7 +7,7 @@ use crate::commands::project::{
 use crate::commands::SharedState;
 use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
-use uv_cache::{Cache, CacheBucket};
+use uv_cache::{Cache, CacheBucket, EnvironmentLease};
 use uv_cache_key::{cache_digest, hash_digest};
 use uv_client::Connectivity;
 use uv_configuration::Concurrency;
@@ -16,12 +16,10 @@ use uv_python::{Interpreter, PythonEnvironment};
 
 /// A [`PythonEnvironment`] stored in the cache.
 #[derive(Debug)]
-pub(crate) struct CachedEnvironment(PythonEnvironment);
-
-impl From<CachedEnvironment> for PythonEnvironment {
-    fn from(environment: CachedEnvironment) -> Self {
-        environment.0
-    }
+pub(crate) struct CachedEnvironment {
+    environment: PythonEnvironment,
+    /// A lease on the environment, which prevents it from being pruned or evicted while in use.
+    lease: EnvironmentLease,
 }
 
 impl CachedEnvironment {
@@ -42,19 +40,7 @@ impl CachedEnvironment {
     ) -> Result<Self, ProjectError> {
         // When caching, always use the base interpreter, rather than that of the virtual
         // environment.
-        let interpreter = if let Some(interpreter) = interpreter.to_base_interpreter(cache)? {
-            debug!(
-                "Caching via base interpreter: `{}`",
-                interpreter.sys_executable().display()
-            );
-            interpreter
-        } else {
-            debug!(
-                "Caching via interpreter: `{}`",
-                interpreter.sys_executable().display()
-            );
-            interpreter
-        };
+        let interpreter = Self::base_interpreter(interpreter, cache)?;
 
         // Resolve the requirements with the interpreter.
         let graph = resolve_environment(
@@ -72,6 +58,67 @@ impl CachedEnvironment {
         .await?;
         let resolution = Resolution::from(graph);
 
+        Self::from_base_resolution(
+            resolution,
+            interpreter,
+            settings,
+            state,
+            install,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await
+    }
+
+    /// Get or create a [`CachedEnvironment`] for an existing [`Resolution`] (e.g., one read from a
+    /// lockfile), skipping the resolution step.
+    pub(crate) async fn from_resolution(
+        resolution: Resolution,
+        interpreter: Interpreter,
+        settings: &ResolverInstallerSettings,
+        state: &SharedState,
+        install: Box<dyn InstallLogger>,
+        connectivity: Connectivity,
+        concurrency: Concurrency,
+        native_tls: bool,
+        cache: &Cache,
+        printer: Printer,
+    ) -> Result<Self, ProjectError> {
+        // When caching, always use the base interpreter, rather than that of the virtual
+        // environment.
+        let interpreter = Self::base_interpreter(interpreter, cache)?;
+
+        Self::from_base_resolution(
+            resolution,
+            interpreter,
+            settings,
+            state,
+            install,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await
+    }
+
+    /// Get or create a [`CachedEnvironment`] for a [`Resolution`] and a base interpreter.
+    async fn from_base_resolution(
+        resolution: Resolution,
+        interpreter: Interpreter,
+        settings: &ResolverInstallerSettings,
+        state: &SharedState,
+        install: Box<dyn InstallLogger>,
+        connectivity: Connectivity,
+        concurrency: Concurrency,
+        native_tls: bool,
+        cache: &Cache,
+        printer: Printer,
+    ) -> Result<Self, ProjectError> {
         // Hash the resolution by hashing the generated lockfile.
         // TODO(charlie): If the resolution contains any mutable metadata (like a path or URL
         // dependency), skip this step.
@@ -87,10 +134,14 @@ impl CachedEnvironment {
         // Search in the content-addressed cache.
         let cache_entry = cache.entry(CacheBucket::Environments, interpreter_hash, resolution_hash);
 
+        // Lease the environment before reading or creating it, such that it can't be evicted by
+        // another process while in use.
+        let lease = cache.lease_environment(&cache_entry).await?;
+
         if cache.refresh().is_none() {
             if let Ok(root) = fs_err::read_link(cache_entry.path()) {
                 if let Ok(environment) = PythonEnvironment::from_root(root, cache) {
-                    return Ok(Self(environment));
+                    return Ok(Self { environment, lease });
                 }
             }
         }
@@ -127,11 +178,41 @@ impl CachedEnvironment {
             .await?;
         let root = cache.archive(&id);
 
-        Ok(Self(PythonEnvironment::from_root(root, cache)?))
+        // If the cache is over its size limit, evict the least-recently-used environments.
+        if let Err(err) = cache.enforce_max_size().await {
+            warn!("Failed to enforce the maximum cache size: {err}");
+        }
+
+        Ok(Self {
+            environment: PythonEnvironment::from_root(root, cache)?,
+            lease,
+        })
+    }
+
+    /// Return the base interpreter for the given [`Interpreter`], if it's a virtual environment.
+    #[allow(clippy::result_large_err)]
+    fn base_interpreter(
+        interpreter: Interpreter,
+        cache: &Cache,
+    ) -> Result<Interpreter, ProjectError> {
+        if let Some(interpreter) = interpreter.to_base_interpreter(cache)? {
+            debug!(
+                "Caching via base interpreter: `{}`",
+                interpreter.sys_executable().display()
+            );
+            Ok(interpreter)
+        } else {
+            debug!(
+                "Caching via interpreter: `{}`",
+                interpreter.sys_executable().display()
+            );
+            Ok(interpreter)
+        }
     }
 
-    /// Convert the [`CachedEnvironment`] into an [`Interpreter`].
-    pub(crate) fn into_interpreter(self) -> Interpreter {
-        self.0.into_interpreter()
+    /// Convert the [`CachedEnvironment`] into a [`PythonEnvironment`], along with the lease that
+    /// must be held for as long as the environment is in use.
+    pub(crate) fn into_parts(self) -> (PythonEnvironment, EnvironmentLease) {
+        (self.environment, self.lease)
     }
 }
@@ -9,6 +9,7 @@ use rustc_hash::{FxBuildHasher, FxHashMap};
 use tracing::debug;
 
 use uv_cache::Cache;
+use uv_cli::ProfileFormat;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildOptions, Concurrency, Constraints, ExtrasSpecification, LowerBound, Reinstall, Upgrade,
@@ -19,6 +20,7 @@ use uv_distribution_types::{
     DependencyMetadata, Index, IndexLocations, NameRequirementSpecification,
     UnresolvedRequirementSpecification,
 };
+use uv_fs::Simplified;
 use uv_git::ResolvedRepositoryReference;
 use uv_normalize::PackageName;
 use uv_pep440::Version;
@@ -28,7 +30,7 @@ use uv_requirements::upgrade::{read_lock_requirements, LockedRequirements};
 use uv_requirements::ExtrasResolver;
 use uv_resolver::{
     FlatIndex, InMemoryIndex, Lock, LockVersion, Options, OptionsBuilder, PythonRequirement,
-    RequiresPython, ResolverManifest, ResolverMarkers, SatisfiesResult, VERSION,
+    RequiresPython, ResolverManifest, ResolverMarkers, ResolverProfile, SatisfiesResult, VERSION,
 };
 use uv_types::{BuildContext, BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
@@ -75,6 +77,8 @@ pub(crate) async fn lock(
     locked: bool,
     frozen: bool,
     dry_run: bool,
+    profile_resolution: Option<&Path>,
+    profile_format: ProfileFormat,
     python: Option<String>,
     settings: ResolverSettings,
     python_preference: PythonPreference,
@@ -117,10 +121,13 @@ pub(crate) async fn lock(
     };
 
     // Initialize any shared state.
-    let state = SharedState::default();
+    let state = SharedState {
+        profile: profile_resolution.map(|_| ResolverProfile::new()),
+        ..SharedState::default()
+    };
 
     // Perform the lock operation.
-    match do_safe_lock(
+    let result = do_safe_lock(
         mode,
         &workspace,
         settings.as_ref(),
@@ -133,8 +140,9 @@ pub(crate) async fn lock(
         cache,
         printer,
     )
-    .await
-    {
+    .await;
+
+    let status = match result {
         Ok(lock) => {
             if dry_run {
                 let changed = if let LockResult::Changed(previous, lock) = &lock {
@@ -177,11 +185,48 @@ pub(crate) async fn lock(
         }
 
         Err(err) => Err(err.into()),
+    };
+
+    // Write the resolution profile, even if the resolution failed. Failing to write the profile
+    // shouldn't mask the outcome of the resolution.
+    if let (Some(path), Some(profile)) = (profile_resolution, state.profile.as_ref()) {
+        if profile.is_empty() {
+            warn_user!(
+                "The lockfile is already up-to-date, so no resolution was profiled; use `--upgrade` to force a resolution"
+            );
+        } else if let Err(err) = write_profile(profile, profile_format, path) {
+            warn_user!(
+                "Failed to write resolution profile to `{}`: {err}",
+                path.user_display()
+            );
+        } else {
+            writeln!(
+                printer.stderr(),
+                "Wrote resolution profile to: {}",
+                path.user_display().cyan()
+            )?;
+        }
     }
+
+    status
+}
+
+/// Write a [`ResolverProfile`] to the given path in the requested format.
+fn write_profile(
+    profile: &ResolverProfile,
+    format: ProfileFormat,
+    path: &Path,
+) -> anyhow::Result<()> {
+    let contents = match format {
+        ProfileFormat::Json => serde_json::to_string_pretty(&profile.report())?,
+        ProfileFormat::ChromeTrace => serde_json::to_string(&profile.chrome_trace())?,
+    };
+    fs_err::write(path, contents)?;
+    Ok(())
 }
 
 #[derive(Debug, Clone, Copy)]
-pub(super) enum LockMode<'env> {
+pub(crate) enum LockMode<'env> {
     /// Write the lockfile to disk.
     Write(&'env Interpreter),
     /// Perform a resolution, but don't write the lockfile to disk.
@@ -194,7 +239,7 @@ pub(super) enum LockMode<'env> {
 
 /// Perform a lock operation, respecting the `--locked` and `--frozen` parameters.
 #[allow(clippy::fn_params_excessive_bools)]
-pub(super) async fn do_safe_lock(
+pub(crate) async fn do_safe_lock(
     mode: LockMode<'_>,
     workspace: &Workspace,
     settings: ResolverSettingsRef<'_>,
@@ -477,7 +522,13 @@ async fn do_lock(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, None, &hasher, build_options)
+        FlatIndex::from_entries(
+            entries,
+            None,
+            &hasher,
+            build_options,
+            exclude_newer.as_ref(),
+        )
     };
 
     // Create a build dispatch.
@@ -641,6 +692,7 @@ async fn do_lock(
                 &build_dispatch,
                 concurrency,
                 options,
+                state.profile.as_ref(),
                 Box::new(SummaryResolveLogger),
                 printer,
             )
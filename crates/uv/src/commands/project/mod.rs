@@ -54,6 +54,7 @@ pub(crate) mod init;
 pub(crate) mod lock;
 pub(crate) mod remove;
 pub(crate) mod run;
+pub(crate) mod shell;
 pub(crate) mod sync;
 pub(crate) mod tree;
 
@@ -513,17 +514,22 @@ impl ProjectInterpreter {
 }
 
 /// Initialize a virtual environment for the current project.
+///
+/// If `relocatable` is `true`, the environment is created as a relocatable environment, and any
+/// existing environment that isn't relocatable is recreated.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn get_or_init_environment(
     workspace: &Workspace,
     python: Option<PythonRequest>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
+    relocatable: bool,
     connectivity: Connectivity,
     native_tls: bool,
     cache: &Cache,
     printer: Printer,
 ) -> Result<PythonEnvironment, ProjectError> {
-    match ProjectInterpreter::discover(
+    let interpreter = match ProjectInterpreter::discover(
         workspace,
         python,
         python_preference,
@@ -536,85 +542,101 @@ pub(crate) async fn get_or_init_environment(
     .await?
     {
         // If we found an existing, compatible environment, use it.
-        ProjectInterpreter::Environment(environment) => Ok(environment),
-
-        // Otherwise, create a virtual environment with the discovered interpreter.
-        ProjectInterpreter::Interpreter(interpreter) => {
-            let venv = workspace.venv();
-
-            // Avoid removing things that are not virtual environments
-            let should_remove = match (venv.try_exists(), venv.join("pyvenv.cfg").try_exists()) {
-                // It's a virtual environment we can remove it
-                (_, Ok(true)) => true,
-                // It doesn't exist at all, we should use it without deleting it to avoid TOCTOU bugs
-                (Ok(false), Ok(false)) => false,
-                // If it's not a virtual environment, bail
-                (Ok(true), Ok(false)) => {
-                    return Err(ProjectError::InvalidProjectEnvironmentDir(
-                        venv,
-                        "it is not a compatible environment but cannot be recreated because it is not a virtual environment".to_string(),
-                    ));
-                }
-                // Similarly, if we can't _tell_ if it exists we should bail
-                (_, Err(err)) | (Err(err), _) => {
-                    return Err(ProjectError::InvalidProjectEnvironmentDir(
-                        venv,
-                        format!("it is not a compatible environment but cannot be recreated because uv cannot determine if it is a virtual environment: {err}"),
-                    ));
-                }
-            };
+        ProjectInterpreter::Environment(environment)
+            if !relocatable || environment.relocatable() =>
+        {
+            return Ok(environment);
+        }
 
-            // Remove the existing virtual environment if it doesn't meet the requirements.
-            if should_remove {
-                match fs_err::remove_dir_all(&venv) {
-                    Ok(()) => {
-                        writeln!(
-                            printer.stderr(),
-                            "Removed virtual environment at: {}",
-                            venv.user_display().cyan()
-                        )?;
-                    }
-                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
-                    Err(e) => return Err(e.into()),
-                }
+        // If the existing environment isn't relocatable, recreate it with its base interpreter.
+        ProjectInterpreter::Environment(environment) => {
+            debug!(
+                "Recreating non-relocatable environment at: {}",
+                environment.root().user_display()
+            );
+            match environment.interpreter().to_base_interpreter(cache)? {
+                Some(interpreter) => interpreter,
+                None => environment.into_interpreter(),
             }
+        }
 
-            writeln!(
-                printer.stderr(),
-                "Creating virtual environment at: {}",
-                venv.user_display().cyan()
-            )?;
+        // Otherwise, create a virtual environment with the discovered interpreter.
+        ProjectInterpreter::Interpreter(interpreter) => interpreter,
+    };
 
-            // Determine a prompt for the environment, in order of preference:
-            //
-            // 1) The name of the project
-            // 2) The name of the directory at the root of the workspace
-            // 3) No prompt
-            let prompt = workspace
-                .pyproject_toml()
-                .project
-                .as_ref()
-                .map(|p| p.name.to_string())
-                .or_else(|| {
-                    workspace
-                        .install_path()
-                        .file_name()
-                        .map(|f| f.to_string_lossy().to_string())
-                })
-                .map(uv_virtualenv::Prompt::Static)
-                .unwrap_or(uv_virtualenv::Prompt::None);
-
-            Ok(uv_virtualenv::create_venv(
-                &venv,
-                interpreter,
-                prompt,
-                false,
-                false,
-                false,
-                false,
-            )?)
+    let venv = workspace.venv();
+
+    // Avoid removing things that are not virtual environments
+    let should_remove = match (venv.try_exists(), venv.join("pyvenv.cfg").try_exists()) {
+        // It's a virtual environment we can remove it
+        (_, Ok(true)) => true,
+        // It doesn't exist at all, we should use it without deleting it to avoid TOCTOU bugs
+        (Ok(false), Ok(false)) => false,
+        // If it's not a virtual environment, bail
+        (Ok(true), Ok(false)) => {
+            return Err(ProjectError::InvalidProjectEnvironmentDir(
+                venv,
+                "it is not a compatible environment but cannot be recreated because it is not a virtual environment".to_string(),
+            ));
+        }
+        // Similarly, if we can't _tell_ if it exists we should bail
+        (_, Err(err)) | (Err(err), _) => {
+            return Err(ProjectError::InvalidProjectEnvironmentDir(
+                venv,
+                format!("it is not a compatible environment but cannot be recreated because uv cannot determine if it is a virtual environment: {err}"),
+            ));
+        }
+    };
+
+    // Remove the existing virtual environment if it doesn't meet the requirements.
+    if should_remove {
+        match fs_err::remove_dir_all(&venv) {
+            Ok(()) => {
+                writeln!(
+                    printer.stderr(),
+                    "Removed virtual environment at: {}",
+                    venv.user_display().cyan()
+                )?;
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
         }
     }
+
+    writeln!(
+        printer.stderr(),
+        "Creating virtual environment at: {}",
+        venv.user_display().cyan()
+    )?;
+
+    // Determine a prompt for the environment, in order of preference:
+    //
+    // 1) The name of the project
+    // 2) The name of the directory at the root of the workspace
+    // 3) No prompt
+    let prompt = workspace
+        .pyproject_toml()
+        .project
+        .as_ref()
+        .map(|p| p.name.to_string())
+        .or_else(|| {
+            workspace
+                .install_path()
+                .file_name()
+                .map(|f| f.to_string_lossy().to_string())
+        })
+        .map(uv_virtualenv::Prompt::Static)
+        .unwrap_or(uv_virtualenv::Prompt::None);
+
+    Ok(uv_virtualenv::create_venv(
+        &venv,
+        interpreter,
+        prompt,
+        false,
+        false,
+        relocatable,
+        false,
+    )?)
 }
 
 /// Resolve any [`UnresolvedRequirementSpecification`] into a fully-qualified [`Requirement`].
@@ -796,7 +818,7 @@ pub(crate) async fn resolve_environment<'a>(
         no_build_isolation_package,
         exclude_newer,
         link_mode,
-        upgrade: _,
+        upgrade,
         build_options,
         sources,
     } = settings;
@@ -862,17 +884,18 @@ pub(crate) async fn resolve_environment<'a>(
     let build_constraints = Constraints::default();
     let build_hasher = HashStrategy::default();
 
+    // If an existing lockfile exists, build up a set of preferences, omitting any packages that
+    // should be upgraded.
+    let LockedRequirements { preferences, git } = spec
+        .lock
+        .map(|lock| read_lock_requirements(lock, upgrade))
+        .unwrap_or_default();
+
     // When resolving from an interpreter, we assume an empty environment, so reinstalls and
     // upgrades aren't relevant.
     let reinstall = Reinstall::default();
     let upgrade = Upgrade::default();
 
-    // If an existing lockfile exists, build up a set of preferences.
-    let LockedRequirements { preferences, git } = spec
-        .lock
-        .map(|lock| read_lock_requirements(lock, &upgrade))
-        .unwrap_or_default();
-
     // Populate the Git resolver.
     for ResolvedRepositoryReference { reference, sha } in git {
         debug!("Inserting Git reference into resolver: `{reference:?}` at `{sha}`");
@@ -885,7 +908,13 @@ pub(crate) async fn resolve_environment<'a>(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(tags),
+            &hasher,
+            build_options,
+            exclude_newer.as_ref(),
+        )
     };
 
     // Create a build dispatch.
@@ -937,6 +966,7 @@ pub(crate) async fn resolve_environment<'a>(
         &resolve_dispatch,
         concurrency,
         options,
+        None,
         logger,
         printer,
     )
@@ -1020,7 +1050,13 @@ pub(crate) async fn sync_environment(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(tags),
+            &hasher,
+            build_options,
+            exclude_newer.as_ref(),
+        )
     };
 
     // Create a build dispatch.
@@ -1086,16 +1122,14 @@ pub(crate) struct EnvironmentUpdate {
     pub(crate) environment: PythonEnvironment,
     /// The [`Changelog`] of changes made to the environment.
     pub(crate) changelog: Changelog,
-}
-
-impl EnvironmentUpdate {
-    /// Convert the [`EnvironmentUpdate`] into a [`PythonEnvironment`].
-    pub(crate) fn into_environment(self) -> PythonEnvironment {
-        self.environment
-    }
+    /// The [`Lock`] for the resolution, relative to the environment root, if requested and the
+    /// environment was re-resolved.
+    pub(crate) lock: Option<Lock>,
 }
 
 /// Update a [`PythonEnvironment`] to satisfy a set of [`RequirementsSource`]s.
+///
+/// If `include_lock` is set, the resolution is also returned as a [`Lock`].
 pub(crate) async fn update_environment(
     venv: PythonEnvironment,
     spec: RequirementsSpecification,
@@ -1103,6 +1137,7 @@ pub(crate) async fn update_environment(
     state: &SharedState,
     resolve: Box<dyn ResolveLogger>,
     install: Box<dyn InstallLogger>,
+    include_lock: bool,
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
@@ -1164,6 +1199,7 @@ pub(crate) async fn update_environment(
                 return Ok(EnvironmentUpdate {
                     environment: venv,
                     changelog: Changelog::default(),
+                    lock: None,
                 });
             }
             SatisfiesResult::Unsatisfied(requirement) => {
@@ -1227,7 +1263,13 @@ pub(crate) async fn update_environment(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(tags),
+            &hasher,
+            build_options,
+            exclude_newer.as_ref(),
+        )
     };
 
     // Create a build dispatch.
@@ -1256,7 +1298,7 @@ pub(crate) async fn update_environment(
     );
 
     // Resolve the requirements.
-    let resolution = match pip::operations::resolve(
+    let graph = pip::operations::resolve(
         requirements,
         constraints,
         overrides,
@@ -1279,14 +1321,17 @@ pub(crate) async fn update_environment(
         &build_dispatch,
         concurrency,
         options,
+        None,
         resolve,
         printer,
     )
-    .await
-    {
-        Ok(resolution) => Resolution::from(resolution),
-        Err(err) => return Err(err.into()),
+    .await?;
+    let lock = if include_lock {
+        Some(Lock::from_resolution_graph(&graph, venv.root())?)
+    } else {
+        None
     };
+    let resolution = Resolution::from(graph);
 
     // Sync the environment.
     let changelog = pip::operations::install(
@@ -1319,6 +1364,7 @@ pub(crate) async fn update_environment(
     Ok(EnvironmentUpdate {
         environment: venv,
         changelog,
+        lock,
     })
 }
 
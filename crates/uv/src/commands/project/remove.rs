@@ -187,6 +187,7 @@ pub(crate) async fn remove(
         python.as_deref().map(PythonRequest::parse),
         python_preference,
         python_downloads,
+        project.workspace().pyproject_toml().is_relocatable(),
         connectivity,
         native_tls,
         cache,
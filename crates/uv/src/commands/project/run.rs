@@ -60,6 +60,7 @@ pub(crate) async fn run(
     script: Option<Pep723Item>,
     command: Option<RunCommand>,
     requirements: Vec<RequirementsSource>,
+    env_file: Vec<PathBuf>,
     show_resolution: bool,
     locked: bool,
     frozen: bool,
@@ -103,6 +104,9 @@ pub(crate) async fn run(
         }
     }
 
+    // Read any environment files, before doing any work.
+    let env_vars = read_env_files(&env_file)?;
+
     // Initialize any shared state.
     let state = SharedState::default();
 
@@ -111,6 +115,7 @@ pub(crate) async fn run(
 
     // Determine whether the command to execute is a PEP 723 script.
     let temp_dir;
+    let _script_lease;
     let script_interpreter = if let Some(script) = script {
         match &script {
             Pep723Item::Script(script) => {
@@ -302,6 +307,10 @@ pub(crate) async fn run(
                 Err(err) => return Err(err.into()),
             };
 
+            // Hold the lease on the cached environment until the command exits.
+            let (environment, lease) = environment.into_parts();
+            _script_lease = lease;
+
             Some(environment.into_interpreter())
         } else {
             // Create a virtual environment.
@@ -530,6 +539,7 @@ pub(crate) async fn run(
                     python.as_deref().map(PythonRequest::parse),
                     python_preference,
                     python_downloads,
+                    project.workspace().pyproject_toml().is_relocatable(),
                     connectivity,
                     native_tls,
                     cache,
@@ -711,6 +721,7 @@ pub(crate) async fn run(
 
     // If necessary, create an environment for the ephemeral requirements or command.
     let temp_dir;
+    let _ephemeral_lease;
     let ephemeral_env = if can_skip_ephemeral(spec.as_ref(), &base_interpreter, &settings) {
         None
     } else {
@@ -785,7 +796,11 @@ pub(crate) async fn run(
                     Err(err) => return Err(err.into()),
                 };
 
-                environment.into()
+                // Hold the lease on the cached environment until the command exits.
+                let (environment, lease) = environment.into_parts();
+                _ephemeral_lease = lease;
+
+                environment
             }
         })
     };
@@ -889,6 +904,13 @@ pub(crate) async fn run(
     debug!("Running `{command}`");
     let mut process = command.as_command(interpreter);
 
+    // Set any variables from the environment files that aren't already set.
+    for (key, value) in &env_vars {
+        if std::env::var_os(key).is_none() {
+            process.env(key, value);
+        }
+    }
+
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
         ephemeral_env
@@ -944,6 +966,46 @@ pub(crate) async fn run(
     }
 }
 
+/// Read the variables defined in the given `.env` files.
+///
+/// The files are merged in order before they're parsed, such that later files take precedence over
+/// earlier ones, and `${VAR}` substitutions can refer to variables defined in earlier files.
+pub(crate) fn read_env_files(env_files: &[PathBuf]) -> anyhow::Result<BTreeMap<String, String>> {
+    let mut contents = String::new();
+    for path in env_files {
+        match fs_err::read_to_string(path) {
+            Ok(content) => {
+                contents.push_str(&content);
+                contents.push('\n');
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                bail!("No environment file found at: `{}`", path.user_display());
+            }
+            Err(err) => {
+                return Err(err).with_context(|| {
+                    format!("Failed to read environment file: `{}`", path.user_display())
+                });
+            }
+        }
+        debug!("Read environment file at: `{}`", path.user_display());
+    }
+
+    let mut env_vars = BTreeMap::new();
+    for item in dotenvy::from_read_iter(contents.as_bytes()) {
+        let (key, value) = item.with_context(|| {
+            format!(
+                "Failed to parse environment files: {}",
+                env_files
+                    .iter()
+                    .map(|path| format!("`{}`", path.user_display()))
+                    .join(", ")
+            )
+        })?;
+        env_vars.insert(key, value);
+    }
+    Ok(env_vars)
+}
+
 /// Returns `true` if we can skip creating an additional ephemeral environment in `uv run`.
 fn can_skip_ephemeral(
     spec: Option<&RequirementsSpecification>,
@@ -0,0 +1,282 @@
+use std::ffi::OsString;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::{
+    Concurrency, DevGroupsSpecification, EditableMode, ExtrasSpecification, InstallOptions,
+};
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference};
+use uv_shell::Shell;
+use uv_static::EnvVars;
+use uv_workspace::{DiscoveryOptions, VirtualProject};
+
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::sync::sync;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// Launch a shell with the project environment activated.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn shell(
+    project_dir: &Path,
+    locked: bool,
+    frozen: bool,
+    no_sync: bool,
+    package: Option<PackageName>,
+    extras: ExtrasSpecification,
+    dev: DevGroupsSpecification,
+    editable: EditableMode,
+    python: Option<String>,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    settings: ResolverInstallerSettings,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Determine the shell to launch before doing any work.
+    let Some(shell) = Shell::from_env() else {
+        bail!(
+            "Unable to detect the current shell; set the `{}` environment variable to the path of your shell",
+            EnvVars::SHELL
+        );
+    };
+
+    // Sync the project environment, as in `uv run`.
+    if !no_sync {
+        let status = sync(
+            project_dir,
+            locked,
+            frozen,
+            package,
+            extras,
+            dev,
+            editable,
+            InstallOptions::default(),
+            Modifications::Sufficient,
+            python,
+            false,
+            python_preference,
+            python_downloads,
+            settings,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await?;
+        if !matches!(status, ExitStatus::Success) {
+            return Ok(status);
+        }
+    }
+
+    // Locate the project environment.
+    let project = VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await?;
+    let root = project.workspace().venv();
+    let venv = PythonEnvironment::from_root(&root, cache).with_context(|| {
+        format!(
+            "Failed to find the project environment at `{}`",
+            root.user_display()
+        )
+    })?;
+
+    // Write any shell-specific startup files to a temporary directory, which must outlive the
+    // shell.
+    let temp_dir = tempfile::Builder::new().prefix("uv-shell-").tempdir()?;
+
+    let executable = shell_executable(shell);
+    let mut process = tokio::process::Command::new(&executable);
+    activate(shell, &mut process, &venv, temp_dir.path())?;
+
+    writeln!(
+        printer.stderr(),
+        "Launching {} with the environment at: {}",
+        executable.to_string_lossy().cyan(),
+        venv.root().user_display().cyan()
+    )?;
+    debug!("Launching shell: {shell:?}");
+
+    // Spawn and wait for completion. Standard input, output, and error streams are all inherited.
+    let mut handle = process
+        .spawn()
+        .with_context(|| format!("Failed to spawn: `{}`", executable.to_string_lossy()))?;
+
+    // Ignore signals in the parent process, deferring them to the shell. This is safe as long as
+    // the shell is the last thing that runs in this process.
+    let _handler = tokio::spawn(async { while tokio::signal::ctrl_c().await.is_ok() {} });
+
+    let status = handle.wait().await.context("Child process disappeared")?;
+
+    // Exit based on the result of the shell.
+    if let Some(code) = status.code() {
+        debug!("Shell exited with code: {code}");
+        if let Ok(code) = u8::try_from(code) {
+            Ok(ExitStatus::External(code))
+        } else {
+            #[allow(clippy::exit)]
+            std::process::exit(code);
+        }
+    } else {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            debug!("Shell exited with signal: {:?}", status.signal());
+        }
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// Return the executable to launch for the given [`Shell`].
+///
+/// Prefers the `SHELL` environment variable, if it refers to the same shell.
+fn shell_executable(shell: Shell) -> OsString {
+    if let Some(path) =
+        std::env::var_os(EnvVars::SHELL).filter(|path| Shell::from_shell_path(path) == Some(shell))
+    {
+        return path;
+    }
+    OsString::from(match shell {
+        Shell::Bash => "bash",
+        Shell::Zsh => "zsh",
+        Shell::Ksh => "ksh",
+        Shell::Fish => "fish",
+        Shell::Nushell => "nu",
+        Shell::Csh => "csh",
+        Shell::Powershell if cfg!(windows) => "powershell",
+        Shell::Powershell => "pwsh",
+        Shell::Cmd => "cmd",
+    })
+}
+
+/// Configure the shell process to activate the given environment on startup.
+fn activate(
+    shell: Shell,
+    process: &mut tokio::process::Command,
+    venv: &PythonEnvironment,
+    temp_dir: &Path,
+) -> Result<()> {
+    let scripts = venv.scripts();
+    match shell {
+        Shell::Bash => {
+            // Source the user's `.bashrc`, as `--rcfile` replaces it.
+            let rcfile = temp_dir.join(".bashrc");
+            fs_err::write(
+                &rcfile,
+                format!(
+                    "if [ -f ~/.bashrc ]; then . ~/.bashrc; fi\n. {}\n",
+                    quote_posix(&scripts.join("activate"))
+                ),
+            )?;
+            process.arg("--rcfile").arg(&rcfile).arg("-i");
+        }
+        Shell::Zsh => {
+            // Point `ZDOTDIR` at the temporary directory, such that Zsh reads our startup files.
+            // The user's `.zshenv` is sourced with their own `ZDOTDIR`, which it may change; we
+            // record the result, and restore it before sourcing the user's `.zshrc`.
+            let zdotdir = std::env::var_os(EnvVars::ZDOTDIR)
+                .map(PathBuf::from)
+                .or_else(|| etcetera::home_dir().ok())
+                .unwrap_or_default();
+            fs_err::write(
+                temp_dir.join(".zshenv"),
+                format!(
+                    "ZDOTDIR={zdotdir}\nif [ -f \"$ZDOTDIR/.zshenv\" ]; then . \"$ZDOTDIR/.zshenv\"; fi\n_uv_zdotdir=\"$ZDOTDIR\"\nZDOTDIR={temp_dir}\n",
+                    zdotdir = quote_posix(&zdotdir),
+                    temp_dir = quote_posix(temp_dir),
+                ),
+            )?;
+            fs_err::write(
+                temp_dir.join(".zshrc"),
+                format!(
+                    "ZDOTDIR=\"$_uv_zdotdir\"\nunset _uv_zdotdir\nif [ -f \"$ZDOTDIR/.zshrc\" ]; then . \"$ZDOTDIR/.zshrc\"; fi\n. {activate}\n",
+                    activate = quote_posix(&scripts.join("activate"))
+                ),
+            )?;
+            process.env(EnvVars::ZDOTDIR, temp_dir).arg("-i");
+        }
+        Shell::Ksh => {
+            // Interactive Korn shells source the file referenced by `ENV`.
+            let rcfile = temp_dir.join(".kshrc");
+            fs_err::write(
+                &rcfile,
+                format!(
+                    "if [ -f ~/.kshrc ]; then . ~/.kshrc; fi\n. {}\n",
+                    quote_posix(&scripts.join("activate"))
+                ),
+            )?;
+            process.env(EnvVars::ENV, &rcfile).arg("-i");
+        }
+        Shell::Fish => {
+            process.arg("--init-command").arg(format!(
+                "source {}",
+                quote_posix(&scripts.join("activate.fish"))
+            ));
+        }
+        Shell::Nushell => {
+            process.arg("--execute").arg(format!(
+                "overlay use {}",
+                quote_posix(&scripts.join("activate.nu"))
+            ));
+        }
+        Shell::Csh => {
+            // The C shell has no option to read an alternative startup file, so point `HOME` at
+            // the temporary directory, whose `.cshrc` restores `HOME` and sources the user's own
+            // startup file before activating the environment.
+            let home = etcetera::home_dir().unwrap_or_default();
+            fs_err::write(
+                temp_dir.join(".cshrc"),
+                format!(
+                    "setenv HOME {home}\nset home = {home}\nif ( $?tcsh && -f ~/.tcshrc ) then\n    source ~/.tcshrc\nelse if ( -f ~/.cshrc ) then\n    source ~/.cshrc\nendif\nsource {activate}\n",
+                    home = quote_posix(&home),
+                    activate = quote_posix(&scripts.join("activate.csh"))
+                ),
+            )?;
+            process.env(EnvVars::HOME, temp_dir).arg("-i");
+        }
+        Shell::Powershell => {
+            process.arg("-NoExit").arg("-Command").arg(format!(
+                ". {}",
+                quote_powershell(&scripts.join("activate.ps1"))
+            ));
+        }
+        Shell::Cmd => {
+            process.arg("/k").arg(
+                scripts
+                    .join("activate.bat")
+                    .simplified_display()
+                    .to_string(),
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Quote a path for use in a POSIX-compatible shell (or Fish or Nushell) command.
+fn quote_posix(path: &Path) -> String {
+    format!(
+        "'{}'",
+        path.simplified_display()
+            .to_string()
+            .replace('\'', r#"'"'"'"#)
+    )
+}
+
+/// Quote a path for use in a `PowerShell` command.
+fn quote_powershell(path: &Path) -> String {
+    format!(
+        "'{}'",
+        path.simplified_display().to_string().replace('\'', "''")
+    )
+}
@@ -1,9 +1,12 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 use std::path::Path;
 use std::str::FromStr;
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
 use uv_auth::store_credentials;
 use uv_cache::Cache;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
@@ -13,12 +16,14 @@ use uv_configuration::{
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution_types::{DirectorySourceDist, Dist, Index, ResolvedDist, SourceDist};
+use uv_fs::Simplified;
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_pep508::{MarkerTree, Requirement, VersionOrUrl};
 use uv_pypi_types::{
     LenientRequirement, ParsedArchiveUrl, ParsedGitUrl, ParsedUrl, VerbatimParsedUrl,
 };
+use uv_python::managed::ManagedPythonInstallations;
 use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_resolver::{FlatIndex, Lock};
 use uv_types::{BuildIsolation, HashStrategy};
@@ -50,6 +55,7 @@ pub(crate) async fn sync(
     install_options: InstallOptions,
     modifications: Modifications,
     python: Option<String>,
+    bundle_python: bool,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     settings: ResolverInstallerSettings,
@@ -59,6 +65,10 @@ pub(crate) async fn sync(
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
+    if bundle_python {
+        validate_bundle_python()?;
+    }
+
     // Identify the project.
     let project = if frozen {
         VirtualProject::discover(
@@ -105,6 +115,7 @@ pub(crate) async fn sync(
         python.as_deref().map(PythonRequest::parse),
         python_preference,
         python_downloads,
+        bundle_python || target.workspace().pyproject_toml().is_relocatable(),
         connectivity,
         native_tls,
         cache,
@@ -112,6 +123,13 @@ pub(crate) async fn sync(
     )
     .await?;
 
+    // If requested, bundle the managed Python installation into the environment.
+    let venv = if bundle_python {
+        bundle_managed_python(venv, cache, printer)?
+    } else {
+        venv
+    };
+
     // Initialize any shared state.
     let state = SharedState::default();
 
@@ -184,6 +202,60 @@ pub(crate) async fn sync(
     Ok(ExitStatus::Success)
 }
 
+/// Return an error if `--bundle-python` isn't supported on the current platform.
+///
+/// On Windows, the virtual environment launchers locate the interpreter via the absolute `home` in
+/// `pyvenv.cfg`, so a bundled environment wouldn't be self-contained once moved.
+pub(crate) fn validate_bundle_python() -> Result<()> {
+    if cfg!(windows) {
+        bail!("The `--bundle-python` option is not supported on Windows");
+    }
+    Ok(())
+}
+
+/// Copy the managed Python installation underlying a virtual environment into the environment.
+pub(crate) fn bundle_managed_python(
+    venv: PythonEnvironment,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<PythonEnvironment> {
+    let base_prefix = fs_err::canonicalize(venv.interpreter().sys_base_prefix())?;
+
+    // If the environment is already based on a bundled interpreter, there's nothing to do.
+    if base_prefix.starts_with(fs_err::canonicalize(venv.root())?) {
+        debug!(
+            "Python is already bundled into the environment at: {}",
+            venv.root().user_display()
+        );
+        return Ok(venv);
+    }
+
+    let Some(installation) = ManagedPythonInstallations::from_settings()?
+        .find_all()?
+        .find(|installation| {
+            fs_err::canonicalize(installation.path()).is_ok_and(|path| path == base_prefix)
+        })
+    else {
+        bail!(
+            "The `--bundle-python` option requires a Python installation managed by uv, but the environment is based on the Python installation at `{}`\n\n{}{} use `--python-preference only-managed` to select a managed Python installation",
+            base_prefix.user_display(),
+            "hint".bold().cyan(),
+            ":".bold()
+        );
+    };
+
+    writeln!(
+        printer.stderr(),
+        "Bundling {} into: {}",
+        installation.key().green(),
+        venv.root().join("python").user_display().cyan()
+    )?;
+
+    uv_virtualenv::bundle_python(&venv, installation.path())?;
+
+    Ok(PythonEnvironment::from_root(venv.root(), cache)?)
+}
+
 /// Sync a lockfile with an environment.
 #[allow(clippy::fn_params_excessive_bools)]
 pub(super) async fn do_sync(
@@ -332,7 +404,13 @@ pub(super) async fn do_sync(
         let entries = client
             .fetch(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(tags),
+            &hasher,
+            build_options,
+            exclude_newer.as_ref(),
+        )
     };
 
     // Create a build dispatch.
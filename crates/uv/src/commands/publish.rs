@@ -3,32 +3,47 @@ use crate::commands::{human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
 use anyhow::{bail, Context, Result};
 use console::Term;
+use futures::StreamExt;
 use owo_colors::OwoColorize;
+use serde::Serialize;
 use std::fmt::Write;
 use std::iter;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
-use tracing::info;
+use tracing::{debug, info};
 use url::Url;
 use uv_cache::Cache;
+use uv_cli::{MirrorFormat, PublishFormat};
 use uv_client::{
-    AuthIntegration, BaseClientBuilder, Connectivity, RegistryClientBuilder, DEFAULT_RETRIES,
+    AuthIntegration, BaseClient, BaseClientBuilder, Connectivity, RegistryClientBuilder,
+    DEFAULT_RETRIES,
 };
 use uv_configuration::{KeyringProviderType, TrustedHost, TrustedPublishing};
+use uv_distribution_filename::DistFilename;
 use uv_distribution_types::{Index, IndexCapabilities, IndexLocations, IndexUrl};
 use uv_publish::{
-    check_trusted_publishing, files_for_publishing, upload, CheckUrlClient, TrustedPublishResult,
+    check_trusted_publishing, files_for_publishing, publish_static, read_attestations, upload,
+    validate_files, CheckUrlClient, IndexLayout, PublishError, StaticIndex, TrustedPublishResult,
 };
 
 pub(crate) async fn publish(
     paths: Vec<String>,
     publish_url: Url,
+    index: Option<String>,
+    publish_index: Option<Index>,
     trusted_publishing: TrustedPublishing,
     keyring_provider: KeyringProviderType,
     allow_insecure_host: Vec<TrustedHost>,
     username: Option<String>,
     password: Option<String>,
     check_url: Option<IndexUrl>,
+    attestations_dir: Option<&Path>,
+    concurrent_uploads: NonZeroUsize,
+    dry_run: bool,
+    format: PublishFormat,
+    layout: MirrorFormat,
     cache: &Cache,
     connectivity: Connectivity,
     native_tls: bool,
@@ -38,6 +53,16 @@ pub(crate) async fn publish(
         bail!("Unable to publish files in offline mode");
     }
 
+    // If an index was requested by name, it must be defined with a `publish-url`.
+    if let Some(name) = index.as_deref() {
+        let Some(publish_index) = publish_index.as_ref() else {
+            bail!("Index not found: `{name}`. Define the index in `[[tool.uv.index]]` to publish to it by name.");
+        };
+        if publish_index.publish_url.is_none() {
+            bail!("Index `{name}` does not define a `publish-url`");
+        }
+    }
+
     let files = files_for_publishing(paths)?;
     match files.len() {
         0 => bail!("No files found to publish"),
@@ -45,6 +70,9 @@ pub(crate) async fn publish(
         n => writeln!(printer.stderr(), "Publishing {n} files {publish_url}")?,
     }
 
+    // Check all files before uploading any of them.
+    validate_files(&files).await?;
+
     // * For the uploads themselves, we roll our own retries due to
     //   https://github.com/seanmonstar/reqwest/issues/2416, but for trusted publishing, we want
     //   the default retries.
@@ -59,6 +87,8 @@ pub(crate) async fn publish(
         .keyring(keyring_provider)
         .native_tls(native_tls)
         .allow_insecure_host(allow_insecure_host.clone())
+        // Apply the authentication and TLS settings of the target index to the upload.
+        .indexes(publish_index.iter())
         // Don't try cloning the request to make an unauthenticated request first.
         .auth_integration(AuthIntegration::OnlyAuthenticated)
         // Set a very high timeout for uploads, connections are often 10x slower on upload than
@@ -69,14 +99,53 @@ pub(crate) async fn publish(
         .auth_integration(AuthIntegration::NoAuthMiddleware)
         .wrap_existing(&upload_client);
 
+    // Local directories and S3 buckets are static indexes, which we write to directly instead of
+    // using the upload API.
+    if let Some(target) = StaticIndex::from_url(&publish_url)? {
+        if check_url.is_some() {
+            bail!("`--check-url` is not supported when publishing to a static index");
+        }
+        let layout = match layout {
+            MirrorFormat::Simple => IndexLayout::Simple,
+            MirrorFormat::Flat => IndexLayout::Flat,
+        };
+        let uploaded = publish_static(&target, &files, layout, &oidc_client, dry_run).await?;
+
+        let mut summary = Vec::with_capacity(files.len());
+        for ((file, _, filename), uploaded) in files.iter().zip(uploaded) {
+            let status = match (uploaded, dry_run) {
+                (true, false) => UploadStatus::Uploaded,
+                (true, true) => UploadStatus::DryRun,
+                (false, _) => UploadStatus::Skipped,
+            };
+            let entry = UploadSummary::new(file, filename, status)?;
+            let (bytes, unit) = human_readable_bytes(entry.size);
+            if uploaded {
+                let label = if dry_run { "Would upload" } else { "Uploaded" };
+                writeln!(
+                    printer.stderr(),
+                    "{} {filename} {}",
+                    label.bold().green(),
+                    format!("({bytes:.1}{unit})").dimmed()
+                )?;
+            } else {
+                writeln!(printer.stderr(), "File {filename} already exists, skipping")?;
+            }
+            summary.push(entry);
+        }
+
+        write_report(&summary, format, printer)?;
+        return Ok(ExitStatus::Success);
+    }
+
     // Initialize the registry client.
-    let check_url_client = if let Some(index_url) = check_url {
-        let index_urls = IndexLocations::new(
-            vec![Index::from_index_url(index_url.clone())],
-            Vec::new(),
-            false,
-        )
-        .index_urls();
+    let check_index = match check_url {
+        Some(index_url) => Some(Index::from_index_url(index_url)),
+        None => publish_index,
+    };
+    let check_url_client = if let Some(check_index) = check_index {
+        let index_url = check_index.url.clone();
+        let index_urls = IndexLocations::new(vec![check_index], Vec::new(), false).index_urls();
         let registry_client_builder = RegistryClientBuilder::new(cache.clone())
             .native_tls(native_tls)
             .connectivity(connectivity)
@@ -153,48 +222,279 @@ pub(crate) async fn publish(
         }
     }
 
+    // Check which files already exist upfront, such that we can report the full plan before
+    // uploading anything, and such that a failed publish can be resumed by re-running it.
+    let mut summary = Vec::with_capacity(files.len());
+    let mut pending = Vec::with_capacity(files.len());
     for (file, raw_filename, filename) in files {
         if let Some(check_url_client) = &check_url_client {
             if uv_publish::check_url(check_url_client, &file, &filename).await? {
                 writeln!(printer.stderr(), "File {filename} already exists, skipping")?;
+                summary.push(UploadSummary::new(&file, &filename, UploadStatus::Skipped)?);
                 continue;
             }
         }
+        pending.push((file, raw_filename, filename));
+    }
+
+    let username = username.as_deref();
+    let password = password.as_deref();
+    let publish_url = &publish_url;
+    let upload_client = &upload_client;
+    let check_url_client = check_url_client.as_ref();
+
+    // Share a single set of progress bars across all concurrent uploads.
+    let reporter = Arc::new(PublishReporter::new(printer, pending.len() as u64));
+
+    // Upload the remaining files. A failed upload doesn't abort the others: every file is
+    // reported, and the command fails at the end.
+    let mut uploaded = futures::stream::iter(pending.into_iter().enumerate())
+        .map(|(index, (file, raw_filename, filename))| {
+            let reporter = reporter.clone();
+            async move {
+                let result = upload_file(
+                    &file,
+                    &raw_filename,
+                    &filename,
+                    attestations_dir,
+                    publish_url,
+                    upload_client,
+                    username,
+                    password,
+                    check_url_client,
+                    dry_run,
+                    reporter,
+                    printer,
+                )
+                .await;
+                (index, file, filename, result)
+            }
+        })
+        .buffer_unordered(concurrent_uploads.get())
+        .collect::<Vec<_>>()
+        .await;
+
+    // Report the results in the original order.
+    uploaded.sort_by_key(|(index, ..)| *index);
+    let mut failed = false;
+    for (_, file, filename, result) in uploaded {
+        match result {
+            Ok(status) => summary.push(UploadSummary::new(&file, &filename, status)?),
+            Err(err) => {
+                failed = true;
+                let mut causes = err.chain();
+                writeln!(
+                    printer.stderr(),
+                    "{}: {}",
+                    "error".red().bold(),
+                    causes.next().unwrap().to_string().trim()
+                )?;
+                for err in causes {
+                    writeln!(
+                        printer.stderr(),
+                        "  {}: {}",
+                        "Caused by".red().bold(),
+                        err.to_string().trim()
+                    )?;
+                }
+                summary.push(UploadSummary::failed(&file, &filename, &err));
+            }
+        }
+    }
+
+    write_report(&summary, format, printer)?;
+
+    if failed {
+        return Ok(ExitStatus::Error);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Upload a single file, returning the outcome.
+async fn upload_file(
+    file: &Path,
+    raw_filename: &str,
+    filename: &DistFilename,
+    attestations_dir: Option<&Path>,
+    publish_url: &Url,
+    upload_client: &BaseClient,
+    username: Option<&str>,
+    password: Option<&str>,
+    check_url_client: Option<&CheckUrlClient<'_>>,
+    dry_run: bool,
+    reporter: Arc<PublishReporter>,
+    printer: Printer,
+) -> Result<UploadStatus> {
+    let size = fs_err::metadata(file)?.len();
+    let (bytes, unit) = human_readable_bytes(size);
+    writeln!(
+        printer.stderr(),
+        "{} {filename} {}",
+        if dry_run {
+            "Would upload".bold().green().to_string()
+        } else {
+            "Uploading".bold().green().to_string()
+        },
+        format!("({bytes:.1}{unit})").dimmed()
+    )?;
+
+    // TODO(attestations): Generate attestations when using trusted publishing. This requires
+    // Sigstore signing (requesting a certificate from Fulcio with the OIDC token, and logging the
+    // signature to Rekor), which uv doesn't implement; only pre-made attestations are uploaded.
+    let attestations = if let Some(attestations_dir) = attestations_dir {
+        read_attestations(attestations_dir, raw_filename)
+            .map_err(|err| PublishError::Attestation(file.to_path_buf(), err))?
+    } else {
+        Vec::new()
+    };
+    if !attestations.is_empty() {
+        debug!(
+            "Uploading {filename} with {} attestation(s)",
+            attestations.len()
+        );
+    }
+
+    if dry_run {
+        return Ok(UploadStatus::DryRun);
+    }
 
-        let size = fs_err::metadata(&file)?.len();
-        let (bytes, unit) = human_readable_bytes(size);
+    let uploaded = upload(
+        file,
+        raw_filename,
+        filename,
+        publish_url,
+        upload_client,
+        DEFAULT_RETRIES,
+        username,
+        password,
+        &attestations,
+        check_url_client,
+        // Needs to be an `Arc` because the reqwest `Body` static lifetime requirement
+        reporter,
+    )
+    .await?; // Filename and/or URL are already attached, if applicable.
+    info!("Upload succeeded");
+    if uploaded {
+        Ok(UploadStatus::Uploaded)
+    } else {
         writeln!(
             printer.stderr(),
-            "{} {filename} {}",
-            "Uploading".bold().green(),
-            format!("({bytes:.1}{unit})").dimmed()
+            "{}",
+            "File already exists, skipping".dimmed()
         )?;
-        let reporter = PublishReporter::single(printer);
-        let uploaded = upload(
-            &file,
-            &raw_filename,
-            &filename,
-            &publish_url,
-            &upload_client,
-            DEFAULT_RETRIES,
-            username.as_deref(),
-            password.as_deref(),
-            check_url_client.as_ref(),
-            // Needs to be an `Arc` because the reqwest `Body` static lifetime requirement
-            Arc::new(reporter),
-        )
-        .await?; // Filename and/or URL are already attached, if applicable.
-        info!("Upload succeeded");
-        if !uploaded {
+        Ok(UploadStatus::Exists)
+    }
+}
+
+/// The outcome of publishing a single file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum UploadStatus {
+    /// The file was uploaded.
+    Uploaded,
+    /// The file was found in the index by `--check-url` before uploading.
+    Skipped,
+    /// The index reported that the file already exists.
+    Exists,
+    /// The file would have been uploaded, but this is a dry run.
+    DryRun,
+    /// The upload failed.
+    Failed,
+}
+
+impl UploadStatus {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Uploaded => "uploaded",
+            Self::Skipped => "skipped",
+            Self::Exists => "exists",
+            Self::DryRun => "would upload",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// An entry in the summary of a `uv publish` invocation.
+#[derive(Debug, Serialize)]
+struct UploadSummary {
+    filename: String,
+    path: PathBuf,
+    size: u64,
+    status: UploadStatus,
+    /// The error that caused the upload to fail, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl UploadSummary {
+    fn new(file: &Path, filename: &DistFilename, status: UploadStatus) -> Result<Self> {
+        Ok(Self {
+            filename: filename.to_string(),
+            path: file.to_path_buf(),
+            size: fs_err::metadata(file)?.len(),
+            status,
+            error: None,
+        })
+    }
+
+    fn failed(file: &Path, filename: &DistFilename, err: &anyhow::Error) -> Self {
+        Self {
+            filename: filename.to_string(),
+            path: file.to_path_buf(),
+            size: fs_err::metadata(file)
+                .map(|metadata| metadata.len())
+                .unwrap_or_default(),
+            status: UploadStatus::Failed,
+            error: Some(format!("{err:#}")),
+        }
+    }
+}
+
+/// Report the summary in the requested format.
+fn write_report(summary: &[UploadSummary], format: PublishFormat, printer: Printer) -> Result<()> {
+    match format {
+        PublishFormat::Text => {
+            // A single file doesn't need a summary.
+            if summary.len() > 1 {
+                write_summary(summary, printer)?;
+            }
+        }
+        PublishFormat::Json => {
             writeln!(
-                printer.stderr(),
+                printer.stdout(),
                 "{}",
-                "File already exists, skipping".dimmed()
+                serde_json::to_string_pretty(summary)?
             )?;
         }
     }
+    Ok(())
+}
 
-    Ok(ExitStatus::Success)
+/// Write the summary as a table to stderr.
+fn write_summary(summary: &[UploadSummary], printer: Printer) -> Result<()> {
+    let width = summary
+        .iter()
+        .map(|entry| entry.status.label().len())
+        .max()
+        .unwrap_or_default();
+    writeln!(printer.stderr(), "{}", "Summary:".bold())?;
+    for entry in summary {
+        let (bytes, unit) = human_readable_bytes(entry.size);
+        let label = format!("{:width$}", entry.status.label());
+        let label = match entry.status {
+            UploadStatus::Uploaded | UploadStatus::DryRun => label.green().to_string(),
+            UploadStatus::Skipped | UploadStatus::Exists => label.dimmed().to_string(),
+            UploadStatus::Failed => label.red().to_string(),
+        };
+        writeln!(
+            printer.stderr(),
+            "  {label}  {} {}",
+            entry.filename,
+            format!("({bytes:.1}{unit})").dimmed()
+        )?;
+    }
+    Ok(())
 }
 
 fn prompt_username_and_password() -> Result<(Option<String>, Option<String>)> {
@@ -1,15 +1,19 @@
 use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::PathBuf;
 
 use anyhow::Result;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
+use serde::Serialize;
 use uv_cache::Cache;
+use uv_cli::PythonListFormat;
 use uv_fs::Simplified;
 use uv_python::downloads::PythonDownloadRequest;
 use uv_python::{
     find_python_installations, DiscoveryError, EnvironmentPreference, PythonDownloads,
-    PythonInstallation, PythonNotFound, PythonPreference, PythonRequest, PythonSource,
+    PythonInstallation, PythonInstallationKey, PythonNotFound, PythonPreference, PythonRequest,
+    PythonSource,
 };
 
 use crate::commands::ExitStatus;
@@ -23,12 +27,64 @@ enum Kind {
     System,
 }
 
+/// An entry in a JSON list of Python installations.
+#[derive(Debug, Serialize)]
+struct Entry {
+    key: String,
+    version: String,
+    implementation: String,
+    variant: String,
+    os: String,
+    arch: String,
+    libc: String,
+    /// The path to the Python executable, if installed.
+    path: Option<String>,
+    /// The target of the Python executable, if it is a symlink.
+    symlink: Option<String>,
+    /// Where the Python installation was discovered, if installed.
+    source: Option<PythonSource>,
+    installed: bool,
+}
+
+impl Entry {
+    fn new(
+        key: &PythonInstallationKey,
+        path: Option<&PathBuf>,
+        source: Option<PythonSource>,
+    ) -> Result<Self> {
+        let symlink = path
+            .map(|path| -> Result<Option<String>> {
+                if fs_err::symlink_metadata(path)?.is_symlink() {
+                    Ok(Some(path.read_link()?.simplified_display().to_string()))
+                } else {
+                    Ok(None)
+                }
+            })
+            .transpose()?
+            .flatten();
+        Ok(Self {
+            key: key.to_string(),
+            version: key.version().to_string(),
+            implementation: key.implementation().to_string(),
+            variant: key.variant().to_string(),
+            os: key.os().to_string(),
+            arch: key.arch().to_string(),
+            libc: key.libc().to_string(),
+            path: path.map(|path| path.simplified_display().to_string()),
+            symlink,
+            source,
+            installed: path.is_some(),
+        })
+    }
+}
+
 /// List available Python installations.
 #[allow(clippy::too_many_arguments)]
 pub(crate) async fn list(
     kinds: PythonListKinds,
     all_versions: bool,
     all_platforms: bool,
+    output_format: PythonListFormat,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     cache: &Cache,
@@ -57,11 +113,12 @@ pub(crate) async fn list(
         let downloads = download_request
             .as_ref()
             .map(uv_python::downloads::PythonDownloadRequest::iter_downloads)
+            .transpose()?
             .into_iter()
             .flatten();
 
         for download in downloads {
-            output.insert((download.key().clone(), Kind::Download, None));
+            output.insert((download.key().clone(), Kind::Download, None, None));
         }
     };
 
@@ -93,6 +150,7 @@ pub(crate) async fn list(
             installation.key(),
             kind,
             Some(installation.interpreter().sys_executable().to_path_buf()),
+            Some(*installation.source()),
         ));
     }
 
@@ -100,7 +158,7 @@ pub(crate) async fn list(
     let mut seen_patch = FxHashSet::default();
     let mut seen_paths = FxHashSet::default();
     let mut include = Vec::new();
-    for (key, kind, path) in output.iter().rev() {
+    for (key, kind, path, source) in output.iter().rev() {
         // Do not show the same path more than once
         if let Some(path) = path {
             if !seen_paths.insert(path) {
@@ -140,15 +198,24 @@ pub(crate) async fn list(
                 }
             }
         }
-        include.push((key, path));
+        include.push((key, path, *source));
+    }
+
+    if output_format == PythonListFormat::Json {
+        let entries = include
+            .into_iter()
+            .map(|(key, path, source)| Entry::new(key, path.as_ref(), source))
+            .collect::<Result<Vec<_>>>()?;
+        writeln!(printer.stdout(), "{}", serde_json::to_string(&entries)?)?;
+        return Ok(ExitStatus::Success);
     }
 
     // Compute the width of the first column.
     let width = include
         .iter()
-        .fold(0usize, |acc, (key, _)| acc.max(key.to_string().len()));
+        .fold(0usize, |acc, (key, ..)| acc.max(key.to_string().len()));
 
-    for (key, path) in include {
+    for (key, path, _) in include {
         let key = key.to_string();
         if let Some(path) = path {
             let is_symlink = fs_err::symlink_metadata(path)?.is_symlink();
@@ -0,0 +1,465 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::{debug, warn};
+
+use uv_client::Connectivity;
+use uv_configuration::PreviewMode;
+use uv_fs::Simplified;
+use uv_python::downloads::{DownloadResult, ManagedPythonDownload, PythonDownloadRequest};
+use uv_python::managed::{
+    python_executable_dir, ManagedPythonInstallation, ManagedPythonInstallations,
+};
+use uv_python::{PyVenvConfiguration, PythonDownloads, PythonRequest};
+use uv_static::EnvVars;
+use uv_tool::InstalledTools;
+use uv_warnings::warn_user;
+
+use crate::commands::python::{ChangeEvent, ChangeEventKind};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+
+/// An environment that may depend on a managed Python installation.
+#[derive(Debug)]
+struct Dependent {
+    /// A description of the environment, e.g., "tool `ruff`".
+    name: String,
+    /// The `home` directory from the environment's `pyvenv.cfg`.
+    home: Option<PathBuf>,
+    /// The Python request from the tool receipt, if any.
+    request: Option<PythonRequest>,
+}
+
+/// An upgrade of the installations of a Python minor version.
+#[derive(Debug)]
+struct Upgrade<'a> {
+    /// The download for the latest patch release, if it isn't installed yet.
+    download: Option<&'static ManagedPythonDownload>,
+    /// The installed latest patch release, if it is installed already.
+    installed: Option<&'a ManagedPythonInstallation>,
+    /// The outdated installations of the minor version.
+    outdated: Vec<&'a ManagedPythonInstallation>,
+}
+
+/// Upgrade managed Python versions to the latest patch release.
+pub(crate) async fn upgrade(
+    project_dir: &Path,
+    targets: Vec<String>,
+    keep: bool,
+    python_downloads: PythonDownloads,
+    native_tls: bool,
+    connectivity: Connectivity,
+    preview: PreviewMode,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    let requests = targets
+        .iter()
+        .map(|target| PythonRequest::parse(target.as_str()))
+        .collect::<Vec<_>>();
+    let download_requests = requests
+        .iter()
+        .map(|request| {
+            PythonDownloadRequest::from_request(request)
+                .map(|request| request.with_prereleases(true))
+                .ok_or_else(|| {
+                    anyhow::anyhow!("Cannot upgrade managed Python for request: {request}")
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Read the existing installations, lock the directory for the duration
+    let installations = ManagedPythonInstallations::from_settings()?.init()?;
+    let installations_dir = installations.root();
+    let cache_dir = installations.cache();
+    let _lock = installations.lock().await?;
+    let existing_installations: Vec<_> = installations.find_all()?.collect();
+
+    // Find the installations to upgrade, i.e., all installations if no targets are provided.
+    let mut selected = BTreeSet::default();
+    for (request, download_request) in requests.iter().zip(&download_requests) {
+        let matching = existing_installations
+            .iter()
+            .filter(|installation| download_request.satisfied_by_key(installation.key()))
+            .collect::<Vec<_>>();
+        if matching.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "No existing installations found for: {}",
+                request.cyan()
+            )?;
+        }
+        selected.extend(matching);
+    }
+    if requests.is_empty() {
+        selected.extend(existing_installations.iter());
+    }
+    if selected.is_empty() {
+        writeln!(printer.stderr(), "No Python installations found")?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Group the installations by minor version, and determine the latest patch release of each.
+    let mut upgrades = Vec::new();
+    for installation in &selected {
+        if upgrades.iter().any(|upgrade: &Upgrade| {
+            upgrade
+                .outdated
+                .iter()
+                .chain(upgrade.installed.iter())
+                .any(|other| other.key().is_same_minor(installation.key()))
+        }) {
+            continue;
+        }
+        let group = existing_installations
+            .iter()
+            .filter(|other| other.key().is_same_minor(installation.key()))
+            .collect::<Vec<_>>();
+        let newest = group
+            .iter()
+            .copied()
+            .max_by(|a, b| a.version().version().cmp(b.version().version()))
+            .expect("The group contains at least the installation itself");
+
+        let upgrade = if let Some(download) = ManagedPythonDownload::latest_patch(newest.key())? {
+            debug!(
+                "Found newer download `{}` for `{}`",
+                download,
+                newest.key().green()
+            );
+            Upgrade {
+                download: Some(download),
+                installed: None,
+                outdated: group,
+            }
+        } else {
+            debug!("`{}` is the latest patch release", newest.key().green());
+            Upgrade {
+                download: None,
+                installed: Some(newest),
+                outdated: group
+                    .into_iter()
+                    .filter(|installation| installation.key() != newest.key())
+                    .collect(),
+            }
+        };
+        upgrades.push(upgrade);
+    }
+
+    let downloads = upgrades
+        .iter()
+        .filter_map(|upgrade| upgrade.download)
+        .collect::<Vec<_>>();
+
+    if downloads.is_empty() && (keep || upgrades.iter().all(|upgrade| upgrade.outdated.is_empty()))
+    {
+        if requests.is_empty() {
+            writeln!(
+                printer.stderr(),
+                "All Python installations are already on the latest patch release"
+            )?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                "All requested versions are already on the latest patch release"
+            )?;
+        }
+        return Ok(ExitStatus::Success);
+    }
+
+    // Check if Python downloads are banned
+    if matches!(python_downloads, PythonDownloads::Never) && !downloads.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "Python downloads are not allowed (`python-downloads = \"never\"`). Change to `python-downloads = \"manual\"` to allow explicit installs.",
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    // Download and unpack the Python versions concurrently
+    let client = uv_client::BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .build();
+    let reporter = PythonDownloadReporter::new(printer, downloads.len() as u64);
+    let mut tasks = FuturesUnordered::new();
+    for download in &downloads {
+        tasks.push(async {
+            (
+                download.key(),
+                download
+                    .fetch(
+                        &client,
+                        installations_dir,
+                        &cache_dir,
+                        false,
+                        Some(&reporter),
+                    )
+                    .await,
+            )
+        });
+    }
+
+    let mut errors = vec![];
+    let mut downloaded = Vec::with_capacity(downloads.len());
+    while let Some((key, result)) = tasks.next().await {
+        match result {
+            Ok(DownloadResult::AlreadyAvailable(path) | DownloadResult::Fetched(path)) => {
+                let installation = ManagedPythonInstallation::new(path)?;
+                installation.ensure_externally_managed()?;
+                installation.ensure_canonical_executables()?;
+                downloaded.push(installation);
+            }
+            Err(err) => {
+                errors.push((key, anyhow::Error::new(err)));
+            }
+        }
+    }
+
+    // Find the environments that may still use the outdated installations.
+    let dependents = find_dependents(project_dir)?;
+
+    let bin = if preview.is_enabled() {
+        Some(python_executable_dir()?)
+    } else {
+        None
+    };
+
+    let mut events = Vec::new();
+    let mut kept = Vec::new();
+    for upgrade in &upgrades {
+        // If the download failed, keep the outdated installations.
+        let latest = if let Some(download) = upgrade.download {
+            let Some(latest) = downloaded
+                .iter()
+                .find(|installation| installation.key() == download.key())
+            else {
+                continue;
+            };
+            events.push(ChangeEvent {
+                key: latest.key().clone(),
+                kind: ChangeEventKind::Added,
+            });
+            latest
+        } else {
+            upgrade
+                .installed
+                .expect("An up-to-date upgrade has an installation")
+        };
+
+        for outdated in &upgrade.outdated {
+            // Point the Python executable at the latest patch release.
+            if let Some(bin) = &bin {
+                let target = bin.join(outdated.key().versioned_executable_name());
+                if outdated.is_bin_link(&target) {
+                    fs_err::remove_file(&target)?;
+                    latest.create_bin_link(&target)?;
+                    debug!(
+                        "Updated executable at {} to {}",
+                        target.user_display(),
+                        latest.key(),
+                    );
+                }
+            }
+
+            if keep {
+                debug!("Keeping `{}` due to `--keep` flag", outdated.key());
+                continue;
+            }
+
+            let users = dependents
+                .iter()
+                .filter(|dependent| dependent.uses(outdated, latest))
+                .map(|dependent| dependent.name.as_str())
+                .collect::<Vec<_>>();
+            if !users.is_empty() {
+                kept.push((outdated.key().clone(), users.join(", ")));
+                continue;
+            }
+
+            match fs_err::tokio::remove_dir_all(outdated.path()).await {
+                Ok(()) => events.push(ChangeEvent {
+                    key: outdated.key().clone(),
+                    kind: ChangeEventKind::Removed,
+                }),
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => {
+                    errors.push((outdated.key(), anyhow::Error::new(err)));
+                }
+            }
+        }
+    }
+
+    let upgraded = events
+        .iter()
+        .filter(|event| event.kind == ChangeEventKind::Added)
+        .count();
+    match upgraded {
+        0 => {}
+        1 => {
+            let event = events
+                .iter()
+                .find(|event| event.kind == ChangeEventKind::Added)
+                .unwrap();
+            // Ex) "Upgraded to Python 3.12.7 in 1.68s"
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Upgraded to {} {}",
+                    format!("Python {}", event.key.version()).bold(),
+                    format!("in {}", elapsed(start.elapsed())).dimmed()
+                )
+                .dimmed()
+            )?;
+        }
+        _ => {
+            // Ex) "Upgraded 2 versions in 1.68s"
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!(
+                    "Upgraded {} {}",
+                    format!("{upgraded} versions").bold(),
+                    format!("in {}", elapsed(start.elapsed())).dimmed()
+                )
+                .dimmed()
+            )?;
+        }
+    }
+
+    for event in events
+        .into_iter()
+        .sorted_unstable_by(|a, b| a.key.cmp(&b.key).then_with(|| a.kind.cmp(&b.kind)))
+    {
+        match event.kind {
+            ChangeEventKind::Added => {
+                writeln!(printer.stderr(), " {} {}", "+".green(), event.key.bold())?;
+            }
+            ChangeEventKind::Removed => {
+                writeln!(printer.stderr(), " {} {}", "-".red(), event.key.bold())?;
+            }
+            ChangeEventKind::Reinstalled => unreachable!(),
+        }
+    }
+
+    for (key, users) in kept {
+        warn_user!(
+            "Keeping `{}`, which is still used by: {}",
+            key.green(),
+            users
+        );
+    }
+
+    if !errors.is_empty() {
+        for (key, err) in errors
+            .into_iter()
+            .sorted_unstable_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b))
+        {
+            writeln!(
+                printer.stderr(),
+                "{}: Failed to upgrade {}",
+                "error".red().bold(),
+                key.green()
+            )?;
+            for err in err.chain() {
+                writeln!(
+                    printer.stderr(),
+                    "  {}: {}",
+                    "Caused by".red().bold(),
+                    err.to_string().trim()
+                )?;
+            }
+        }
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+impl Dependent {
+    /// Whether the environment uses the `outdated` installation, and can't be switched to the
+    /// `latest` installation.
+    fn uses(
+        &self,
+        outdated: &ManagedPythonInstallation,
+        latest: &ManagedPythonInstallation,
+    ) -> bool {
+        // The environment links to the outdated installation.
+        if let Some(home) = &self.home {
+            let home = fs_err::canonicalize(home).unwrap_or_else(|_| home.clone());
+            let path = fs_err::canonicalize(outdated.path())
+                .unwrap_or_else(|_| outdated.path().to_path_buf());
+            if home.starts_with(path) {
+                return true;
+            }
+        }
+        // The tool requests a version that only the outdated installation satisfies.
+        if let Some(request) = &self.request {
+            if outdated.satisfies(request) && !latest.satisfies(request) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Find the tool environments and the virtual environments of the current project and shell.
+fn find_dependents(project_dir: &Path) -> Result<Vec<Dependent>> {
+    let mut dependents = Vec::new();
+
+    let read_home = |root: &Path| match PyVenvConfiguration::parse(root.join("pyvenv.cfg")) {
+        Ok(cfg) => cfg.home().map(Path::to_path_buf),
+        Err(err) => {
+            debug!(
+                "Failed to read `pyvenv.cfg` in {}: {err}",
+                root.user_display()
+            );
+            None
+        }
+    };
+
+    let installed_tools = InstalledTools::from_settings()?;
+    if installed_tools.root().is_dir() {
+        for (name, tool) in installed_tools.tools()? {
+            let tool = match tool {
+                Ok(tool) => tool,
+                Err(err) => {
+                    warn!("Failed to read receipt for tool `{name}`: {err}");
+                    continue;
+                }
+            };
+            dependents.push(Dependent {
+                name: format!("tool `{name}`"),
+                home: read_home(&installed_tools.tool_dir(&name)),
+                request: tool.python().as_deref().map(PythonRequest::parse),
+            });
+        }
+    }
+
+    let mut environments = vec![project_dir.join(".venv")];
+    if let Some(active) = std::env::var_os(EnvVars::VIRTUAL_ENV) {
+        environments.push(PathBuf::from(active));
+    }
+    for root in environments.into_iter().unique() {
+        if !root.join("pyvenv.cfg").is_file() {
+            continue;
+        }
+        dependents.push(Dependent {
+            name: format!("environment `{}`", root.user_display()),
+            home: read_home(&root),
+            request: None,
+        });
+    }
+
+    Ok(dependents)
+}
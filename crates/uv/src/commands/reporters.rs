@@ -493,11 +493,6 @@ pub(crate) struct PublishReporter {
 }
 
 impl PublishReporter {
-    /// Initialize a [`PublishReporter`] for a single upload.
-    pub(crate) fn single(printer: Printer) -> Self {
-        Self::new(printer, 1)
-    }
-
     /// Initialize a [`PublishReporter`] for multiple uploads.
     pub(crate) fn new(printer: Printer, length: u64) -> Self {
         let multi_progress = MultiProgress::with_draw_target(printer.target());
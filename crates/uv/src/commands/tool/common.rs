@@ -14,6 +14,7 @@ use uv_installer::SitePackages;
 use uv_pep508::PackageName;
 use uv_pypi_types::Requirement;
 use uv_python::PythonEnvironment;
+use uv_resolver::{Lock, VERSION};
 use uv_settings::ToolOptions;
 use uv_shell::Shell;
 use uv_tool::{entrypoint_paths, tool_executable_dir, InstalledTools, Tool, ToolEntrypoint};
@@ -44,6 +45,58 @@ pub(super) fn matching_packages(name: &str, site_packages: &SitePackages) -> Vec
         .collect()
 }
 
+/// Read the lockfile for a tool, i.e., the `uv.lock` next to its receipt.
+///
+/// Returns `Ok(None)` if the lockfile does not exist. Lockfiles that are invalid or use an
+/// unsupported version are ignored.
+pub(crate) fn read_tool_lock(
+    installed_tools: &InstalledTools,
+    name: &PackageName,
+) -> anyhow::Result<Option<Lock>> {
+    let path = installed_tools.tool_dir(name).join("uv.lock");
+    let encoded = match fs_err::read_to_string(&path) {
+        Ok(encoded) => encoded,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    match toml::from_str::<Lock>(&encoded) {
+        Ok(lock) if lock.version() == VERSION => Ok(Some(lock)),
+        Ok(lock) => {
+            debug!(
+                "Ignoring lockfile for `{name}` with unsupported version: v{}",
+                lock.version()
+            );
+            Ok(None)
+        }
+        Err(err) => {
+            warn_user!(
+                "Ignoring invalid lockfile for `{}` at `{}`: {err}",
+                name.cyan(),
+                path.user_display()
+            );
+            Ok(None)
+        }
+    }
+}
+
+/// Write the lockfile for a tool next to its receipt.
+///
+/// The lockfile is written atomically, such that a concurrent reader never observes a partially
+/// written lockfile.
+pub(crate) fn write_tool_lock(
+    installed_tools: &InstalledTools,
+    name: &PackageName,
+    lock: &Lock,
+) -> anyhow::Result<()> {
+    let path = installed_tools.tool_dir(name).join("uv.lock");
+    debug!(
+        "Writing lockfile for tool `{name}` to: {}",
+        path.user_display()
+    );
+    uv_fs::write_atomic_sync(path, lock.to_toml()?)?;
+    Ok(())
+}
+
 /// Remove any entrypoints attached to the [`Tool`].
 pub(crate) fn remove_entrypoints(tool: &Tool) {
     for executable in tool
@@ -61,10 +114,12 @@ pub(crate) fn remove_entrypoints(tool: &Tool) {
     }
 }
 
-/// Installs tool executables for a given package and handles any conflicts.
+/// Installs tool executables for a given package, along with those of any packages requested via
+/// `--with-executables-from`, and handles any conflicts.
 pub(crate) fn install_executables(
     environment: &PythonEnvironment,
     name: &PackageName,
+    with_executables_from: &[PackageName],
     installed_tools: &InstalledTools,
     options: ToolOptions,
     force: bool,
@@ -88,23 +143,57 @@ pub(crate) fn install_executables(
         executable_directory.user_display()
     );
 
-    let entry_points = entrypoint_paths(
+    let mut entry_points = entrypoint_paths(
         &site_packages,
         installed_dist.name(),
         installed_dist.version(),
-    )?;
+    )?
+    .into_iter()
+    .map(|(name, source_path)| (name, source_path, None))
+    .collect::<Vec<_>>();
+
+    // Include the entry points of any packages requested via `--with-executables-from`.
+    for package in with_executables_from
+        .iter()
+        .filter(|package| *package != name)
+    {
+        let Some(dist) = site_packages.get_packages(package).first().copied() else {
+            // Clean up the environment we just created.
+            installed_tools.remove_environment(name)?;
+
+            bail!(
+                "`{}` was requested via `--with-executables-from`, but is not installed in the tool environment (use `--with {}` to include it)",
+                package.cyan(),
+                package
+            );
+        };
+
+        let package_entry_points = entrypoint_paths(&site_packages, dist.name(), dist.version())?;
+        if package_entry_points.is_empty() {
+            // Clean up the environment we just created.
+            installed_tools.remove_environment(name)?;
+
+            bail!("No executables are provided by `{}`", package.cyan());
+        }
+
+        entry_points.extend(
+            package_entry_points
+                .into_iter()
+                .map(|(name, source_path)| (name, source_path, Some(package.clone()))),
+        );
+    }
 
     // Determine the entry points targets. Use a sorted collection for deterministic output.
     let target_entry_points = entry_points
         .into_iter()
-        .map(|(name, source_path)| {
+        .map(|(name, source_path, from)| {
             let target_path = executable_directory.join(
                 source_path
                     .file_name()
                     .map(std::borrow::ToOwned::to_owned)
                     .unwrap_or_else(|| OsString::from(name.clone())),
             );
-            (name, source_path, target_path)
+            (name, source_path, target_path, from)
         })
         .collect::<BTreeSet<_>>();
 
@@ -126,13 +215,13 @@ pub(crate) fn install_executables(
     // Check if they exist, before installing
     let mut existing_entry_points = target_entry_points
         .iter()
-        .filter(|(_, _, target_path)| target_path.exists())
+        .filter(|(_, _, target_path, _)| target_path.exists())
         .peekable();
 
     // Ignore any existing entrypoints if the user passed `--force`, or the existing recept was
     // broken.
     if force {
-        for (name, _, target) in existing_entry_points {
+        for (name, _, target, _) in existing_entry_points {
             debug!("Removing existing executable: `{name}`");
             fs_err::remove_file(target)?;
         }
@@ -142,7 +231,7 @@ pub(crate) fn install_executables(
 
         let existing_entry_points = existing_entry_points
             // SAFETY: We know the target has a filename because we just constructed it above
-            .map(|(_, _, target)| target.file_name().unwrap().to_string_lossy())
+            .map(|(_, _, target, _)| target.file_name().unwrap().to_string_lossy())
             .collect::<Vec<_>>();
         let (s, exists) = if existing_entry_points.len() == 1 {
             ("", "exists")
@@ -158,7 +247,7 @@ pub(crate) fn install_executables(
         )
     }
 
-    for (name, source_path, target_path) in &target_entry_points {
+    for (name, source_path, target_path, _) in &target_entry_points {
         debug!("Installing executable: `{name}`");
         #[cfg(unix)]
         replace_symlink(source_path, target_path).context("Failed to install executable")?;
@@ -177,7 +266,7 @@ pub(crate) fn install_executables(
         target_entry_points.len(),
         target_entry_points
             .iter()
-            .map(|(name, _, _)| name.bold())
+            .map(|(name, _, _, _)| name.bold())
             .join(", ")
     )?;
 
@@ -187,7 +276,7 @@ pub(crate) fn install_executables(
         python,
         target_entry_points
             .into_iter()
-            .map(|(name, _, target_path)| ToolEntrypoint::new(name, target_path)),
+            .map(|(name, _, target_path, from)| ToolEntrypoint::new(name, target_path, from)),
         options,
     );
     installed_tools.add_tool_receipt(name, tool)?;
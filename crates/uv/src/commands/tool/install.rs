@@ -1,4 +1,6 @@
+use std::collections::BTreeSet;
 use std::fmt::Write;
+use std::path::Path;
 use std::str::FromStr;
 
 use anyhow::{bail, Result};
@@ -8,26 +10,30 @@ use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::{Concurrency, Upgrade};
-use uv_distribution_types::UnresolvedRequirementSpecification;
+use uv_distribution_types::{NameRequirementSpecification, UnresolvedRequirementSpecification};
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::{Requirement, RequirementSource};
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
+    PythonRequest,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
+use uv_resolver::Lock;
 use uv_settings::{ResolverInstallerOptions, ToolOptions};
 use uv_tool::InstalledTools;
 use uv_warnings::warn_user;
 
-use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
+use crate::commands::pip::loggers::{
+    DefaultInstallLogger, DefaultResolveLogger, SummaryResolveLogger,
+};
 
 use crate::commands::project::{
     resolve_environment, resolve_names, sync_environment, update_environment,
-    EnvironmentSpecification,
+    EnvironmentSpecification, EnvironmentUpdate,
 };
-use crate::commands::tool::common::remove_entrypoints;
+use crate::commands::tool::common::{read_tool_lock, remove_entrypoints, write_tool_lock};
 use crate::commands::tool::Target;
 use crate::commands::{reporters::PythonDownloadReporter, tool::common::install_executables};
 use crate::commands::{ExitStatus, SharedState};
@@ -35,13 +41,18 @@ use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
 
 /// Install a tool.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn install(
     package: String,
     editable: bool,
     from: Option<String>,
     with: &[RequirementsSource],
+    with_executables_from: &[PackageName],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
     python: Option<String>,
     force: bool,
+    locked: bool,
     options: ResolverInstallerOptions,
     settings: ResolverInstallerSettings,
     python_preference: PythonPreference,
@@ -201,6 +212,15 @@ pub(crate) async fn install(
         }
     };
 
+    // Deduplicate the packages to install executables from, omitting the tool package itself.
+    let with_executables_from = with_executables_from
+        .iter()
+        .filter(|package| **package != from.name)
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
     // If the user passed, e.g., `ruff@latest`, we need to mark it as upgradable.
     let settings = if target.is_latest() {
         ResolverInstallerSettings {
@@ -213,8 +233,10 @@ pub(crate) async fn install(
         settings
     };
 
-    // Read the `--with` requirements.
-    let spec = RequirementsSpecification::from_simple_sources(with, &client_builder).await?;
+    // Read the `--with` requirements, along with any constraints and overrides.
+    let spec =
+        RequirementsSpecification::from_sources(with, constraints, overrides, &client_builder)
+            .await?;
 
     // Resolve the `--from` and `--with` requirements.
     let requirements = {
@@ -237,12 +259,54 @@ pub(crate) async fn install(
         requirements
     };
 
-    // Convert to tool options.
-    let options = ToolOptions::from(options);
+    // Resolve the overrides (constraints are always named). Strip the origins, such that the
+    // requirements can be compared against those in an existing receipt.
+    let constraints = spec
+        .constraints
+        .iter()
+        .map(|constraint| Requirement {
+            origin: None,
+            ..constraint.requirement.clone()
+        })
+        .collect::<Vec<_>>();
+    let overrides = resolve_names(
+        spec.overrides.clone(),
+        &interpreter,
+        &settings,
+        &state,
+        connectivity,
+        concurrency,
+        native_tls,
+        &cache,
+        printer,
+    )
+    .await?
+    .into_iter()
+    .map(|requirement| Requirement {
+        origin: None,
+        ..requirement
+    })
+    .collect::<Vec<_>>();
+
+    // Convert to tool options, persisting the constraints and overrides in the receipt.
+    let options = ToolOptions {
+        constraints: Some(constraints).filter(|constraints| !constraints.is_empty()),
+        overrides: Some(overrides).filter(|overrides| !overrides.is_empty()),
+        ..ToolOptions::from(options)
+    };
 
     let installed_tools = InstalledTools::from_settings()?.init()?;
     let _lock = installed_tools.lock().await?;
 
+    // Read the existing lockfile, if any, to reproduce the locked versions.
+    let existing_lock = read_tool_lock(&installed_tools, &from.name)?;
+    if locked && existing_lock.is_none() {
+        bail!(
+            "Unable to find a lockfile for `{}`, but `--locked` was provided. To create a lockfile, run `uv tool install` without `--locked`.",
+            from.name.cyan()
+        );
+    }
+
     // Find the existing receipt, if it exists. If the receipt is present but malformed, we'll
     // remove the environment and continue with the install.
     //
@@ -308,7 +372,30 @@ pub(crate) async fn install(
     {
         if let Some(tool_receipt) = existing_tool_receipt.as_ref() {
             let receipt = tool_receipt.requirements().to_vec();
-            if requirements == receipt {
+            if requirements == receipt
+                && tool_receipt.executables_from() == with_executables_from
+                && tool_receipt.options().constraints == options.constraints
+                && tool_receipt.options().overrides == options.overrides
+            {
+                // Environments installed by older versions of uv may lack a lockfile.
+                if existing_lock.is_none() {
+                    let lock = lock_tool(
+                        &requirements,
+                        &options,
+                        &interpreter,
+                        &installed_tools.tool_dir(&from.name),
+                        &settings,
+                        &state,
+                        connectivity,
+                        concurrency,
+                        native_tls,
+                        &cache,
+                        printer,
+                    )
+                    .await?;
+                    write_tool_lock(&installed_tools, &from.name, &lock)?;
+                }
+
                 if *tool_receipt.options() != options {
                     // ...but the options differ, we need to update the receipt.
                     installed_tools
@@ -334,6 +421,13 @@ pub(crate) async fn install(
             .cloned()
             .map(UnresolvedRequirementSpecification::from)
             .collect(),
+        overrides: options
+            .overrides
+            .iter()
+            .flatten()
+            .cloned()
+            .map(UnresolvedRequirementSpecification::from)
+            .collect(),
         ..spec
     };
 
@@ -341,22 +435,49 @@ pub(crate) async fn install(
     // This lets us confirm the environment is valid before removing an existing install. However,
     // entrypoints always contain an absolute path to the relevant Python interpreter, which would
     // be invalidated by moving the environment.
-    let environment = if let Some(environment) = existing_environment {
-        let environment = update_environment(
+    //
+    // With `--locked`, the environment is always re-created from the lockfile.
+    let (environment, lock) = if let Some(environment) = existing_environment.filter(|_| !locked) {
+        let EnvironmentUpdate {
+            environment, lock, ..
+        } = update_environment(
             environment,
             spec,
             &settings,
             &state,
             Box::new(DefaultResolveLogger),
             Box::new(DefaultInstallLogger),
+            true,
             connectivity,
             concurrency,
             native_tls,
             &cache,
             printer,
         )
-        .await?
-        .into_environment();
+        .await?;
+
+        // If the environment already satisfied the requirements, it wasn't re-resolved. Retain the
+        // existing lockfile or, if there isn't one, resolve the requirements to create it.
+        let lock = match lock {
+            Some(lock) => Some(lock),
+            None if existing_lock.is_none() => Some(
+                lock_tool(
+                    &requirements,
+                    &options,
+                    environment.interpreter(),
+                    &installed_tools.tool_dir(&from.name),
+                    &settings,
+                    &state,
+                    connectivity,
+                    concurrency,
+                    native_tls,
+                    &cache,
+                    printer,
+                )
+                .await?,
+            ),
+            None => None,
+        };
 
         // At this point, we updated the existing environment, so we should remove any of its
         // existing executables.
@@ -364,12 +485,12 @@ pub(crate) async fn install(
             remove_entrypoints(&existing_receipt);
         }
 
-        environment
+        (environment, lock)
     } else {
         // If we're creating a new environment, ensure that we can resolve the requirements prior
         // to removing any existing tools.
         let resolution = resolve_environment(
-            EnvironmentSpecification::from(spec),
+            EnvironmentSpecification::from(spec).with_lock(existing_lock.as_ref()),
             &interpreter,
             settings.as_ref().into(),
             &state,
@@ -382,6 +503,19 @@ pub(crate) async fn install(
         )
         .await?;
 
+        let lock = Lock::from_resolution_graph(&resolution, &installed_tools.tool_dir(&from.name))?;
+
+        // If `--locked` was provided, the resolution must match the existing lockfile.
+        if locked {
+            let existing = existing_lock.as_ref().map(Lock::to_toml).transpose()?;
+            if existing != Some(lock.to_toml()?) {
+                bail!(
+                    "The lockfile for `{}` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv tool install` without `--locked`.",
+                    from.name.cyan()
+                );
+            }
+        }
+
         let environment = installed_tools.create_environment(&from.name, interpreter)?;
 
         // At this point, we removed any existing environment, so we should remove any of its
@@ -391,7 +525,7 @@ pub(crate) async fn install(
         }
 
         // Sync the environment with the resolved requirements.
-        sync_environment(
+        let environment = sync_environment(
             environment,
             &resolution.into(),
             settings.as_ref().into(),
@@ -408,12 +542,20 @@ pub(crate) async fn install(
             // If we failed to sync, remove the newly created environment.
             debug!("Failed to sync environment; removing `{}`", from.name);
             let _ = installed_tools.remove_environment(&from.name);
-        })?
+        })?;
+
+        (environment, Some(lock))
     };
 
+    // Record the resolved versions next to the receipt, to reproduce the environment later on.
+    if let Some(lock) = lock {
+        write_tool_lock(&installed_tools, &from.name, &lock)?;
+    }
+
     install_executables(
         &environment,
         &from.name,
+        &with_executables_from,
         &installed_tools,
         options,
         force || invalid_tool_receipt,
@@ -422,3 +564,50 @@ pub(crate) async fn install(
         printer,
     )
 }
+
+/// Resolve the requirements of a tool, without modifying its environment, to create its lockfile.
+async fn lock_tool(
+    requirements: &[Requirement],
+    options: &ToolOptions,
+    interpreter: &Interpreter,
+    root: &Path,
+    settings: &ResolverInstallerSettings,
+    state: &SharedState,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<Lock> {
+    let spec = RequirementsSpecification {
+        constraints: options
+            .constraints
+            .iter()
+            .flatten()
+            .cloned()
+            .map(NameRequirementSpecification::from)
+            .collect(),
+        overrides: options
+            .overrides
+            .iter()
+            .flatten()
+            .cloned()
+            .map(UnresolvedRequirementSpecification::from)
+            .collect(),
+        ..RequirementsSpecification::from_requirements(requirements.to_vec())
+    };
+    let resolution = resolve_environment(
+        EnvironmentSpecification::from(spec),
+        interpreter,
+        settings.as_ref().into(),
+        state,
+        Box::new(SummaryResolveLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?;
+    Ok(Lock::from_resolution_graph(&resolution, root)?)
+}
@@ -84,17 +84,24 @@ pub(crate) async fn list(
             )?;
         }
 
-        // Output tool entrypoints
+        // Output tool entrypoints, along with the package that provides them, if it differs from
+        // the tool package.
         for entrypoint in tool.entrypoints() {
+            let from = if let Some(from) = &entrypoint.from {
+                format!(" [from: {from}]")
+            } else {
+                String::new()
+            };
+
             if show_paths {
                 writeln!(
                     printer.stdout(),
-                    "- {} ({})",
+                    "- {}{from} ({})",
                     entrypoint.name,
                     entrypoint.install_path.simplified_display().cyan()
                 )?;
             } else {
-                writeln!(printer.stdout(), "- {}", entrypoint.name)?;
+                writeln!(printer.stdout(), "- {}{from}", entrypoint.name)?;
             }
         }
     }
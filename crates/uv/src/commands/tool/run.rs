@@ -10,31 +10,35 @@ use owo_colors::OwoColorize;
 use tokio::process::Command;
 use tracing::{debug, warn};
 
-use uv_cache::{Cache, Refresh};
+use uv_cache::{Cache, EnvironmentLease, Refresh};
 use uv_cache_info::Timestamp;
 use uv_cli::ExternalCommand;
 use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::Concurrency;
-use uv_distribution_types::{Name, UnresolvedRequirementSpecification};
+use uv_configuration::{Concurrency, LowerBound};
+use uv_distribution_types::{Name, Resolution, UnresolvedRequirementSpecification};
+use uv_fs::{Simplified, CWD};
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::{Requirement, RequirementSource};
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_static::EnvVars;
 use uv_tool::{entrypoint_paths, InstalledTools};
 use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceError};
 
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryInstallLogger, SummaryResolveLogger,
 };
 use crate::commands::pip::operations;
-use crate::commands::project::{resolve_names, EnvironmentSpecification, ProjectError};
+use crate::commands::project::lock::LockMode;
+use crate::commands::project::run::read_env_files;
+use crate::commands::project::{self, resolve_names, EnvironmentSpecification, ProjectError};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::tool::Target;
 use crate::commands::{
@@ -63,10 +67,15 @@ impl Display for ToolRunCommand {
 }
 
 /// Run a command.
+#[allow(clippy::fn_params_excessive_bools)]
 pub(crate) async fn run(
     command: Option<ExternalCommand>,
     from: Option<String>,
     with: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+    from_project: bool,
+    env_file: &[PathBuf],
     show_resolution: bool,
     python: Option<String>,
     settings: ResolverInstallerSettings,
@@ -98,6 +107,9 @@ pub(crate) async fn run(
 
     let target = Target::parse(target, from.as_deref());
 
+    // Read any environment files, before doing any work.
+    let env_vars = read_env_files(env_file)?;
+
     // If the user passed, e.g., `ruff@latest`, refresh the cache.
     let cache = if target.is_latest() {
         cache.with_refresh(Refresh::All(Timestamp::now()))
@@ -109,6 +121,9 @@ pub(crate) async fn run(
     let result = get_or_create_environment(
         &target,
         with,
+        constraints,
+        overrides,
+        from_project,
         show_resolution,
         python.as_deref(),
         &settings,
@@ -123,7 +138,7 @@ pub(crate) async fn run(
     )
     .await;
 
-    let (from, environment) = match result {
+    let (from, environment, _lease) = match result {
         Ok(resolution) => resolution,
         Err(ProjectError::Operation(operations::Error::Resolve(
             uv_resolver::ResolveError::NoSolution(err),
@@ -146,6 +161,13 @@ pub(crate) async fn run(
     let mut process = Command::new(executable);
     process.args(args);
 
+    // Set any variables from the environment files that aren't already set.
+    for (key, value) in &env_vars {
+        if std::env::var_os(key).is_none() {
+            process.env(key, value);
+        }
+    }
+
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
         std::iter::once(environment.scripts().to_path_buf()).chain(
@@ -389,10 +411,15 @@ fn warn_executable_not_provided_by_package(
 /// Get or create a [`PythonEnvironment`] in which to run the specified tools.
 ///
 /// If the target tool is already installed in a compatible environment, returns that
-/// [`PythonEnvironment`]. Otherwise, gets or creates a [`CachedEnvironment`].
+/// [`PythonEnvironment`]. Otherwise, gets or creates a [`CachedEnvironment`], returning it along
+/// with the [`EnvironmentLease`] that must be held while the environment is in use.
+#[allow(clippy::fn_params_excessive_bools)]
 async fn get_or_create_environment(
     target: &Target<'_>,
     with: &[RequirementsSource],
+    constraints: &[RequirementsSource],
+    overrides: &[RequirementsSource],
+    from_project: bool,
     show_resolution: bool,
     python: Option<&str>,
     settings: &ResolverInstallerSettings,
@@ -404,7 +431,7 @@ async fn get_or_create_environment(
     native_tls: bool,
     cache: &Cache,
     printer: Printer,
-) -> Result<(Requirement, PythonEnvironment), ProjectError> {
+) -> Result<(Requirement, PythonEnvironment, Option<EnvironmentLease>), ProjectError> {
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls);
@@ -483,12 +510,52 @@ async fn get_or_create_environment(
         .unwrap(),
     };
 
-    // Read the `--with` requirements.
+    // If requested, run the version of the tool that's locked in the current project.
+    if from_project {
+        if let Some(resolution) = read_locked_tool(
+            target,
+            &from,
+            &interpreter,
+            settings,
+            &state,
+            show_resolution,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await? {
+            let environment = CachedEnvironment::from_resolution(
+                resolution,
+                interpreter,
+                settings,
+                &state,
+                if show_resolution {
+                    Box::new(DefaultInstallLogger)
+                } else {
+                    Box::new(SummaryInstallLogger)
+                },
+                connectivity,
+                concurrency,
+                native_tls,
+                cache,
+                printer,
+            )
+            .await?;
+            let (environment, lease) = environment.into_parts();
+
+            return Ok((from, environment, Some(lease)));
+        }
+    }
+
+    // Read the `--with` requirements, along with any constraints and overrides.
     let spec = {
         let client_builder = BaseClientBuilder::new()
             .connectivity(connectivity)
             .native_tls(native_tls);
-        RequirementsSpecification::from_simple_sources(with, &client_builder).await?
+        RequirementsSpecification::from_sources(with, constraints, overrides, &client_builder)
+            .await?
     };
 
     // Resolve the `--from` and `--with` requirements.
@@ -512,8 +579,23 @@ async fn get_or_create_environment(
         requirements
     };
 
-    // Check if the tool is already installed in a compatible environment.
-    if !isolated && !target.is_latest() {
+    // Resolve the overrides.
+    let overrides = resolve_names(
+        spec.overrides.clone(),
+        &interpreter,
+        settings,
+        &state,
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await?;
+
+    // Check if the tool is already installed in a compatible environment. Overrides can't be
+    // validated against an existing environment, so they always require a fresh resolution.
+    if !isolated && !target.is_latest() && overrides.is_empty() {
         let installed_tools = InstalledTools::from_settings()?.init()?;
         let _lock = installed_tools.lock().await?;
 
@@ -534,18 +616,17 @@ async fn get_or_create_environment(
                 .cloned()
                 .map(UnresolvedRequirementSpecification::from)
                 .collect::<Vec<_>>();
-            let constraints = [];
 
             if matches!(
                 site_packages.satisfies(
                     &requirements,
-                    &constraints,
+                    &spec.constraints,
                     &interpreter.resolver_markers()
                 ),
                 Ok(SatisfiesResult::Fresh { .. })
             ) {
                 debug!("Using existing tool `{}`", from.name);
-                return Ok((from, environment));
+                return Ok((from, environment, None));
             }
         }
     }
@@ -556,6 +637,10 @@ async fn get_or_create_environment(
             .into_iter()
             .map(UnresolvedRequirementSpecification::from)
             .collect(),
+        overrides: overrides
+            .into_iter()
+            .map(UnresolvedRequirementSpecification::from)
+            .collect(),
         ..spec
     };
 
@@ -585,5 +670,135 @@ async fn get_or_create_environment(
     )
     .await?;
 
-    Ok((from, environment.into()))
+    let (environment, lease) = environment.into_parts();
+
+    Ok((from, environment, Some(lease)))
+}
+
+/// Read the [`Resolution`] for a tool from the lockfile of the project in the current directory,
+/// as requested via `--from-project`.
+///
+/// Returns `Ok(None)` if the tool isn't locked in the project, or if the locked version doesn't
+/// satisfy the request, in which case the tool should be resolved as usual.
+#[allow(clippy::fn_params_excessive_bools)]
+async fn read_locked_tool(
+    target: &Target<'_>,
+    from: &Requirement,
+    interpreter: &Interpreter,
+    settings: &ResolverInstallerSettings,
+    state: &SharedState,
+    show_resolution: bool,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: &Cache,
+    printer: Printer,
+) -> anyhow::Result<Option<Resolution>> {
+    // Ex) `ruff@latest`
+    if target.is_latest() {
+        warn_user!(
+            "Ignoring `--from-project`, since the latest version of `{}` was requested",
+            from.name.cyan()
+        );
+        return Ok(None);
+    }
+
+    let workspace = match Workspace::discover(&CWD, &DiscoveryOptions::default()).await {
+        Ok(workspace) => workspace,
+        Err(WorkspaceError::MissingPyprojectToml | WorkspaceError::NonWorkspace(_)) => {
+            bail!("`--from-project` was provided, but no project was found in the current directory or any parent directory");
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Validate the lockfile against the project's requirements, as with `--locked`. If it's
+    // outdated, the existing lockfile is used as-is.
+    let lock = match project::lock::do_safe_lock(
+        LockMode::Locked(interpreter),
+        &workspace,
+        settings.as_ref().into(),
+        LowerBound::Allow,
+        state,
+        if show_resolution {
+            Box::new(DefaultResolveLogger)
+        } else {
+            Box::new(SummaryResolveLogger)
+        },
+        connectivity,
+        concurrency,
+        native_tls,
+        cache,
+        printer,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::LockMismatch) => {
+            warn_user!(
+                "The lockfile at `{}` needs to be updated, so the locked version of `{}` may be outdated; run `{}` to update it",
+                workspace.install_path().join("uv.lock").user_display().cyan(),
+                from.name.cyan(),
+                "uv lock".green()
+            );
+            project::lock::read(&workspace)
+                .await?
+                .ok_or(ProjectError::MissingLockfile)?
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Validate that the Python version is supported by the lockfile.
+    if !lock
+        .requires_python()
+        .contains(interpreter.python_version())
+    {
+        return Err(ProjectError::LockedPythonIncompatibility(
+            interpreter.python_version().clone(),
+            lock.requires_python().clone(),
+        )
+        .into());
+    }
+
+    let markers = interpreter.resolver_markers();
+    let Some(package) = lock.find_package(&from.name, &markers)? else {
+        warn_user!(
+            "`{}` is not locked in the project at `{}`; resolving it instead",
+            from.name.cyan(),
+            workspace.install_path().user_display().cyan()
+        );
+        return Ok(None);
+    };
+
+    // If a specific version was requested, it must match the locked version.
+    let satisfied = match &from.source {
+        RequirementSource::Registry { specifier, .. } => specifier.contains(package.version()),
+        _ => false,
+    };
+    if !satisfied {
+        warn_user!(
+            "The project locks `{}` at v{}, which doesn't satisfy the requested `{}`; resolving it instead",
+            from.name.cyan(),
+            package.version(),
+            from.cyan()
+        );
+        return Ok(None);
+    }
+
+    debug!(
+        "Using locked version of `{}` from `{}`: v{}",
+        from.name,
+        workspace.install_path().user_display(),
+        package.version()
+    );
+
+    let resolution = lock.to_package_resolution(
+        package,
+        &from.extras,
+        workspace.install_path(),
+        &markers,
+        interpreter.tags()?,
+        &settings.build_options,
+    )?;
+
+    Ok(Some(resolution))
 }
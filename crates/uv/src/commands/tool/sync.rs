@@ -0,0 +1,120 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::Connectivity;
+use uv_configuration::Concurrency;
+use uv_fs::{Simplified, CWD};
+use uv_python::{PythonDownloads, PythonPreference};
+use uv_requirements::RequirementsSource;
+use uv_settings::ResolverInstallerOptions;
+use uv_tool::{InstalledTools, ToolManifest};
+
+use crate::commands::tool::install::install;
+use crate::commands::tool::uninstall::uninstall;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
+
+/// Install, upgrade, and uninstall tools to match a `uv-tools.toml` manifest.
+pub(crate) async fn sync(
+    manifest: Option<PathBuf>,
+    python: Option<String>,
+    options: ResolverInstallerOptions,
+    settings: ResolverInstallerSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    cache: Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let path = manifest.unwrap_or_else(|| CWD.join("uv-tools.toml"));
+    let manifest = match ToolManifest::from_path(&path) {
+        Ok(manifest) => manifest,
+        Err(uv_tool::Error::Io(err)) if err.kind() == std::io::ErrorKind::NotFound => {
+            bail!("No tool manifest found at `{}`", path.user_display());
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    debug!(
+        "Syncing {} tool(s) from: {}",
+        manifest.tools.len(),
+        path.user_display()
+    );
+
+    // Install the tools declared in the manifest. Tools that are already installed with the same
+    // requirements are left untouched; the receipts of the others are replaced.
+    for (name, tool) in &manifest.tools {
+        let with = tool
+            .with
+            .iter()
+            .cloned()
+            .map(RequirementsSource::from_with_package)
+            .collect::<Vec<_>>();
+        let constraints = tool
+            .constraints
+            .iter()
+            .cloned()
+            .map(RequirementsSource::Package)
+            .collect::<Vec<_>>();
+        let overrides = tool
+            .overrides
+            .iter()
+            .cloned()
+            .map(RequirementsSource::Package)
+            .collect::<Vec<_>>();
+
+        Box::pin(install(
+            name.to_string(),
+            false,
+            tool.from.clone(),
+            &with,
+            &[],
+            &constraints,
+            &overrides,
+            tool.python.clone().or_else(|| python.clone()),
+            false,
+            false,
+            options.clone(),
+            settings.clone(),
+            python_preference,
+            python_downloads,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache.clone(),
+            printer,
+        ))
+        .await
+        .with_context(|| format!("Failed to sync `{}`", name.cyan()))?;
+    }
+
+    // Uninstall any tools that are not declared in the manifest.
+    let installed_tools = InstalledTools::from_settings()?.init()?;
+    let extraneous = {
+        let _lock = installed_tools.lock().await?;
+        installed_tools
+            .tools()?
+            .into_iter()
+            .map(|(name, _)| name)
+            .filter(|name| !manifest.tools.contains_key(name))
+            .collect::<Vec<_>>()
+    };
+
+    if extraneous.is_empty() {
+        if manifest.tools.is_empty() {
+            writeln!(printer.stderr(), "No tools declared in the manifest")?;
+        }
+    } else {
+        uninstall(extraneous, printer).await?;
+    }
+
+    Ok(ExitStatus::Success)
+}
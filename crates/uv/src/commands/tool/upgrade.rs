@@ -7,12 +7,14 @@ use tracing::debug;
 use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::Concurrency;
+use uv_distribution_types::{NameRequirementSpecification, UnresolvedRequirementSpecification};
 use uv_normalize::PackageName;
 use uv_python::{
     EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
     PythonRequest,
 };
 use uv_requirements::RequirementsSpecification;
+use uv_resolver::Lock;
 use uv_settings::{Combine, ResolverInstallerOptions, ToolOptions};
 use uv_tool::InstalledTools;
 
@@ -20,10 +22,11 @@ use crate::commands::pip::loggers::{
     DefaultInstallLogger, SummaryResolveLogger, UpgradeInstallLogger,
 };
 use crate::commands::project::{
-    resolve_environment, sync_environment, update_environment, EnvironmentUpdate,
+    resolve_environment, sync_environment, update_environment, EnvironmentSpecification,
+    EnvironmentUpdate,
 };
 use crate::commands::reporters::PythonDownloadReporter;
-use crate::commands::tool::common::remove_entrypoints;
+use crate::commands::tool::common::{read_tool_lock, remove_entrypoints, write_tool_lock};
 use crate::commands::{tool::common::install_executables, ExitStatus, SharedState};
 use crate::printer::Printer;
 use crate::settings::ResolverInstallerSettings;
@@ -231,6 +234,10 @@ async fn upgrade_tool(
         }
     };
 
+    // Read the existing lockfile, to retain the locked versions of any packages that aren't
+    // upgraded.
+    let existing_lock = read_tool_lock(installed_tools, name)?;
+
     // Resolve the appropriate settings, preferring: CLI > receipt > user.
     let options = args.clone().combine(
         ResolverInstallerOptions::from(existing_tool_receipt.options().clone())
@@ -238,21 +245,39 @@ async fn upgrade_tool(
     );
     let settings = ResolverInstallerSettings::from(options.clone());
 
-    // Resolve the requirements.
+    // Resolve the requirements, respecting the constraints and overrides from the receipt.
     let requirements = existing_tool_receipt.requirements();
-    let spec = RequirementsSpecification::from_requirements(requirements.to_vec());
+    let spec = RequirementsSpecification {
+        constraints: existing_tool_receipt
+            .options()
+            .constraints
+            .iter()
+            .flatten()
+            .cloned()
+            .map(NameRequirementSpecification::from)
+            .collect(),
+        overrides: existing_tool_receipt
+            .options()
+            .overrides
+            .iter()
+            .flatten()
+            .cloned()
+            .map(UnresolvedRequirementSpecification::from)
+            .collect(),
+        ..RequirementsSpecification::from_requirements(requirements.to_vec())
+    };
 
     // Initialize any shared state.
     let state = SharedState::default();
 
     // Check if we need to create a new environment — if so, resolve it first, then
     // install the requested tool
-    let (environment, outcome) = if let Some(interpreter) =
+    let (environment, outcome, lock) = if let Some(interpreter) =
         interpreter.filter(|interpreter| !environment.uses(interpreter))
     {
         // If we're using a new interpreter, re-create the environment for each tool.
         let resolution = resolve_environment(
-            RequirementsSpecification::from_requirements(requirements.to_vec()).into(),
+            EnvironmentSpecification::from(spec).with_lock(existing_lock.as_ref()),
             interpreter,
             settings.as_ref().into(),
             &state,
@@ -265,6 +290,8 @@ async fn upgrade_tool(
         )
         .await?;
 
+        let lock = Lock::from_resolution_graph(&resolution, &installed_tools.tool_dir(name))?;
+
         let environment = installed_tools.create_environment(name, interpreter.clone())?;
 
         let environment = sync_environment(
@@ -281,7 +308,7 @@ async fn upgrade_tool(
         )
         .await?;
 
-        (environment, UpgradeOutcome::UpgradeEnvironment)
+        (environment, UpgradeOutcome::UpgradeEnvironment, Some(lock))
     } else {
         // Otherwise, upgrade the existing environment.
         // TODO(zanieb): Build the environment in the cache directory then copy into the tool
@@ -289,6 +316,7 @@ async fn upgrade_tool(
         let EnvironmentUpdate {
             environment,
             changelog,
+            lock,
         } = update_environment(
             environment,
             spec,
@@ -296,6 +324,7 @@ async fn upgrade_tool(
             &state,
             Box::new(SummaryResolveLogger),
             Box::new(UpgradeInstallLogger::new(name.clone())),
+            true,
             connectivity,
             concurrency,
             native_tls,
@@ -312,9 +341,13 @@ async fn upgrade_tool(
             UpgradeOutcome::UpgradeDependencies
         };
 
-        (environment, outcome)
+        (environment, outcome, lock)
     };
 
+    if let Some(lock) = lock {
+        write_tool_lock(installed_tools, name, &lock)?;
+    }
+
     if matches!(
         outcome,
         UpgradeOutcome::UpgradeEnvironment | UpgradeOutcome::UpgradeTool
@@ -327,8 +360,13 @@ async fn upgrade_tool(
         install_executables(
             &environment,
             name,
+            &existing_tool_receipt.executables_from(),
             installed_tools,
-            ToolOptions::from(options),
+            ToolOptions {
+                constraints: existing_tool_receipt.options().constraints.clone(),
+                overrides: existing_tool_receipt.options().overrides.clone(),
+                ..ToolOptions::from(options)
+            },
             true,
             existing_tool_receipt.python().to_owned(),
             requirements.to_vec(),
@@ -1,7 +1,6 @@
 use std::fmt::Write;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::vec;
 
 use anstream::eprint;
 use anyhow::Result;
@@ -21,21 +20,24 @@ use uv_fs::Simplified;
 use uv_install_wheel::linker::LinkMode;
 use uv_pypi_types::Requirement;
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
-    PythonVariant, PythonVersionFile, VersionRequest,
+    EnvironmentPreference, Interpreter, PythonDownloads, PythonInstallation, PythonPreference,
+    PythonRequest, PythonVariant, PythonVersionFile, VersionRequest,
 };
+use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_resolver::{ExcludeNewer, FlatIndex, RequiresPython};
 use uv_shell::Shell;
 use uv_types::{BuildContext, BuildIsolation, HashStrategy};
 use uv_warnings::warn_user_once;
 use uv_workspace::{DiscoveryOptions, VirtualProject, WorkspaceError};
 
-use crate::commands::pip::loggers::{DefaultInstallLogger, InstallLogger};
+use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
 use crate::commands::pip::operations::Changelog;
-use crate::commands::project::find_requires_python;
+use crate::commands::project::sync::{bundle_managed_python, validate_bundle_python};
+use crate::commands::project::{find_requires_python, update_environment};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{ExitStatus, SharedState};
 use crate::printer::Printer;
+use crate::settings::ResolverInstallerSettings;
 
 /// Create a virtual environment.
 #[allow(clippy::unnecessary_wraps, clippy::fn_params_excessive_bools)]
@@ -55,6 +57,7 @@ pub(crate) async fn venv(
     system_site_packages: bool,
     connectivity: Connectivity,
     seed: bool,
+    requirements: Vec<RequirementsSource>,
     allow_existing: bool,
     exclude_newer: Option<ExcludeNewer>,
     concurrency: Concurrency,
@@ -64,6 +67,7 @@ pub(crate) async fn venv(
     cache: &Cache,
     printer: Printer,
     relocatable: bool,
+    bundle_python: bool,
 ) -> Result<ExitStatus> {
     match venv_impl(
         project_dir,
@@ -79,6 +83,7 @@ pub(crate) async fn venv(
         system_site_packages,
         connectivity,
         seed,
+        requirements,
         python_preference,
         python_downloads,
         allow_existing,
@@ -90,6 +95,7 @@ pub(crate) async fn venv(
         cache,
         printer,
         relocatable,
+        bundle_python,
     )
     .await
     {
@@ -107,10 +113,18 @@ enum VenvError {
     #[diagnostic(code(uv::venv::creation))]
     Creation(#[source] uv_virtualenv::Error),
 
+    #[error("Failed to bundle Python installation")]
+    #[diagnostic(code(uv::venv::bundle))]
+    Bundle(#[source] anyhow::Error),
+
     #[error("Failed to install seed packages")]
     #[diagnostic(code(uv::venv::seed))]
     Seed(#[source] anyhow::Error),
 
+    #[error("Failed to install requirements")]
+    #[diagnostic(code(uv::venv::requirements))]
+    Requirements(#[source] anyhow::Error),
+
     #[error("Failed to extract interpreter tags")]
     #[diagnostic(code(uv::venv::tags))]
     Tags(#[source] uv_platform_tags::TagsError),
@@ -136,6 +150,7 @@ async fn venv_impl(
     system_site_packages: bool,
     connectivity: Connectivity,
     seed: bool,
+    requirements: Vec<RequirementsSource>,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     allow_existing: bool,
@@ -147,7 +162,12 @@ async fn venv_impl(
     cache: &Cache,
     printer: Printer,
     relocatable: bool,
+    bundle_python: bool,
 ) -> miette::Result<ExitStatus> {
+    if bundle_python {
+        validate_bundle_python().map_err(VenvError::Bundle)?;
+    }
+
     let project = if no_project {
         None
     } else {
@@ -267,13 +287,21 @@ async fn venv_impl(
         prompt,
         system_site_packages,
         allow_existing,
-        relocatable,
+        relocatable || bundle_python,
         seed,
     )
     .map_err(VenvError::Creation)?;
 
-    // Install seed packages.
-    if seed {
+    // If requested, bundle the managed Python installation into the environment.
+    let venv = if bundle_python {
+        bundle_managed_python(venv, cache, printer).map_err(VenvError::Bundle)?
+    } else {
+        venv
+    };
+
+    // Install seed packages. If additional requirements were provided, the seed packages are
+    // installed alongside them instead.
+    if seed && requirements.is_empty() {
         // Extract the interpreter.
         let interpreter = venv.interpreter();
 
@@ -285,13 +313,13 @@ async fn venv_impl(
         }
 
         // Instantiate a client.
-        let client = RegistryClientBuilder::try_from(client_builder)
+        let client = RegistryClientBuilder::try_from(client_builder.clone())
             .into_diagnostic()?
             .cache(cache.clone())
             .index_urls(index_locations.index_urls())
             .index_strategy(index_strategy)
             .keyring(keyring_provider)
-            .allow_insecure_host(allow_insecure_host)
+            .allow_insecure_host(allow_insecure_host.clone())
             .markers(interpreter.markers())
             .platform(interpreter.platform())
             .build();
@@ -309,6 +337,7 @@ async fn venv_impl(
                 Some(tags),
                 &HashStrategy::None,
                 &BuildOptions::new(NoBinary::None, NoBuild::All),
+                exclude_newer.as_ref(),
             )
         };
 
@@ -350,18 +379,10 @@ async fn venv_impl(
         );
 
         // Resolve the seed packages.
-        let requirements = if interpreter.python_tuple() < (3, 12) {
-            // Only include `setuptools` and `wheel` on Python <3.12
-            vec![
-                Requirement::from(uv_pep508::Requirement::from_str("pip").unwrap()),
-                Requirement::from(uv_pep508::Requirement::from_str("setuptools").unwrap()),
-                Requirement::from(uv_pep508::Requirement::from_str("wheel").unwrap()),
-            ]
-        } else {
-            vec![Requirement::from(
-                uv_pep508::Requirement::from_str("pip").unwrap(),
-            )]
-        };
+        let requirements = seed_packages(interpreter)
+            .iter()
+            .map(|name| Requirement::from(uv_pep508::Requirement::from_str(name).unwrap()))
+            .collect::<Vec<_>>();
 
         // Resolve and install the requirements.
         //
@@ -382,6 +403,57 @@ async fn venv_impl(
             .into_diagnostic()?;
     }
 
+    // Install any additional requirements, with the same resolver and installer as
+    // `uv pip install`.
+    let venv = if requirements.is_empty() {
+        venv
+    } else {
+        let mut requirements = requirements;
+        if seed {
+            requirements.extend(
+                seed_packages(venv.interpreter())
+                    .iter()
+                    .map(|name| RequirementsSource::Package((*name).to_string())),
+            );
+        }
+
+        let spec = RequirementsSpecification::from_simple_sources(&requirements, &client_builder)
+            .await
+            .map_err(VenvError::Requirements)?;
+
+        let settings = ResolverInstallerSettings {
+            index_locations: index_locations.clone(),
+            index_strategy,
+            keyring_provider,
+            allow_insecure_host,
+            dependency_metadata,
+            exclude_newer,
+            link_mode,
+            ..ResolverInstallerSettings::default()
+        };
+
+        // Initialize any shared state.
+        let state = SharedState::default();
+
+        update_environment(
+            venv,
+            spec,
+            &settings,
+            &state,
+            Box::new(DefaultResolveLogger),
+            Box::new(DefaultInstallLogger),
+            false,
+            connectivity,
+            concurrency,
+            native_tls,
+            cache,
+            printer,
+        )
+        .await
+        .map_err(VenvError::Requirements)?
+        .environment
+    };
+
     // Determine the appropriate activation command.
     let activation = match Shell::from_env() {
         None => None,
@@ -414,6 +486,16 @@ async fn venv_impl(
     Ok(ExitStatus::Success)
 }
 
+/// Return the names of the seed packages for the given interpreter.
+fn seed_packages(interpreter: &Interpreter) -> &'static [&'static str] {
+    // Only include `setuptools` and `wheel` on Python <3.12
+    if interpreter.python_tuple() < (3, 12) {
+        &["pip", "setuptools", "wheel"]
+    } else {
+        &["pip"]
+    }
+}
+
 /// Quote a path, if necessary, for safe use in a POSIX-compatible shell command.
 fn shlex_posix(executable: impl AsRef<Path>) -> String {
     // Convert to a display path.
@@ -16,8 +16,9 @@ use tracing::{debug, instrument};
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_cli::{
-    compat::CompatArgs, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
-    PipCommand, PipNamespace, ProjectCommand,
+    compat::CompatArgs, AuthCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
+    CacheNamespace, Cli, Commands, IndexCommand, IndexNamespace, PipCommand, PipNamespace,
+    ProjectCommand,
 };
 use uv_cli::{PythonCommand, PythonNamespace, ToolCommand, ToolNamespace, TopLevelArgs};
 #[cfg(feature = "self-update")]
@@ -277,7 +278,8 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     show_settings!(cache_settings, false);
 
     // Configure the cache.
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_max_size(cache_settings.max_cache_size);
 
     let result = match *cli.command {
         Commands::Help(args) => commands::help(
@@ -682,7 +684,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             command: CacheCommand::Prune(args),
         }) => {
             show_settings!(args);
-            commands::cache_prune(args.ci, &cache, printer)
+            commands::cache_prune(args.ci, args.environments, args.older_than, &cache, printer)
         }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
@@ -759,6 +761,17 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 }
             });
 
+            let requirements = args
+                .with
+                .into_iter()
+                .map(RequirementsSource::from_with_package)
+                .chain(
+                    args.seed_with
+                        .into_iter()
+                        .map(RequirementsSource::from_requirements_file),
+                )
+                .collect::<Vec<_>>();
+
             commands::venv(
                 &project_dir,
                 args.path,
@@ -775,6 +788,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.system_site_packages,
                 globals.connectivity,
                 args.seed,
+                requirements,
                 args.allow_existing,
                 args.settings.exclude_newer,
                 globals.concurrency,
@@ -784,6 +798,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &cache,
                 printer,
                 args.relocatable,
+                args.bundle_python,
             )
             .await
         }
@@ -884,11 +899,25 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                         .map(RequirementsSource::from_requirements_file),
                 )
                 .collect::<Vec<_>>();
+            let constraints = args
+                .constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let overrides = args
+                .overrides
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Vec<_>>();
 
-            commands::tool_run(
+            Box::pin(commands::tool_run(
                 args.command,
                 args.from,
                 &requirements,
+                &constraints,
+                &overrides,
+                args.from_project,
+                &args.env_file,
                 args.show_resolution || globals.verbose > 0,
                 args.python,
                 args.settings,
@@ -901,7 +930,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.native_tls,
                 cache,
                 printer,
-            )
+            ))
             .await
         }
         Commands::Tool(ToolNamespace {
@@ -933,14 +962,28 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                         .map(RequirementsSource::from_requirements_file),
                 )
                 .collect::<Vec<_>>();
+            let constraints = args
+                .constraints
+                .into_iter()
+                .map(RequirementsSource::from_constraints_txt)
+                .collect::<Vec<_>>();
+            let overrides = args
+                .overrides
+                .into_iter()
+                .map(RequirementsSource::from_overrides_txt)
+                .collect::<Vec<_>>();
 
             Box::pin(commands::tool_install(
                 args.package,
                 args.editable,
                 args.from,
                 &requirements,
+                &args.with_executables_from,
+                &constraints,
+                &overrides,
                 args.python,
                 args.force,
+                args.locked,
                 args.options,
                 args.settings,
                 globals.python_preference,
@@ -996,6 +1039,35 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             ))
             .await
         }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Sync(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolSyncSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            Box::pin(commands::tool_sync(
+                args.manifest,
+                args.python,
+                args.options,
+                args.settings,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                cache,
+                printer,
+            ))
+            .await
+        }
         Commands::Tool(ToolNamespace {
             command: ToolCommand::Uninstall(args),
         }) => {
@@ -1035,6 +1107,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.kinds,
                 args.all_versions,
                 args.all_platforms,
+                args.output_format,
                 globals.python_preference,
                 globals.python_downloads,
                 &cache,
@@ -1062,6 +1135,25 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Python(PythonNamespace {
+            command: PythonCommand::Upgrade(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::PythonUpgradeSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::python_upgrade(
+                &project_dir,
+                args.targets,
+                args.keep,
+                globals.python_downloads,
+                globals.native_tls,
+                globals.connectivity,
+                globals.preview,
+                printer,
+            )
+            .await
+        }
         Commands::Python(PythonNamespace {
             command: PythonCommand::Uninstall(args),
         }) => {
@@ -1133,7 +1225,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 files,
                 username,
                 password,
+                attestations,
+                concurrent_uploads,
+                dry_run,
+                format,
+                layout,
+                index,
                 publish_url,
+                publish_index,
                 trusted_publishing,
                 keyring_provider,
                 allow_insecure_host,
@@ -1143,12 +1242,19 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::publish(
                 files,
                 publish_url,
+                index,
+                publish_index,
                 trusted_publishing,
                 keyring_provider,
                 allow_insecure_host,
                 username,
                 password,
                 check_url,
+                attestations.as_deref(),
+                concurrent_uploads,
+                dry_run,
+                format,
+                layout,
                 &cache,
                 globals.connectivity,
                 globals.native_tls,
@@ -1156,6 +1262,76 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             )
             .await
         }
+        Commands::Index(IndexNamespace {
+            command: IndexCommand::Mirror(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::IndexMirrorSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::index_mirror(
+                &project_dir,
+                &args.output_dir,
+                args.format,
+                args.require_hashes,
+                args.keyring_provider,
+                args.allow_insecure_host,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                printer,
+            )
+            .await
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Login(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthLoginSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_login(
+                &args.service,
+                args.username,
+                args.password,
+                args.keyring_provider,
+                &args.indexes,
+                printer,
+            )
+            .await
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Logout(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthLogoutSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_logout(
+                &args.service,
+                args.username.as_deref(),
+                args.keyring_provider,
+                &args.indexes,
+                printer,
+            )
+            .await
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Token(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthTokenSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_token(
+                &args.service,
+                args.username.as_deref(),
+                args.keyring_provider,
+                &args.indexes,
+                printer,
+            )
+            .await
+        }
         Commands::BuildBackend { command } => spawn_blocking(move || match command {
             BuildBackendCommand::BuildSdist { sdist_directory } => {
                 commands::build_backend::build_sdist(&sdist_directory)
@@ -1288,6 +1464,7 @@ async fn run_project(
                 script,
                 command,
                 requirements,
+                args.env_file,
                 args.show_resolution || globals.verbose > 0,
                 args.locked,
                 args.frozen,
@@ -1334,6 +1511,7 @@ async fn run_project(
                 args.install_options,
                 args.modifications,
                 args.python,
+                args.bundle_python,
                 globals.python_preference,
                 globals.python_downloads,
                 args.settings,
@@ -1345,6 +1523,39 @@ async fn run_project(
             )
             .await
         }
+        ProjectCommand::Shell(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ShellSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            Box::pin(commands::shell(
+                project_dir,
+                args.locked,
+                args.frozen,
+                args.no_sync,
+                args.package,
+                args.extras,
+                args.dev,
+                args.editable,
+                args.python,
+                globals.python_preference,
+                globals.python_downloads,
+                args.settings,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                &cache,
+                printer,
+            ))
+            .await
+        }
         ProjectCommand::Lock(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::LockSettings::resolve(args, filesystem);
@@ -1361,6 +1572,8 @@ async fn run_project(
                 args.locked,
                 args.frozen,
                 args.dry_run,
+                args.profile_resolution.as_deref(),
+                args.profile_format,
                 args.python,
                 args.settings,
                 globals.python_preference,
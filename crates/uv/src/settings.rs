@@ -5,17 +5,19 @@ use std::process;
 use std::str::FromStr;
 
 use url::Url;
-use uv_cache::{CacheArgs, Refresh};
+use uv_cache::{CacheArgs, CacheSize, Refresh};
 use uv_cli::{
     options::{flag, resolver_installer_options, resolver_options},
-    AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, ToolUpgradeArgs,
+    AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, AuthorFrom, BuildArgs, ExportArgs,
+    IndexMirrorArgs, MirrorFormat, PublishArgs, PublishFormat, PythonDirArgs, ToolUpgradeArgs,
 };
 use uv_cli::{
     AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
     PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs,
-    PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs,
-    PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs, ToolDirArgs,
-    ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
+    PipSyncArgs, PipTreeArgs, PipUninstallArgs, ProfileFormat, PythonFindArgs, PythonInstallArgs,
+    PythonListArgs, PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs,
+    RemoveArgs, RunArgs, ShellArgs, SyncArgs, ToolDirArgs, ToolInstallArgs, ToolListArgs,
+    ToolRunArgs, ToolSyncArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
 };
 use uv_client::Connectivity;
 use uv_configuration::{
@@ -138,6 +140,7 @@ impl GlobalSettings {
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) max_cache_size: Option<CacheSize>,
 }
 
 impl CacheSettings {
@@ -151,6 +154,9 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            max_cache_size: args
+                .max_cache_size
+                .or_else(|| workspace.and_then(|workspace| workspace.globals.max_cache_size)),
         }
     }
 }
@@ -233,6 +239,7 @@ pub(crate) struct RunSettings {
     pub(crate) with: Vec<String>,
     pub(crate) with_editable: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
+    pub(crate) env_file: Vec<PathBuf>,
     pub(crate) isolated: bool,
     pub(crate) show_resolution: bool,
     pub(crate) package: Option<PackageName>,
@@ -264,6 +271,7 @@ impl RunSettings {
             with,
             with_editable,
             with_requirements,
+            env_file,
             isolated,
             no_sync,
             locked,
@@ -294,6 +302,7 @@ impl RunSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            env_file,
             isolated,
             show_resolution,
             package,
@@ -318,6 +327,10 @@ pub(crate) struct ToolRunSettings {
     pub(crate) with: Vec<String>,
     pub(crate) with_editable: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
+    pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) overrides: Vec<PathBuf>,
+    pub(crate) from_project: bool,
+    pub(crate) env_file: Vec<PathBuf>,
     pub(crate) isolated: bool,
     pub(crate) show_resolution: bool,
     pub(crate) python: Option<String>,
@@ -339,6 +352,10 @@ impl ToolRunSettings {
             with,
             with_editable,
             with_requirements,
+            constraints,
+            overrides,
+            from_project,
+            env_file,
             isolated,
             show_resolution,
             installer,
@@ -375,6 +392,16 @@ impl ToolRunSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            constraints: constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            overrides: overrides
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            from_project,
+            env_file,
             isolated,
             show_resolution,
             python: python.and_then(Maybe::into_option),
@@ -396,11 +423,15 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) with: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
     pub(crate) with_editable: Vec<String>,
+    pub(crate) with_executables_from: Vec<PackageName>,
+    pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) overrides: Vec<PathBuf>,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) options: ResolverInstallerOptions,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
+    pub(crate) locked: bool,
     pub(crate) editable: bool,
 }
 
@@ -414,9 +445,13 @@ impl ToolInstallSettings {
             from,
             with,
             with_editable,
+            with_executables_from,
             with_requirements,
+            constraints,
+            overrides,
             installer,
             force,
+            locked,
             build,
             refresh,
             python,
@@ -440,8 +475,18 @@ impl ToolInstallSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            with_executables_from,
+            constraints: constraints
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
+            overrides: overrides
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
             python: python.and_then(Maybe::into_option),
             force,
+            locked,
             editable,
             refresh: Refresh::from(refresh),
             options,
@@ -450,6 +495,48 @@ impl ToolInstallSettings {
     }
 }
 
+/// The resolved settings to use for a `tool sync` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ToolSyncSettings {
+    pub(crate) manifest: Option<PathBuf>,
+    pub(crate) python: Option<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) options: ResolverInstallerOptions,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl ToolSyncSettings {
+    /// Resolve the [`ToolSyncSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolSyncArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolSyncArgs {
+            manifest,
+            installer,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        let options = resolver_installer_options(installer, build).combine(
+            filesystem
+                .map(FilesystemOptions::into_options)
+                .map(|options| options.top_level)
+                .unwrap_or_default(),
+        );
+
+        let settings = ResolverInstallerSettings::from(options.clone());
+
+        Self {
+            manifest,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            options,
+            settings,
+        }
+    }
+}
+
 /// The resolved settings to use for a `tool upgrade` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -571,6 +658,7 @@ pub(crate) struct PythonListSettings {
     pub(crate) kinds: PythonListKinds,
     pub(crate) all_platforms: bool,
     pub(crate) all_versions: bool,
+    pub(crate) output_format: PythonListFormat,
 }
 
 impl PythonListSettings {
@@ -581,6 +669,7 @@ impl PythonListSettings {
             all_versions,
             all_platforms,
             only_installed,
+            output_format,
         } = args;
 
         let kinds = if only_installed {
@@ -593,6 +682,7 @@ impl PythonListSettings {
             kinds,
             all_platforms,
             all_versions,
+            output_format,
         }
     }
 }
@@ -632,6 +722,23 @@ impl PythonInstallSettings {
     }
 }
 
+/// The resolved settings to use for a `python upgrade` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct PythonUpgradeSettings {
+    pub(crate) targets: Vec<String>,
+    pub(crate) keep: bool,
+}
+
+impl PythonUpgradeSettings {
+    /// Resolve the [`PythonUpgradeSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: PythonUpgradeArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let PythonUpgradeArgs { targets, keep } = args;
+
+        Self { targets, keep }
+    }
+}
+
 /// The resolved settings to use for a `python uninstall` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -722,6 +829,7 @@ pub(crate) struct SyncSettings {
     pub(crate) modifications: Modifications,
     pub(crate) package: Option<PackageName>,
     pub(crate) python: Option<String>,
+    pub(crate) bundle_python: bool,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverInstallerSettings,
 }
@@ -753,6 +861,7 @@ impl SyncSettings {
             refresh,
             package,
             python,
+            bundle_python,
         } = args;
 
         let settings = ResolverInstallerSettings::combine(
@@ -783,12 +892,77 @@ impl SyncSettings {
             },
             package,
             python: python.and_then(Maybe::into_option),
+            bundle_python,
             refresh: Refresh::from(refresh),
             settings,
         }
     }
 }
 
+/// The resolved settings to use for a `shell` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ShellSettings {
+    pub(crate) locked: bool,
+    pub(crate) frozen: bool,
+    pub(crate) no_sync: bool,
+    pub(crate) extras: ExtrasSpecification,
+    pub(crate) dev: DevGroupsSpecification,
+    pub(crate) editable: EditableMode,
+    pub(crate) package: Option<PackageName>,
+    pub(crate) python: Option<String>,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl ShellSettings {
+    /// Resolve the [`ShellSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ShellArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ShellArgs {
+            extra,
+            all_extras,
+            no_all_extras,
+            dev,
+            no_dev,
+            only_dev,
+            group,
+            no_group,
+            only_group,
+            no_editable,
+            no_sync,
+            locked,
+            frozen,
+            installer,
+            build,
+            refresh,
+            package,
+            python,
+        } = args;
+
+        Self {
+            locked,
+            frozen,
+            no_sync,
+            extras: ExtrasSpecification::from_args(
+                flag(all_extras, no_all_extras).unwrap_or_default(),
+                extra.unwrap_or_default(),
+            ),
+            dev: DevGroupsSpecification::from_args(
+                dev, no_dev, only_dev, group, no_group, only_group,
+            ),
+            editable: EditableMode::from_args(no_editable),
+            package,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+        }
+    }
+}
+
 /// The resolved settings to use for a `lock` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
@@ -796,6 +970,8 @@ pub(crate) struct LockSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
     pub(crate) dry_run: bool,
+    pub(crate) profile_resolution: Option<PathBuf>,
+    pub(crate) profile_format: ProfileFormat,
     pub(crate) python: Option<String>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: ResolverSettings,
@@ -809,6 +985,8 @@ impl LockSettings {
             locked,
             frozen,
             dry_run,
+            profile_resolution,
+            profile_format,
             resolver,
             build,
             refresh,
@@ -819,6 +997,8 @@ impl LockSettings {
             locked,
             frozen,
             dry_run,
+            profile_resolution,
+            profile_format,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
@@ -1860,11 +2040,14 @@ impl BuildSettings {
 #[derive(Debug, Clone)]
 pub(crate) struct VenvSettings {
     pub(crate) seed: bool,
+    pub(crate) with: Vec<String>,
+    pub(crate) seed_with: Vec<PathBuf>,
     pub(crate) allow_existing: bool,
     pub(crate) path: Option<PathBuf>,
     pub(crate) prompt: Option<String>,
     pub(crate) system_site_packages: bool,
     pub(crate) relocatable: bool,
+    pub(crate) bundle_python: bool,
     pub(crate) no_project: bool,
     pub(crate) settings: PipSettings,
 }
@@ -1877,11 +2060,14 @@ impl VenvSettings {
             system,
             no_system,
             seed,
+            with,
+            seed_with,
             allow_existing,
             path,
             prompt,
             system_site_packages,
             relocatable,
+            bundle_python,
             index_args,
             index_strategy,
             keyring_provider,
@@ -1894,12 +2080,18 @@ impl VenvSettings {
 
         Self {
             seed,
+            with,
+            seed_with: seed_with
+                .into_iter()
+                .filter_map(Maybe::into_option)
+                .collect(),
             allow_existing,
             path,
             prompt,
             system_site_packages,
             no_project,
             relocatable,
+            bundle_python,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -2608,9 +2800,17 @@ pub(crate) struct PublishSettings {
     pub(crate) files: Vec<String>,
     pub(crate) username: Option<String>,
     pub(crate) password: Option<String>,
+    pub(crate) attestations: Option<PathBuf>,
+    pub(crate) concurrent_uploads: NonZeroUsize,
+    pub(crate) dry_run: bool,
+    pub(crate) format: PublishFormat,
+    pub(crate) layout: MirrorFormat,
+
+    pub(crate) index: Option<String>,
 
     // Both CLI and configuration.
     pub(crate) publish_url: Url,
+    pub(crate) publish_index: Option<Index>,
     pub(crate) trusted_publishing: TrustedPublishing,
     pub(crate) keyring_provider: KeyringProviderType,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
@@ -2633,9 +2833,18 @@ impl PublishSettings {
         let ResolverInstallerOptions {
             keyring_provider,
             allow_insecure_host,
+            index,
             ..
         } = top_level;
 
+        // Look up the index to publish to, if one was requested by name.
+        let publish_index = args.index.as_deref().and_then(|name| {
+            index
+                .into_iter()
+                .flatten()
+                .find(|index| index.name.as_deref() == Some(name))
+        });
+
         // Tokens are encoded in the same way as username/password
         let (username, password) = if let Some(token) = args.token {
             (Some("__token__".to_string()), Some(token))
@@ -2647,10 +2856,24 @@ impl PublishSettings {
             files: args.files,
             username,
             password,
+            attestations: args.attestations,
+            concurrent_uploads: args
+                .concurrent_uploads
+                .unwrap_or_else(|| NonZeroUsize::new(1).unwrap()),
+            dry_run: args.dry_run,
+            format: args.format,
+            layout: args.layout,
+            index: args.index,
             publish_url: args
                 .publish_url
+                .or_else(|| {
+                    publish_index
+                        .as_ref()
+                        .and_then(|index| index.publish_url.clone())
+                })
                 .combine(publish_url)
                 .unwrap_or_else(|| Url::parse(PYPI_PUBLISH_URL).unwrap()),
+            publish_index,
             trusted_publishing: trusted_publishing
                 .combine(args.trusted_publishing)
                 .unwrap_or_default(),
@@ -2673,6 +2896,162 @@ impl PublishSettings {
     }
 }
 
+/// The resolved settings to use for an `index mirror` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct IndexMirrorSettings {
+    pub(crate) output_dir: PathBuf,
+    pub(crate) format: MirrorFormat,
+    pub(crate) require_hashes: bool,
+    pub(crate) keyring_provider: KeyringProviderType,
+    pub(crate) allow_insecure_host: Vec<TrustedHost>,
+}
+
+impl IndexMirrorSettings {
+    /// Resolve the [`IndexMirrorSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: IndexMirrorArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options { top_level, .. } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        let ResolverInstallerOptions {
+            keyring_provider,
+            allow_insecure_host,
+            ..
+        } = top_level;
+
+        Self {
+            output_dir: args.output_dir,
+            format: args.format,
+            require_hashes: args.require_hashes,
+            keyring_provider: args
+                .keyring_provider
+                .combine(keyring_provider)
+                .unwrap_or_default(),
+            allow_insecure_host: args
+                .allow_insecure_host
+                .map(|allow_insecure_host| {
+                    allow_insecure_host
+                        .into_iter()
+                        .filter_map(Maybe::into_option)
+                        .collect()
+                })
+                .combine(allow_insecure_host)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// The resolved settings to use for an `auth login` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthLoginSettings {
+    pub(crate) service: String,
+    pub(crate) username: Option<String>,
+    pub(crate) password: Option<String>,
+    pub(crate) keyring_provider: KeyringProviderType,
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl AuthLoginSettings {
+    /// Resolve the [`AuthLoginSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthLoginArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options { top_level, .. } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        let ResolverInstallerOptions {
+            keyring_provider,
+            index,
+            ..
+        } = top_level;
+
+        // Tokens are encoded in the same way as username/password
+        let (username, password) = if let Some(token) = args.token {
+            (Some("__token__".to_string()), Some(token))
+        } else {
+            (args.username, args.password)
+        };
+
+        Self {
+            service: args.service,
+            username,
+            password,
+            keyring_provider: args
+                .keyring_provider
+                .combine(keyring_provider)
+                .unwrap_or_default(),
+            indexes: index.unwrap_or_default(),
+        }
+    }
+}
+
+/// The resolved settings to use for an `auth logout` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthLogoutSettings {
+    pub(crate) service: String,
+    pub(crate) username: Option<String>,
+    pub(crate) keyring_provider: KeyringProviderType,
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl AuthLogoutSettings {
+    /// Resolve the [`AuthLogoutSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthLogoutArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options { top_level, .. } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        let ResolverInstallerOptions {
+            keyring_provider,
+            index,
+            ..
+        } = top_level;
+
+        Self {
+            service: args.service,
+            username: args.username,
+            keyring_provider: args
+                .keyring_provider
+                .combine(keyring_provider)
+                .unwrap_or_default(),
+            indexes: index.unwrap_or_default(),
+        }
+    }
+}
+
+/// The resolved settings to use for an `auth token` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthTokenSettings {
+    pub(crate) service: String,
+    pub(crate) username: Option<String>,
+    pub(crate) keyring_provider: KeyringProviderType,
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl AuthTokenSettings {
+    /// Resolve the [`AuthTokenSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthTokenArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options { top_level, .. } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        let ResolverInstallerOptions {
+            keyring_provider,
+            index,
+            ..
+        } = top_level;
+
+        Self {
+            service: args.service,
+            username: args.username,
+            keyring_provider: args
+                .keyring_provider
+                .combine(keyring_provider)
+                .unwrap_or_default(),
+            indexes: index.unwrap_or_default(),
+        }
+    }
+}
+
 // Environment variables that are not exposed as CLI arguments.
 mod env {
     use uv_static::EnvVars;
@@ -0,0 +1,212 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Store a token for a URL, read it back, and remove it.
+#[test]
+fn login_token_logout() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("login")
+        .arg("https://pypi.example.com/simple")
+        .arg("--token")
+        .arg("secret"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for https://pypi.example.com/simple in [TEMP_DIR]/credentials/credentials.toml
+    "###);
+
+    // The token applies to any URL below the service.
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("https://pypi.example.com/simple/iniconfig/"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    secret
+
+    ----- stderr -----
+    "###);
+
+    // But not to other hosts.
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("https://pypi.example.org/simple"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No credentials found for https://pypi.example.org/simple
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("logout")
+        .arg("https://pypi.example.com/simple"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed credentials for https://pypi.example.com/simple
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("https://pypi.example.com/simple"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No credentials found for https://pypi.example.com/simple
+    "###);
+}
+
+/// Refer to a service by the name of a configured index.
+#[test]
+fn login_index_name() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://pypi.example.com/simple/"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("login")
+        .arg("internal")
+        .arg("--username")
+        .arg("user")
+        .arg("--password")
+        .arg("password"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for https://pypi.example.com/simple/ in [TEMP_DIR]/credentials/credentials.toml
+    "###);
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("https://pypi.example.com/simple/")
+        .arg("--username")
+        .arg("user"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    password
+
+    ----- stderr -----
+    "###);
+
+    // Credentials from the environment take precedence.
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("internal")
+        .env("UV_INDEX_INTERNAL_USERNAME", "user")
+        .env("UV_INDEX_INTERNAL_PASSWORD", "from-env"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    from-env
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// `uv auth token` respects the `authenticate` and `credential-provider` settings of an index.
+#[test]
+fn token_index_policy() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let netrc = context.temp_dir.child(".netrc");
+    netrc.write_str("machine pypi.example.com login user password from-netrc")?;
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [[tool.uv.index]]
+        name = "netrc"
+        url = "https://pypi.example.com/netrc/"
+        credential-provider = "netrc"
+
+        [[tool.uv.index]]
+        name = "keyring"
+        url = "https://pypi.example.com/keyring/"
+        credential-provider = "keyring"
+
+        [[tool.uv.index]]
+        name = "never"
+        url = "https://pypi.example.com/never/"
+        authenticate = "never"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("netrc")
+        .env(EnvVars::NETRC, netrc.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    from-netrc
+
+    ----- stderr -----
+    "###);
+
+    // The netrc file is not consulted for an index that only uses the keyring.
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("keyring")
+        .env(EnvVars::NETRC, netrc.path()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No credentials found for https://pypi.example.com/keyring/
+    "###);
+
+    // No credentials are used for an index that disables authentication, even if provided via
+    // the environment.
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("never")
+        .env(EnvVars::NETRC, netrc.path())
+        .env("UV_INDEX_NEVER_USERNAME", "user")
+        .env("UV_INDEX_NEVER_PASSWORD", "from-env"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Authentication is disabled for https://pypi.example.com/never/ (`authenticate = "never"`)
+    "###);
+
+    Ok(())
+}
@@ -1,3 +1,6 @@
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
 use anyhow::Result;
 use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
@@ -118,12 +121,150 @@ fn prune_cached_env() {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     Pruning cache at: [CACHE_DIR]/
+    DEBUG Acquired lock for `environments`
+    DEBUG Acquired lock for `[CACHE_DIR]/environments-v1/[ENTRY]
+    DEBUG Released lock at `[CACHE_DIR]/environments-v1/[ENTRY]
     DEBUG Removing dangling cache environment: [CACHE_DIR]/environments-v1/[ENTRY]
+    DEBUG Released lock at `[CACHE_DIR]/environments-v1/[ENTRY]
     DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
     Removed [N] files ([SIZE])
     "###);
 }
 
+/// `cache prune --environments --older-than` should only remove cached environments that haven't
+/// been used within the given duration.
+#[test]
+fn prune_environments_older_than() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Create a cached environment.
+    context
+        .tool_run()
+        .arg("pytest@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // The environment was just used, so it should be retained.
+    uv_snapshot!(context.filters(), context.prune().arg("--environments").arg("--older-than").arg("7d"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    No unused entries found
+    "###);
+
+    // Mark the environment as last used 30 days ago.
+    let leases = cached_environment_leases(&context)?;
+    assert_eq!(leases.len(), 1);
+    let last_used = SystemTime::now() - Duration::from_secs(30 * 24 * 60 * 60);
+    filetime::set_file_mtime(&leases[0], filetime::FileTime::from_system_time(last_used))?;
+
+    uv_snapshot!(context.filters(), context.prune().arg("--environments").arg("--older-than").arg("7d"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Pruning cache at: [CACHE_DIR]/
+    Removed [N] files ([SIZE])
+    "###);
+
+    assert!(cached_environment_leases(&context)?.is_empty());
+
+    // The environment should be recreated from the cached wheels.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("pytest@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.0
+    "###);
+
+    Ok(())
+}
+
+/// Once the cached environments exceed `--max-cache-size`, the least-recently-used environments
+/// should be evicted.
+#[test]
+fn max_cache_size_evicts_environments() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    context
+        .tool_run()
+        .arg("pytest@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+    assert_eq!(cached_environment_leases(&context)?.len(), 1);
+
+    // With a tiny limit, creating a second environment evicts the first, but retains the
+    // environment that's in use.
+    context
+        .tool_run()
+        .arg("black@24.3.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::UV_MAX_CACHE_SIZE, "1KiB")
+        .assert()
+        .success();
+    assert_eq!(cached_environment_leases(&context)?.len(), 1);
+
+    // An invalid size should be rejected.
+    uv_snapshot!(context.filters(), context.prune().arg("--max-cache-size").arg("10XB"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value '10XB' for '--max-cache-size <SIZE>': Unknown cache size unit `XB` in `10XB` (expected one of: `B`, `K`, `KB`, `KiB`, `M`, `MB`, `MiB`, `G`, `GB`, `GiB`, `T`, `TB`, `TiB`)
+
+    For more information, try '--help'.
+    "###);
+
+    Ok(())
+}
+
+/// Return the lease files for the cached environments.
+fn cached_environment_leases(context: &TestContext) -> Result<Vec<PathBuf>> {
+    let mut leases = Vec::new();
+    for shard in fs_err::read_dir(context.cache_dir.join("environments-v1"))? {
+        let shard = shard?;
+        if !shard.file_type()?.is_dir() {
+            continue;
+        }
+        for entry in fs_err::read_dir(shard.path())? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "lock") {
+                leases.push(path);
+            }
+        }
+    }
+    Ok(leases)
+}
+
 /// `cache prune` should remove any stale symlink from the cache.
 #[test]
 fn prune_stale_symlink() -> Result<()> {
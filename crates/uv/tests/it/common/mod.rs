@@ -599,6 +599,21 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv shell` command with options shared across scenarios.
+    pub fn shell(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("shell");
+        self.add_shared_args(&mut command, false);
+        command
+            .env_remove(EnvVars::NU_VERSION)
+            .env_remove(EnvVars::FISH_VERSION)
+            .env_remove(EnvVars::BASH_VERSION)
+            .env_remove(EnvVars::ZSH_VERSION)
+            .env_remove(EnvVars::KSH_VERSION)
+            .env_remove(EnvVars::SHELL);
+        command
+    }
+
     /// Create a `uv lock` command with options shared across scenarios.
     pub fn lock(&self) -> Command {
         let mut command = self.new_command();
@@ -615,6 +630,25 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv index mirror` command with options shared across scenarios.
+    pub fn index_mirror(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("index").arg("mirror");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv auth` command, storing credentials in the test directory.
+    pub fn auth(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("auth").env(
+            EnvVars::UV_CREDENTIALS_DIR,
+            self.temp_dir.join("credentials"),
+        );
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv build` command with options shared across scenarios.
     pub fn build(&self) -> Command {
         let mut command = self.new_command();
@@ -650,6 +684,18 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python list` command with options shared across scenarios.
+    pub fn python_list(&self) -> Command {
+        let mut command = self.new_command();
+        command
+            .arg("python")
+            .arg("list")
+            .env(EnvVars::UV_PYTHON_INSTALL_DIR, "")
+            .current_dir(&self.temp_dir);
+        self.add_shared_args(&mut command, true);
+        command
+    }
+
     /// Create a `uv python install` command with options shared across scenarios.
     pub fn python_install(&self) -> Command {
         let mut command = self.new_command();
@@ -673,6 +719,21 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv python upgrade` command with options shared across scenarios.
+    pub fn python_upgrade(&self) -> Command {
+        let mut command = self.new_command();
+        let managed = self.temp_dir.join("managed");
+        let bin = self.temp_dir.join("bin");
+        self.add_shared_args(&mut command, true);
+        command
+            .arg("python")
+            .arg("upgrade")
+            .env(EnvVars::UV_PYTHON_INSTALL_DIR, managed)
+            .env(EnvVars::UV_PYTHON_BIN_DIR, bin)
+            .current_dir(&self.temp_dir);
+        command
+    }
+
     /// Create a `uv python uninstall` command with options shared across scenarios.
     pub fn python_uninstall(&self) -> Command {
         let mut command = self.new_command();
@@ -740,6 +801,15 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tool sync` command with options shared across scenarios.
+    pub fn tool_sync(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("tool").arg("sync");
+        self.add_shared_args(&mut command, false);
+        command.env(EnvVars::UV_EXCLUDE_NEWER, EXCLUDE_NEWER);
+        command
+    }
+
     /// Create a `uv tool list` command with options shared across scenarios.
     pub fn tool_list(&self) -> Command {
         let mut command = self.new_command();
@@ -19,6 +19,7 @@ fn help() {
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
       sync                       Update the project's environment
+      shell                      Launch a shell with the project environment activated
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
@@ -28,6 +29,8 @@ fn help() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Manage package indexes
+      auth                       Manage authentication
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Display uv's version
@@ -89,6 +92,7 @@ fn help_flag() {
       add      Add dependencies to the project
       remove   Remove dependencies from the project
       sync     Update the project's environment
+      shell    Launch a shell with the project environment activated
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
       tree     Display the project's dependency tree
@@ -98,6 +102,8 @@ fn help_flag() {
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      index    Manage package indexes
+      auth     Manage authentication
       cache    Manage uv's cache
       self     Manage the uv executable
       version  Display uv's version
@@ -157,6 +163,7 @@ fn help_short_flag() {
       add      Add dependencies to the project
       remove   Remove dependencies from the project
       sync     Update the project's environment
+      shell    Launch a shell with the project environment activated
       lock     Update the project's lockfile
       export   Export the project's lockfile to an alternate format
       tree     Display the project's dependency tree
@@ -166,6 +173,8 @@ fn help_short_flag() {
       venv     Create a virtual environment
       build    Build Python packages into source distributions and wheels
       publish  Upload distributions to an index
+      index    Manage package indexes
+      auth     Manage authentication
       cache    Manage uv's cache
       self     Manage the uv executable
       version  Display uv's version
@@ -260,6 +269,7 @@ fn help_subcommand() {
     Commands:
       list       List the available Python installations
       install    Download and install Python versions
+      upgrade    Upgrade managed Python versions to the latest patch release
       find       Search for a Python installation
       pin        Pin to a specific Python version
       dir        Show the uv Python installation directory
@@ -588,6 +598,7 @@ fn help_flag_subcommand() {
     Commands:
       list       List the available Python installations
       install    Download and install Python versions
+      upgrade    Upgrade managed Python versions to the latest patch release
       find       Search for a Python installation
       pin        Pin to a specific Python version
       dir        Show the uv Python installation directory
@@ -697,6 +708,7 @@ fn help_unknown_subcommand() {
         add
         remove
         sync
+        shell
         lock
         export
         tree
@@ -706,6 +718,8 @@ fn help_unknown_subcommand() {
         venv
         build
         publish
+        index
+        auth
         cache
         self
         version
@@ -724,6 +738,7 @@ fn help_unknown_subcommand() {
         add
         remove
         sync
+        shell
         lock
         export
         tree
@@ -733,6 +748,8 @@ fn help_unknown_subcommand() {
         venv
         build
         publish
+        index
+        auth
         cache
         self
         version
@@ -778,6 +795,7 @@ fn help_with_global_option() {
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
       sync                       Update the project's environment
+      shell                      Launch a shell with the project environment activated
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
@@ -787,6 +805,8 @@ fn help_with_global_option() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Manage package indexes
+      auth                       Manage authentication
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Display uv's version
@@ -883,6 +903,7 @@ fn help_with_no_pager() {
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
       sync                       Update the project's environment
+      shell                      Launch a shell with the project environment activated
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
       tree                       Display the project's dependency tree
@@ -892,6 +913,8 @@ fn help_with_no_pager() {
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      index                      Manage package indexes
+      auth                       Manage authentication
       cache                      Manage uv's cache
       self                       Manage the uv executable
       version                    Display uv's version
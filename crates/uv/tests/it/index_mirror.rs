@@ -0,0 +1,217 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Mirror the locked distributions into a PEP 503 simple index, then install from it offline.
+#[test]
+fn mirror_simple() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.index_mirror().arg("mirror"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Mirrored 2 files to mirror in [TIME]
+    "###);
+
+    context
+        .temp_dir
+        .child("mirror")
+        .child("index.html")
+        .assert(predicates::str::contains(
+            r#"<a href="iniconfig/">iniconfig</a>"#,
+        ));
+    context
+        .temp_dir
+        .child("mirror")
+        .child("iniconfig")
+        .child("iniconfig-2.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+    context
+        .temp_dir
+        .child("mirror")
+        .child("iniconfig")
+        .child("index.html")
+        .assert(predicates::str::contains(
+            "iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+        ));
+
+    // Mirroring again should be a no-op.
+    uv_snapshot!(context.filters(), context.index_mirror().arg("mirror"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Mirrored 2 files to mirror in [TIME]
+    "###);
+
+    // The mirror should be usable as an index.
+    let index_url = context.temp_dir.child("mirror").path().to_path_buf();
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--index-url")
+        .arg(index_url)
+        .arg("--no-cache")
+        .arg("--offline"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// Mirror the locked distributions into a flat directory, suitable for `--find-links`.
+#[test]
+fn mirror_flat() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.index_mirror().arg("wheels").arg("--format").arg("flat"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Mirrored 2 files to wheels in [TIME]
+    "###);
+
+    context
+        .temp_dir
+        .child("wheels")
+        .child("iniconfig-2.0.0.tar.gz")
+        .assert(predicates::path::is_file());
+    context
+        .temp_dir
+        .child("wheels")
+        .child("index.html")
+        .assert(predicates::path::missing());
+
+    Ok(())
+}
+
+/// Distributions without a hash in the lockfile are mirrored with a computed hash, unless hashes
+/// are required.
+#[test]
+fn mirror_missing_hashes() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    // Strip the hashes from the lockfile.
+    let lock = context.read("uv.lock");
+    let lock = regex::Regex::new(r#", hash = "sha256:[0-9a-f]+""#)?.replace_all(&lock, "");
+    context.temp_dir.child("uv.lock").write_str(&lock)?;
+
+    uv_snapshot!(context.filters(), context.index_mirror().arg("mirror").arg("--require-hashes"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: In `--require-hashes` mode, all distributions must have a hash, but none were found in `uv.lock` for: `iniconfig-2.0.0-py3-none-any.whl`, `iniconfig-2.0.0.tar.gz`
+    "###);
+
+    context
+        .temp_dir
+        .child("mirror")
+        .assert(predicates::path::missing());
+
+    uv_snapshot!(context.filters(), context.index_mirror().arg("mirror"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: No hash found in `uv.lock` for `iniconfig-2.0.0-py3-none-any.whl`; recording its computed SHA-256 digest instead
+    warning: No hash found in `uv.lock` for `iniconfig-2.0.0.tar.gz`; recording its computed SHA-256 digest instead
+    Mirrored 2 files to mirror in [TIME]
+    "###);
+
+    // The computed hashes should be recorded in the index.
+    context
+        .temp_dir
+        .child("mirror")
+        .child("iniconfig")
+        .child("index.html")
+        .assert(predicates::str::contains(
+            "iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374",
+        ));
+
+    Ok(())
+}
+
+/// Mirroring requires an existing lockfile.
+#[test]
+fn mirror_missing_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.index_mirror().arg("mirror"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    "###);
+
+    Ok(())
+}
@@ -17750,3 +17750,80 @@ fn lock_transitive_git() -> Result<()> {
 
     Ok(())
 }
+
+/// Write a profile of the resolution with `--profile-resolution`.
+#[test]
+fn lock_profile_resolution() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.lock().arg("--profile-resolution").arg("profile.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Wrote resolution profile to: profile.json
+    "###);
+
+    let profile: serde_json::Value = serde_json::from_str(&context.read("profile.json"))?;
+    let packages = profile["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|package| package["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(packages, ["anyio", "idna", "project", "sniffio"]);
+    assert_eq!(profile["backtracks"], 0);
+    // The project's metadata is read statically, so nothing should be built.
+    assert_eq!(profile["builds"], serde_json::json!([]));
+
+    // If the lockfile is already up-to-date, no resolution is performed, so there's nothing to
+    // profile.
+    uv_snapshot!(context.filters(), context.lock().arg("--profile-resolution").arg("fresh.json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    warning: The lockfile is already up-to-date, so no resolution was profiled; use `--upgrade` to force a resolution
+    "###);
+
+    assert!(!context.temp_dir.child("fresh.json").exists());
+
+    // Write the profile in the Chrome trace format.
+    uv_snapshot!(context.filters(), context.lock().arg("--profile-resolution").arg("trace.json").arg("--profile-format").arg("chrome-trace").arg("--upgrade"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Wrote resolution profile to: trace.json
+    "###);
+
+    let trace: serde_json::Value = serde_json::from_str(&context.read("trace.json"))?;
+    assert!(trace["traceEvents"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|event| event["cat"] == "decision" && event["name"] == "anyio==3.7.0"));
+
+    Ok(())
+}
@@ -3,6 +3,9 @@
 
 pub(crate) mod common;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod auth;
+
 mod branching_urls;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -28,6 +31,9 @@ mod export;
 
 mod help;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod index_mirror;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod init;
 
@@ -69,6 +75,9 @@ mod python_dir;
 #[cfg(feature = "python")]
 mod python_find;
 
+#[cfg(feature = "python")]
+mod python_list;
+
 #[cfg(feature = "python-managed")]
 mod python_install;
 
@@ -81,6 +90,9 @@ mod run;
 #[cfg(feature = "self-update")]
 mod self_update;
 
+#[cfg(all(feature = "python", feature = "pypi", unix))]
+mod shell;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod show_settings;
 
@@ -99,6 +111,9 @@ mod tool_list;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_run;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod tool_sync;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_uninstall;
 
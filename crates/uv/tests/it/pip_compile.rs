@@ -4509,6 +4509,243 @@ fn find_links_directory() -> Result<()> {
     Ok(())
 }
 
+/// Compile using `--find-links` with a local directory that includes a PEP 691 `index.json`,
+/// which provides upload times and yanked statuses for the distributions in the directory.
+#[test]
+fn find_links_directory_metadata() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    for filename in ["ok-1.0.0-py3-none-any.whl", "ok-2.0.0-py3-none-any.whl"] {
+        fs_err::copy(
+            context
+                .workspace_root
+                .join("scripts")
+                .join("links")
+                .join(filename),
+            links.child(filename),
+        )?;
+    }
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("ok")?;
+
+    // `ok==2.0.0` was uploaded after the `--exclude-newer` cutoff.
+    links.child("index.json").write_str(indoc! {r#"
+        {
+            "files": [
+                {
+                    "filename": "ok-1.0.0-py3-none-any.whl",
+                    "url": "ok-1.0.0-py3-none-any.whl",
+                    "hashes": {},
+                    "upload-time": "2024-01-01T00:00:00Z"
+                },
+                {
+                    "filename": "ok-2.0.0-py3-none-any.whl",
+                    "url": "ok-2.0.0-py3-none-any.whl",
+                    "hashes": {},
+                    "upload-time": "2024-06-01T00:00:00Z"
+                }
+            ]
+        }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(links.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index
+    ok==1.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // `ok==2.0.0` was yanked.
+    links.child("index.json").write_str(indoc! {r#"
+        {
+            "files": [
+                {
+                    "filename": "ok-2.0.0-py3-none-any.whl",
+                    "url": "ok-2.0.0-py3-none-any.whl",
+                    "hashes": {},
+                    "yanked": "Broken release"
+                }
+            ]
+        }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(links.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index
+    ok==1.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    // A yanked version can still be selected with an exact pin.
+    requirements_in.write_str("ok==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(links.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index
+    ok==2.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: `ok==2.0.0` is yanked (reason: "Broken release")
+    "###
+    );
+
+    Ok(())
+}
+
+/// Compile using `--find-links` with a local directory that includes a PEP 691 `index.json`,
+/// which provides the `requires-python` for the distributions in the directory.
+#[test]
+fn find_links_directory_metadata_requires_python() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    for filename in ["ok-1.0.0-py3-none-any.whl", "ok-2.0.0-py3-none-any.whl"] {
+        fs_err::copy(
+            context
+                .workspace_root
+                .join("scripts")
+                .join("links")
+                .join(filename),
+            links.child(filename),
+        )?;
+    }
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("ok")?;
+
+    // `ok==2.0.0` requires a newer Python version.
+    links.child("index.json").write_str(indoc! {r#"
+        {
+            "files": [
+                {
+                    "filename": "ok-2.0.0-py3-none-any.whl",
+                    "url": "ok-2.0.0-py3-none-any.whl",
+                    "hashes": {},
+                    "requires-python": ">=3.13"
+                }
+            ]
+        }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(links.path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index
+    ok==1.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
+/// Compile using a local PEP 503 index directory, in which the package directory contains a PEP
+/// 691 `index.json` rather than an `index.html`.
+#[test]
+fn local_index_json() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let package = context.temp_dir.child("simple").child("ok");
+    package.create_dir_all()?;
+    for filename in ["ok-1.0.0-py3-none-any.whl", "ok-2.0.0-py3-none-any.whl"] {
+        fs_err::copy(
+            context
+                .workspace_root
+                .join("scripts")
+                .join("links")
+                .join(filename),
+            package.child(filename),
+        )?;
+    }
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("ok")?;
+
+    // `ok==2.0.0` was yanked.
+    package.child("index.json").write_str(indoc! {r#"
+        {
+            "files": [
+                {
+                    "filename": "ok-1.0.0-py3-none-any.whl",
+                    "url": "ok-1.0.0-py3-none-any.whl",
+                    "hashes": {},
+                    "upload-time": "2024-01-01T00:00:00Z"
+                },
+                {
+                    "filename": "ok-2.0.0-py3-none-any.whl",
+                    "url": "ok-2.0.0-py3-none-any.whl",
+                    "hashes": {},
+                    "upload-time": "2024-01-01T00:00:00Z",
+                    "yanked": true
+                }
+            ]
+        }
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--index-url")
+            .arg(context.temp_dir.child("simple").path()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in
+    ok==1.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Compile using `--find-links` with a URL by resolving `tqdm` from the `PyTorch` wheels index.
 #[test]
 fn find_links_url() -> Result<()> {
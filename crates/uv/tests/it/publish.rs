@@ -1,5 +1,11 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+use url::Url;
+
 use crate::common::{uv_snapshot, TestContext};
 use uv_static::EnvVars;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
 
 #[test]
 fn username_password_no_longer_supported() {
@@ -142,3 +148,396 @@ fn no_credentials() {
     "###
     );
 }
+
+/// Publishing to a named index requires the index to define a `publish-url`.
+#[test]
+fn index_without_publish_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://test.pypi.org/simple"
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--index")
+        .arg("internal")
+        .arg("dist/*")
+        .current_dir(&context.temp_dir), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    error: Index `internal` does not define a `publish-url`
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("--index")
+        .arg("missing")
+        .arg("dist/*")
+        .current_dir(&context.temp_dir), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    error: Index not found: `missing`. Define the index in `[[tool.uv.index]]` to publish to it by name.
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject files whose metadata doesn't match the filename, before uploading anything.
+#[test]
+fn metadata_filename_mismatch() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("dist")
+        .child("tqdm-1.0.0.tar.gz")
+        .write_file(std::path::Path::new(
+            "../../scripts/links/tqdm-999.0.0.tar.gz",
+        ))?;
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("-u")
+        .arg("__token__")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("dist/*")
+        .current_dir(&context.temp_dir), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 1 file to https://test.pypi.org/legacy/
+    error: Failed to publish: `dist/tqdm-1.0.0.tar.gz`
+      Caused by: The version in the metadata (`999.0.0`) does not match the filename (`1.0.0`)
+    "###
+    );
+
+    Ok(())
+}
+
+/// Reject the same distribution from multiple paths, before uploading anything.
+#[test]
+fn duplicate_distribution() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    for directory in ["a", "b"] {
+        context
+            .temp_dir
+            .child(directory)
+            .child("ok-1.0.0-py3-none-any.whl")
+            .write_file(std::path::Path::new(
+                "../../scripts/links/ok-1.0.0-py3-none-any.whl",
+            ))?;
+    }
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("-u")
+        .arg("__token__")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("a/*")
+        .arg("b/*")
+        .current_dir(&context.temp_dir), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 2 files https://test.pypi.org/legacy/
+    error: Found multiple files for ok-1.0.0-py3-none-any.whl: `a/ok-1.0.0-py3-none-any.whl` and `b/ok-1.0.0-py3-none-any.whl`
+    "###
+    );
+
+    Ok(())
+}
+
+/// Report which files would be uploaded, without uploading anything.
+#[test]
+fn dry_run() {
+    let context = TestContext::new("3.12");
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("-u")
+        .arg("__token__")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("--dry-run")
+        .arg("--concurrent-uploads")
+        .arg("2")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .arg("../../scripts/links/ok-2.0.0-py3-none-any.whl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 2 files https://test.pypi.org/legacy/
+    Would upload ok-1.0.0-py3-none-any.whl ([SIZE])
+    Would upload ok-2.0.0-py3-none-any.whl ([SIZE])
+    Summary:
+      would upload  ok-1.0.0-py3-none-any.whl ([SIZE])
+      would upload  ok-2.0.0-py3-none-any.whl ([SIZE])
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.publish()
+        .arg("-u")
+        .arg("__token__")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg("https://test.pypi.org/legacy/")
+        .arg("--dry-run")
+        .arg("--format")
+        .arg("json")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "filename": "ok-1.0.0-py3-none-any.whl",
+        "path": "../../scripts/links/ok-1.0.0-py3-none-any.whl",
+        "size": 875,
+        "status": "dry-run"
+      }
+    ]
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 1 file to https://test.pypi.org/legacy/
+    Would upload ok-1.0.0-py3-none-any.whl ([SIZE])
+    "###
+    );
+}
+
+/// With concurrent uploads, a failed upload doesn't abort the other uploads: every file is
+/// reported, and the command fails at the end.
+#[tokio::test]
+async fn concurrent_upload_failure() {
+    let context = TestContext::new("3.12");
+
+    // Reject `ok-1.0.0`, and accept all other files.
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/legacy/"))
+        .and(|request: &Request| {
+            let needle = b"ok-1.0.0-py3-none-any.whl";
+            request
+                .body
+                .windows(needle.len())
+                .any(|window| window == needle)
+        })
+        .respond_with(ResponseTemplate::new(400).set_body_string("Invalid distribution"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/legacy/"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let publish_url = format!("{}/legacy/", server.uri());
+    let escaped_publish_url = regex::escape(&publish_url);
+    let filters = [(escaped_publish_url.as_str(), "[PUBLISH_URL]")]
+        .into_iter()
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.publish()
+        .arg("-u")
+        .arg("__token__")
+        .arg("-p")
+        .arg("dummy")
+        .arg("--publish-url")
+        .arg(&publish_url)
+        .arg("--concurrent-uploads")
+        .arg("3")
+        .arg("--format")
+        .arg("json")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .arg("../../scripts/links/ok-2.0.0-py3-none-any.whl")
+        .arg("../../scripts/links/tqdm-999.0.0.tar.gz"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+    [
+      {
+        "filename": "ok-1.0.0-py3-none-any.whl",
+        "path": "../../scripts/links/ok-1.0.0-py3-none-any.whl",
+        "size": 875,
+        "status": "failed",
+        "error": "Failed to publish `../../scripts/links/ok-1.0.0-py3-none-any.whl` to [PUBLISH_URL]: Upload failed with status code 400 Bad Request. Server says: Invalid distribution"
+      },
+      {
+        "filename": "ok-2.0.0-py3-none-any.whl",
+        "path": "../../scripts/links/ok-2.0.0-py3-none-any.whl",
+        "size": 871,
+        "status": "uploaded"
+      },
+      {
+        "filename": "tqdm-999.0.0.tar.gz",
+        "path": "../../scripts/links/tqdm-999.0.0.tar.gz",
+        "size": 2127,
+        "status": "uploaded"
+      }
+    ]
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 3 files [PUBLISH_URL]
+    Uploading ok-1.0.0-py3-none-any.whl ([SIZE])
+    Uploading ok-2.0.0-py3-none-any.whl ([SIZE])
+    Uploading tqdm-999.0.0.tar.gz ([SIZE])
+    error: Failed to publish `../../scripts/links/ok-1.0.0-py3-none-any.whl` to [PUBLISH_URL]
+      Caused by: Upload failed with status code 400 Bad Request. Server says: Invalid distribution
+    "###
+    );
+}
+
+/// Publish to a local directory as a static index, and skip files that are already in it.
+#[test]
+fn publish_file_url() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let index = context.temp_dir.child("index");
+    let publish_url = Url::from_directory_path(index.path()).unwrap();
+    let escaped_publish_url = regex::escape(publish_url.as_str());
+    let filters = [(escaped_publish_url.as_str(), "[PUBLISH_URL]")]
+        .into_iter()
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.publish()
+        .arg("--publish-url")
+        .arg(publish_url.as_str())
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .arg("../../scripts/links/tqdm-999.0.0.tar.gz"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 2 files [PUBLISH_URL]
+    Uploaded ok-1.0.0-py3-none-any.whl ([SIZE])
+    Uploaded tqdm-999.0.0.tar.gz ([SIZE])
+    Summary:
+      uploaded  ok-1.0.0-py3-none-any.whl ([SIZE])
+      uploaded  tqdm-999.0.0.tar.gz ([SIZE])
+    "###
+    );
+
+    index
+        .child("ok")
+        .child("ok-1.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+    index
+        .child("tqdm")
+        .child("tqdm-999.0.0.tar.gz")
+        .assert(predicates::path::is_file());
+    insta::assert_snapshot!(
+        fs_err::read_to_string(index.child("ok").child("index.html"))?,
+        @r###"
+    <!DOCTYPE html>
+    <html>
+      <head>
+        <title>Links for ok</title>
+      </head>
+      <body>
+        <h1>Links for ok</h1>
+        <a href="ok-1.0.0-py3-none-any.whl#sha256=79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f">ok-1.0.0-py3-none-any.whl</a><br/>
+      </body>
+    </html>
+    "###
+    );
+
+    // Publishing again skips the existing file and adds the new one to the package page.
+    uv_snapshot!(filters, context.publish()
+        .arg("--publish-url")
+        .arg(publish_url.as_str())
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl")
+        .arg("../../scripts/links/ok-2.0.0-py3-none-any.whl"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 2 files [PUBLISH_URL]
+    File ok-1.0.0-py3-none-any.whl already exists, skipping
+    Uploaded ok-2.0.0-py3-none-any.whl ([SIZE])
+    Summary:
+      skipped   ok-1.0.0-py3-none-any.whl ([SIZE])
+      uploaded  ok-2.0.0-py3-none-any.whl ([SIZE])
+    "###
+    );
+
+    insta::assert_snapshot!(
+        fs_err::read_to_string(index.child("ok").child("index.html"))?,
+        @r###"
+    <!DOCTYPE html>
+    <html>
+      <head>
+        <title>Links for ok</title>
+      </head>
+      <body>
+        <h1>Links for ok</h1>
+        <a href="ok-1.0.0-py3-none-any.whl#sha256=79f0b33e6ce1e09eaa1784c8eee275dfe84d215d9c65c652f07c18e85fdaac5f">ok-1.0.0-py3-none-any.whl</a><br/>
+        <a href="ok-2.0.0-py3-none-any.whl#sha256=8163cd4f0477f8e93b856ac6a517fe5fa0f29339291fe2807d5376df685f6697">ok-2.0.0-py3-none-any.whl</a><br/>
+      </body>
+    </html>
+    "###
+    );
+
+    // There's no index to check against.
+    uv_snapshot!(filters, context.publish()
+        .arg("--publish-url")
+        .arg(publish_url.as_str())
+        .arg("--check-url")
+        .arg("https://test.pypi.org/simple/")
+        .arg("../../scripts/links/ok-1.0.0-py3-none-any.whl"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: `uv publish` is experimental and may change without warning
+    Publishing 1 file to [PUBLISH_URL]
+    error: `--check-url` is not supported when publishing to a static index
+    "###
+    );
+
+    Ok(())
+}
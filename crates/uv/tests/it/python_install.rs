@@ -1,7 +1,12 @@
 use std::process::Command;
 
-use assert_fs::{assert::PathAssert, prelude::PathChild};
+use assert_cmd::prelude::*;
+use assert_fs::{
+    assert::PathAssert,
+    prelude::{PathChild, PathCreateDir},
+};
 use predicates::prelude::predicate;
+use uv_static::EnvVars;
 
 use crate::common::{uv_snapshot, TestContext};
 
@@ -249,6 +254,227 @@ fn python_install_freethreaded() {
     "###);
 }
 
+#[test]
+fn python_install_debug() {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_keys();
+
+    // Install the debug variant
+    uv_snapshot!(context.filters(), context.python_install().arg("--preview").arg("3.13d"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.13.0 in [TIME]
+     + cpython-3.13.0+debug-[PLATFORM]
+    "###);
+
+    let bin_python = context
+        .temp_dir
+        .child("bin")
+        .child(format!("python3.13d{}", std::env::consts::EXE_SUFFIX));
+
+    // The executable should be installed in the bin directory
+    bin_python.assert(predicate::path::exists());
+
+    // The executable should be a debug build
+    uv_snapshot!(context.filters(), Command::new(bin_python.as_os_str())
+        .arg("-c").arg("import sys; print(hasattr(sys, 'gettotalrefcount'))"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    True
+
+    ----- stderr -----
+    "###);
+
+    // The key syntax should be equivalent
+    uv_snapshot!(context.filters(), context.python_install().arg("cpython-3.13+debug"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python installations
+    Uninstalled Python 3.13.0 in [TIME]
+     - cpython-3.13.0+debug-[PLATFORM]
+    "###);
+}
+
+#[test]
+fn python_upgrade() {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_keys();
+
+    // Install outdated patch releases
+    uv_snapshot!(context.filters(), context.python_install().arg("3.12.5").arg("3.11.9"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed 2 versions in [TIME]
+     + cpython-3.11.9-[PLATFORM]
+     + cpython-3.12.5-[PLATFORM]
+    "###);
+
+    // Upgrade a single minor version, removing the outdated patch release
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Upgraded to Python 3.12.7 in [TIME]
+     - cpython-3.12.5-[PLATFORM]
+     + cpython-3.12.7-[PLATFORM]
+    "###);
+
+    // Upgrading again is a no-op
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All requested versions are already on the latest patch release
+    "###);
+
+    // Upgrade all minor versions, keeping the outdated patch release
+    uv_snapshot!(context.filters(), context.python_upgrade().arg("--keep"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Upgraded to Python 3.11.10 in [TIME]
+     + cpython-3.11.10-[PLATFORM]
+    "###);
+
+    uv_snapshot!(context.filters(), context.python_uninstall().arg("--all"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Searching for Python installations
+    Uninstalled 3 versions in [TIME]
+     - cpython-3.11.9-[PLATFORM]
+     - cpython-3.11.10-[PLATFORM]
+     - cpython-3.12.7-[PLATFORM]
+    "###);
+}
+
+/// Outdated patch releases that are still used by an environment are kept.
+#[test]
+fn python_upgrade_in_use() {
+    let context: TestContext = TestContext::new_with_versions(&[]);
+    // Unlike `with_filtered_python_keys`, only filter the platform, to retain the rest of the
+    // warning.
+    let filters = [(r"((?:cpython|pypy)-\d+\.\d+\.\d+)-[^\s`]+", "$1-[PLATFORM]")]
+        .into_iter()
+        .chain(context.filters())
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.python_install().arg("3.12.5"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed Python 3.12.5 in [TIME]
+     + cpython-3.12.5-[PLATFORM]
+    "###);
+
+    // Create a project environment with the outdated patch release
+    context
+        .venv()
+        .arg(context.temp_dir.join(".venv"))
+        .arg("--python")
+        .arg("3.12.5")
+        .arg("--python-preference")
+        .arg("only-managed")
+        .env(
+            EnvVars::UV_PYTHON_INSTALL_DIR,
+            context.temp_dir.join("managed"),
+        )
+        .assert()
+        .success();
+
+    uv_snapshot!(filters, context.python_upgrade().env_remove(EnvVars::VIRTUAL_ENV), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Upgraded to Python 3.12.7 in [TIME]
+     + cpython-3.12.7-[PLATFORM]
+    warning: Keeping `cpython-3.12.5-[PLATFORM]`, which is still used by: environment `.venv`
+    "###);
+}
+
+/// A virtual environment with a bundled Python installation can be moved, even once the managed
+/// installation is removed.
+#[cfg(unix)]
+#[test]
+fn python_install_bundle_venv() -> anyhow::Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_keys();
+
+    context.python_install().arg("3.12").assert().success();
+
+    uv_snapshot!(context.filters(), context
+        .venv()
+        .arg(context.temp_dir.join(".venv"))
+        .arg("--python")
+        .arg("3.12")
+        .arg("--python-preference")
+        .arg("only-managed")
+        .arg("--bundle-python")
+        .env(EnvVars::UV_PYTHON_INSTALL_DIR, context.temp_dir.join("managed")), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.7
+    Creating virtual environment at: .venv
+    Bundling cpython-3.12.7-[PLATFORM]
+    Activate with: source .venv/[BIN]/activate
+    "###);
+
+    // Remove the managed installation, and move the environment elsewhere.
+    fs_err::remove_dir_all(context.temp_dir.join("managed"))?;
+    let moved = context.temp_dir.child("moved");
+    moved.create_dir_all()?;
+    fs_err::rename(context.temp_dir.join(".venv"), moved.join(".venv"))?;
+
+    // The environment should still be usable, from any working directory.
+    let elsewhere = context.temp_dir.child("elsewhere");
+    elsewhere.create_dir_all()?;
+    uv_snapshot!(context.filters(), Command::new(moved.join(".venv").join("bin").join("python"))
+        .arg("-c")
+        .arg("import sys; print(sys.prefix); print(sys.base_prefix)")
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .current_dir(&elsewhere), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [TEMP_DIR]/moved/.venv
+    [TEMP_DIR]/moved/.venv/python
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn python_install_invalid_request() {
     let context: TestContext = TestContext::new_with_versions(&[]).with_filtered_python_keys();
@@ -0,0 +1,39 @@
+use anyhow::Result;
+
+use crate::common::TestContext;
+
+#[test]
+fn python_list_json() -> Result<()> {
+    let context: TestContext = TestContext::new_with_versions(&["3.11", "3.12"]);
+
+    let output = context
+        .python_list()
+        .arg("--only-installed")
+        .arg("--output-format")
+        .arg("json")
+        .output()?;
+    assert!(output.status.success(), "{output:?}");
+
+    let entries: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout)?;
+    let versions = entries
+        .iter()
+        .map(|entry| entry["version"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(versions.len(), 2, "{entries:?}");
+    assert!(versions[0].starts_with("3.12."), "{versions:?}");
+    assert!(versions[1].starts_with("3.11."), "{versions:?}");
+
+    for entry in &entries {
+        assert_eq!(entry["implementation"], "cpython");
+        assert_eq!(entry["variant"], "default");
+        assert_eq!(entry["source"], "search-path");
+        assert_eq!(entry["installed"], true);
+        assert!(entry["key"]
+            .as_str()
+            .unwrap()
+            .starts_with(&format!("cpython-{}-", entry["version"].as_str().unwrap())));
+        assert!(std::path::Path::new(entry["path"].as_str().unwrap()).exists());
+    }
+
+    Ok(())
+}
@@ -2668,3 +2668,143 @@ fn run_stdin_with_pep723() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn run_env_file() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.8"
+        dependencies = []
+        "#
+    })?;
+
+    let test_script = context.temp_dir.child("main.py");
+    test_script.write_str(indoc! { r#"
+        import os
+        for key in ["GREETING", "QUOTED", "SINGLE", "MULTILINE", "EXPANDED", "EXPORTED", "PRECEDENCE"]:
+            print(f"{key}={os.environ.get(key)!r}")
+       "#
+    })?;
+
+    context.temp_dir.child(".env").write_str(indoc! { r#"
+        # A comment.
+        GREETING=hello
+        QUOTED="hello world"
+        SINGLE='literal ${GREETING}'
+        MULTILINE="first
+        second"
+        EXPANDED="${GREETING}, world"
+        export EXPORTED=yes
+        PRECEDENCE=file
+       "#
+    })?;
+
+    context.temp_dir.child(".env.local").write_str(indoc! { r#"
+        GREETING=hi
+        EXPANDED="${QUOTED}!"
+       "#
+    })?;
+
+    // Variables should be loaded from the file, with support for quoting, multiline values, and
+    // substitution. Variables that are already set in the environment take precedence.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--env-file").arg(".env")
+        .arg("main.py")
+        .env("PRECEDENCE", "environment"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    GREETING='hello'
+    QUOTED='hello world'
+    SINGLE='literal ${GREETING}'
+    MULTILINE='first\nsecond'
+    EXPANDED='hello, world'
+    EXPORTED='yes'
+    PRECEDENCE='environment'
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // Later files take precedence over earlier ones, and can refer to variables defined in earlier
+    // files.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--env-file").arg(".env")
+        .arg("--env-file").arg(".env.local")
+        .arg("main.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    GREETING='hi'
+    QUOTED='hello world'
+    SINGLE='literal ${GREETING}'
+    MULTILINE='first\nsecond'
+    EXPANDED='hello world!'
+    EXPORTED='yes'
+    PRECEDENCE='file'
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // The file can be provided via `UV_ENV_FILE`.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("main.py")
+        .env(EnvVars::UV_ENV_FILE, ".env.local"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    GREETING='hi'
+    QUOTED=None
+    SINGLE=None
+    MULTILINE=None
+    EXPANDED='!'
+    EXPORTED=None
+    PRECEDENCE=None
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // Multiple files can be provided via `UV_ENV_FILE`, separated by the platform's path separator.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("main.py")
+        .env(EnvVars::UV_ENV_FILE, std::env::join_paths([".env", ".env.local"])?), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    GREETING='hi'
+    QUOTED='hello world'
+    SINGLE='literal ${GREETING}'
+    MULTILINE='first\nsecond'
+    EXPANDED='hello world!'
+    EXPORTED='yes'
+    PRECEDENCE='file'
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    "###);
+
+    // A missing file is an error.
+    uv_snapshot!(context.filters(), context.run()
+        .arg("--env-file").arg(".env.missing")
+        .arg("main.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No environment file found at: `.env.missing`
+    "###);
+
+    Ok(())
+}
@@ -0,0 +1,328 @@
+#[cfg(target_os = "linux")]
+use std::io::{Read, Write};
+#[cfg(target_os = "linux")]
+use std::path::Path;
+#[cfg(target_os = "linux")]
+use std::process::Command;
+#[cfg(target_os = "linux")]
+use std::sync::mpsc;
+#[cfg(target_os = "linux")]
+use std::time::Duration;
+
+use anyhow::Result;
+#[cfg(target_os = "linux")]
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use indoc::formatdoc;
+#[cfg(target_os = "linux")]
+use portable_pty::{native_pty_system, CommandBuilder, PtySize};
+
+use crate::common::{uv_snapshot, TestContext};
+#[cfg(target_os = "linux")]
+use uv_static::EnvVars;
+
+/// The result of an interactive `uv shell` session.
+#[cfg(target_os = "linux")]
+struct Session {
+    /// The exit code of `uv shell`.
+    exit_code: u32,
+    /// The combined output of uv and the shell, with carriage returns removed.
+    output: String,
+}
+
+/// Run the given command in a pseudo-terminal, writing `input` to it as if typed by the user, and
+/// wait for it to exit.
+#[cfg(target_os = "linux")]
+fn run_in_pty(command: &Command, input: &str) -> Result<Session> {
+    let pair = native_pty_system().openpty(PtySize {
+        rows: 24,
+        cols: 200,
+        pixel_width: 0,
+        pixel_height: 0,
+    })?;
+
+    let mut builder = CommandBuilder::new(command.get_program());
+    builder.args(command.get_args());
+    for (key, value) in command.get_envs() {
+        match value {
+            Some(value) => builder.env(key, value),
+            None => builder.env_remove(key),
+        }
+    }
+    if let Some(dir) = command.get_current_dir() {
+        builder.cwd(dir);
+    }
+
+    let mut child = pair.slave.spawn_command(builder)?;
+
+    // Close our handle to the terminal, such that reads end once the shell exits.
+    drop(pair.slave);
+
+    let mut reader = pair.master.try_clone_reader()?;
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut output = Vec::new();
+        // Once the terminal is closed, reads fail with `EIO` rather than returning EOF.
+        let _ = reader.read_to_end(&mut output);
+        let _ = sender.send(output);
+    });
+
+    // The input is buffered by the terminal until the shell is ready to read it.
+    let mut writer = pair.master.take_writer()?;
+    writer.write_all(input.as_bytes())?;
+    writer.flush()?;
+
+    let Ok(output) = receiver.recv_timeout(Duration::from_secs(60)) else {
+        child.kill()?;
+        anyhow::bail!("Timed out waiting for the shell to exit");
+    };
+    let status = child.wait()?;
+
+    Ok(Session {
+        exit_code: status.exit_code(),
+        output: String::from_utf8_lossy(&output).replace('\r', ""),
+    })
+}
+
+/// Create a `uv shell` command for the given shell, isolated from the user's startup files.
+#[cfg(target_os = "linux")]
+fn shell_command(context: &TestContext, shell: &Path) -> Result<Command> {
+    let home = context.temp_dir.child("home");
+    home.create_dir_all()?;
+
+    let mut command = context.shell();
+    command
+        .env(EnvVars::SHELL, shell)
+        .env(EnvVars::HOME, home.path())
+        .env(EnvVars::XDG_CONFIG_HOME, home.child(".config").path())
+        .env(EnvVars::XDG_DATA_HOME, home.child(".local/share").path())
+        .env_remove(EnvVars::ZDOTDIR)
+        .env_remove(EnvVars::VIRTUAL_ENV)
+        .env("TERM", "dumb");
+    Ok(command)
+}
+
+/// Write a `pyproject.toml` for a project named `project`.
+fn write_project(context: &TestContext, dependencies: &str) -> Result<()> {
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(&formatdoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = {dependencies}
+        "#,
+            dependencies = dependencies,
+        })?;
+    Ok(())
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn shell_bash() -> Result<()> {
+    let context = TestContext::new("3.12");
+    write_project(&context, r#"["iniconfig"]"#)?;
+
+    let bash = which::which("bash")?;
+
+    // A `.bashrc` in the user's home directory should be sourced before activation.
+    context
+        .temp_dir
+        .child("home")
+        .child(".bashrc")
+        .write_str("PS1='bash> '\n")?;
+
+    // The environment should be synced, and the shell launched with the environment activated.
+    let session = run_in_pty(
+        &shell_command(&context, &bash)?,
+        "echo \"VIRTUAL_ENV=$VIRTUAL_ENV\"\nexit\n",
+    )?;
+
+    assert_eq!(session.exit_code, 0, "{}", session.output);
+    assert!(
+        session.output.contains("+ iniconfig==2.0.0"),
+        "{}",
+        session.output
+    );
+    assert!(
+        session
+            .output
+            .contains(&format!("VIRTUAL_ENV={}", context.venv.display())),
+        "{}",
+        session.output
+    );
+    assert!(
+        session.output.contains("(project) bash> "),
+        "{}",
+        session.output
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn shell_bash_exit_code() -> Result<()> {
+    let context = TestContext::new("3.12");
+    write_project(&context, "[]")?;
+
+    let bash = which::which("bash")?;
+
+    // The shell's exit code should be propagated.
+    let session = run_in_pty(&shell_command(&context, &bash)?, "exit 3\n")?;
+    assert_eq!(session.exit_code, 3, "{}", session.output);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn shell_no_sync() -> Result<()> {
+    let context = TestContext::new("3.12");
+    write_project(&context, r#"["iniconfig"]"#)?;
+
+    let bash = which::which("bash")?;
+
+    // With an existing environment, it should be activated as-is.
+    context.venv().assert().success();
+
+    let mut command = shell_command(&context, &bash)?;
+    command.arg("--no-sync");
+    let session = run_in_pty(&command, "echo \"VIRTUAL_ENV=$VIRTUAL_ENV\"\nexit\n")?;
+
+    assert_eq!(session.exit_code, 0, "{}", session.output);
+    assert!(!session.output.contains("iniconfig"), "{}", session.output);
+    assert!(
+        session
+            .output
+            .contains(&format!("VIRTUAL_ENV={}", context.venv.display())),
+        "{}",
+        session.output
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(target_os = "linux", feature = "shells"))]
+fn shell_fish() -> Result<()> {
+    let fish = which::which("fish")?;
+
+    let context = TestContext::new("3.12");
+    write_project(&context, "[]")?;
+
+    let session = run_in_pty(
+        &shell_command(&context, &fish)?,
+        "echo \"VIRTUAL_ENV=$VIRTUAL_ENV\"; exit\n",
+    )?;
+
+    assert_eq!(session.exit_code, 0, "{}", session.output);
+    assert!(
+        session
+            .output
+            .contains(&format!("VIRTUAL_ENV={}", context.venv.display())),
+        "{}",
+        session.output
+    );
+    assert!(session.output.contains("(project) "), "{}", session.output);
+
+    Ok(())
+}
+
+/// Zsh should be activated even if the user's `.zshenv` changes `ZDOTDIR`.
+#[test]
+#[cfg(all(target_os = "linux", feature = "shells"))]
+fn shell_zsh_zdotdir() -> Result<()> {
+    let zsh = which::which("zsh")?;
+
+    let context = TestContext::new("3.12");
+    write_project(&context, "[]")?;
+
+    let home = context.temp_dir.child("home");
+    home.child(".zshenv")
+        .write_str("ZDOTDIR=\"$HOME/.config/zsh\"\n")?;
+    home.child(".config")
+        .child("zsh")
+        .child(".zshrc")
+        .write_str("PS1='zsh> '\n")?;
+
+    let session = run_in_pty(
+        &shell_command(&context, &zsh)?,
+        "echo \"VIRTUAL_ENV=$VIRTUAL_ENV\"\nexit\n",
+    )?;
+
+    assert_eq!(session.exit_code, 0, "{}", session.output);
+    assert!(
+        session
+            .output
+            .contains(&format!("VIRTUAL_ENV={}", context.venv.display())),
+        "{}",
+        session.output
+    );
+    assert!(
+        session.output.contains("(project) zsh> "),
+        "{}",
+        session.output
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(all(target_os = "linux", feature = "shells"))]
+fn shell_csh() -> Result<()> {
+    let csh = which::which("tcsh").or_else(|_| which::which("csh"))?;
+
+    let context = TestContext::new("3.12");
+    write_project(&context, "[]")?;
+
+    // The user's `.cshrc` should be sourced before activation.
+    context
+        .temp_dir
+        .child("home")
+        .child(".cshrc")
+        .write_str("set prompt = 'csh> '\n")?;
+
+    let session = run_in_pty(
+        &shell_command(&context, &csh)?,
+        "echo \"VIRTUAL_ENV=$VIRTUAL_ENV\"\nexit\n",
+    )?;
+
+    assert_eq!(session.exit_code, 0, "{}", session.output);
+    assert!(
+        session
+            .output
+            .contains(&format!("VIRTUAL_ENV={}", context.venv.display())),
+        "{}",
+        session.output
+    );
+    assert!(
+        session.output.contains("(project) csh> "),
+        "{}",
+        session.output
+    );
+
+    Ok(())
+}
+
+#[test]
+fn shell_unknown() -> Result<()> {
+    let context = TestContext::new("3.12");
+    write_project(&context, "[]")?;
+
+    // If the shell can't be detected, we should fail before syncing.
+    uv_snapshot!(context.filters(), context.shell(), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to detect the current shell; set the `SHELL` environment variable to the path of your shell
+    "###);
+
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    Ok(())
+}
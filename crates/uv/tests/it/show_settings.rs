@@ -65,6 +65,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -115,6 +116,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -212,6 +218,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -262,6 +269,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -360,6 +372,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -410,6 +423,11 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -540,6 +558,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -590,6 +609,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -689,6 +713,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -818,6 +843,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -868,6 +894,11 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -990,6 +1021,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1040,6 +1072,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                     Index {
@@ -1068,6 +1105,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -1167,6 +1209,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1217,6 +1260,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -1247,6 +1295,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                     Index {
@@ -1275,6 +1328,11 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -1397,6 +1455,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1448,6 +1507,11 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -1568,6 +1632,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1703,6 +1768,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1753,6 +1819,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                     Index {
@@ -1781,6 +1852,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -1878,6 +1954,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -1928,6 +2005,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                     Index {
@@ -1956,6 +2038,11 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -2077,6 +2164,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2202,6 +2290,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2327,6 +2416,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2454,6 +2544,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2600,6 +2691,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -2607,6 +2699,9 @@ fn resolve_tool() -> anyhow::Result<()> {
         with: [],
         with_requirements: [],
         with_editable: [],
+        with_executables_from: [],
+        constraints: [],
+        overrides: [],
         python: None,
         refresh: None(
             Timestamp(
@@ -2687,6 +2782,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             },
         },
         force: false,
+        locked: false,
         editable: false,
     }
 
@@ -2758,6 +2854,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2911,6 +3008,7 @@ fn resolve_both() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -2961,6 +3059,11 @@ fn resolve_both() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -3085,6 +3188,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3135,6 +3239,11 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -3333,6 +3442,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3461,6 +3571,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3597,6 +3708,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3747,6 +3859,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3797,6 +3910,11 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -3827,6 +3945,11 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -3924,6 +4047,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -3974,6 +4098,11 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4004,6 +4133,11 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -4107,6 +4241,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4157,6 +4292,11 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4187,6 +4327,11 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -4285,6 +4430,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4335,6 +4481,11 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4365,6 +4516,11 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -4470,6 +4626,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4520,6 +4677,11 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4550,6 +4712,11 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
@@ -4648,6 +4815,7 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        max_cache_size: None,
     }
     PipCompileSettings {
         src_file: [
@@ -4698,6 +4866,11 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: false,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: Some(
                             Cli,
                         ),
@@ -4728,6 +4901,11 @@ fn index_priority() -> anyhow::Result<()> {
                         ),
                         explicit: false,
                         default: true,
+                        authenticate: Auto,
+                        credential_provider: Auto,
+                        ca_bundle: None,
+                        allow_insecure: false,
+                        publish_url: None,
                         origin: None,
                     },
                 ],
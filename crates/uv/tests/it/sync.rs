@@ -3798,3 +3798,125 @@ fn sync_explicit() -> Result<()> {
 
     Ok(())
 }
+
+/// Sync a project with `tool.uv.relocatable` enabled.
+#[test]
+fn sync_relocatable() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig"]
+
+        [tool.uv]
+        relocatable = true
+        "#,
+    )?;
+
+    // The existing environment isn't relocatable, so it should be recreated.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    let pyvenv_cfg = fs_err::read_to_string(context.venv.join("pyvenv.cfg"))?;
+    assert!(pyvenv_cfg.contains("relocatable = true"));
+
+    // On subsequent syncs, the environment should be retained.
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited 1 package in [TIME]
+    "###);
+
+    Ok(())
+}
+
+/// Bundling requires a managed Python installation.
+#[cfg(unix)]
+#[test]
+fn sync_bundle_python_unmanaged() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r"Python installation at `.*`",
+            "Python installation at `[PREFIX]`",
+        )])
+        .collect::<Vec<_>>();
+
+    uv_snapshot!(filters, context.sync().arg("--bundle-python"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed virtual environment at: .venv
+    Creating virtual environment at: .venv
+    error: The `--bundle-python` option requires a Python installation managed by uv, but the environment is based on the Python installation at `[PREFIX]`
+
+    hint: use `--python-preference only-managed` to select a managed Python installation
+    "###);
+
+    Ok(())
+}
+
+/// Bundling isn't supported on Windows.
+#[cfg(windows)]
+#[test]
+fn sync_bundle_python_windows() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.sync().arg("--bundle-python"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The `--bundle-python` option is not supported on Windows
+    "###);
+
+    Ok(())
+}
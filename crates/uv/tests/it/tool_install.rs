@@ -1,6 +1,7 @@
 use std::process::Command;
 
 use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::{
     assert::PathAssert,
     fixture::{FileTouch, FileWriteStr, PathChild},
@@ -365,6 +366,347 @@ fn tool_install_version() {
     "###);
 }
 
+/// Test that installing a tool writes a lockfile, which can be used with `--locked`.
+#[test]
+fn tool_install_locked() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Without a lockfile, `--locked` fails.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black==24.2.0")
+        .arg("--locked")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find a lockfile for `black`, but `--locked` was provided. To create a lockfile, run `uv tool install` without `--locked`.
+    "###);
+
+    // Install `black`, which writes a lockfile next to the receipt.
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    let lockfile = tool_dir.child("black").child("uv.lock");
+    lockfile.assert(predicate::path::exists());
+    let lock = fs_err::read_to_string(&lockfile)?;
+    assert!(
+        lock.contains("name = \"black\"\nversion = \"24.2.0\""),
+        "{lock}"
+    );
+    assert!(
+        lock.contains("name = \"click\"\nversion = \"8.1.7\""),
+        "{lock}"
+    );
+
+    // Re-create the environment from the lockfile.
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .arg("--locked")
+        .arg("--reinstall")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+    assert_eq!(fs_err::read_to_string(&lockfile)?, lock);
+
+    // Changing the requirements would change the lockfile, which isn't allowed with `--locked`.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black==24.1.0")
+        .arg("--locked")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    error: The lockfile for `black` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv tool install` without `--locked`.
+    "###);
+
+    // The existing installation is left untouched.
+    assert_eq!(fs_err::read_to_string(&lockfile)?, lock);
+    uv_snapshot!(context.filters(), Command::new("black").arg("--version").env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black, 24.2.0 (compiled: yes)
+    Python (CPython) 3.12.[X]
+
+    ----- stderr -----
+    "###);
+
+    // If the lockfile is missing, e.g., for a tool installed by an older version of uv, it's
+    // re-created, even if the tool is already installed.
+    fs_err::remove_file(&lockfile)?;
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    `black==24.2.0` is already installed
+    "###);
+    assert_eq!(fs_err::read_to_string(&lockfile)?, lock);
+
+    Ok(())
+}
+
+/// Test installing a tool with `--constraints` and `--overrides`.
+#[test]
+fn tool_install_constraints_overrides() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("click<8.1.7")?;
+
+    // `black` requires `packaging>=22.0`, so this can only be satisfied via an override.
+    let overrides_txt = context.temp_dir.child("overrides.txt");
+    overrides_txt.write_str("packaging==21.3")?;
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--constraints")
+        .arg("constraints.txt")
+        .arg("--overrides")
+        .arg("overrides.txt")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.3.0
+     + click==8.1.6
+     + mypy-extensions==1.0.0
+     + packaging==21.3
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+     + pyparsing==3.1.2
+    Installed 2 executables: black, blackd
+    "###);
+
+    // The constraints and overrides should be persisted in the receipt.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        [tool]
+        requirements = [{ name = "black" }]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
+        ]
+
+        [tool.options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        constraints = [{ name = "click", specifier = "<8.1.7" }]
+        overrides = [{ name = "packaging", specifier = "==21.3" }]
+        "###);
+    });
+
+    // Re-installing with the same constraints and overrides is a no-op.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .arg("--constraints")
+        .arg("constraints.txt")
+        .arg("--overrides")
+        .arg("overrides.txt")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    `black` is already installed
+    "###);
+
+    // Re-installing without them should drop them from the environment and the receipt.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Uninstalled [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     - click==8.1.6
+     + click==8.1.7
+     - packaging==21.3
+     + packaging==24.0
+     - pyparsing==3.1.2
+    Installed 2 executables: black, blackd
+    "###);
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        [tool]
+        requirements = [{ name = "black" }]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
+        ]
+
+        [tool.options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "###);
+    });
+
+    Ok(())
+}
+
+/// Test installing the executables of a `--with` package via `--with-executables-from`.
+#[test]
+fn tool_install_with_executables_from() {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // The package must be included in the environment.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black==24.2.0")
+        .arg("--with-executables-from")
+        .arg("flask")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    error: `flask` was requested via `--with-executables-from`, but is not installed in the tool environment (use `--with flask` to include it)
+    "###);
+
+    tool_dir.child("black").assert(predicate::path::missing());
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black==24.2.0")
+        .arg("--with")
+        .arg("flask")
+        .arg("--with-executables-from")
+        .arg("flask")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     + black==24.2.0
+     + blinker==1.7.0
+     + click==8.1.7
+     + flask==3.0.2
+     + itsdangerous==2.1.2
+     + jinja2==3.1.3
+     + markupsafe==2.1.5
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+     + werkzeug==3.0.1
+    Installed 3 executables: black, blackd, flask
+    "###);
+
+    bin_dir
+        .child(format!("flask{}", std::env::consts::EXE_SUFFIX))
+        .assert(predicate::path::exists());
+
+    // The receipt should record the package that provides each executable.
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml")).unwrap(), @r###"
+        [tool]
+        requirements = [
+            { name = "black", specifier = "==24.2.0" },
+            { name = "flask" },
+        ]
+        entrypoints = [
+            { name = "black", install-path = "[TEMP_DIR]/bin/black" },
+            { name = "blackd", install-path = "[TEMP_DIR]/bin/blackd" },
+            { name = "flask", install-path = "[TEMP_DIR]/bin/flask", from = "flask" },
+        ]
+
+        [tool.options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+        "###);
+    });
+
+    // Re-installing without `--with-executables-from` should remove the `flask` executable.
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black==24.2.0")
+        .arg("--with")
+        .arg("flask")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Installed 2 executables: black, blackd
+    "###);
+
+    bin_dir
+        .child(format!("flask{}", std::env::consts::EXE_SUFFIX))
+        .assert(predicate::path::missing());
+}
+
 /// Test an editable installation of a tool.
 #[test]
 fn tool_install_editable() {
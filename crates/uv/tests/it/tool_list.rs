@@ -35,6 +35,40 @@ fn tool_list() {
     "###);
 }
 
+#[test]
+fn tool_list_with_executables_from() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`, along with the `flask` executable.
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .arg("--with")
+        .arg("flask")
+        .arg("--with-executables-from")
+        .arg("flask")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_list()
+    .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+    .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black v24.2.0
+    - black
+    - blackd
+    - flask [from: flask]
+
+    ----- stderr -----
+    "###);
+}
+
 #[test]
 fn tool_list_paths() {
     let context = TestContext::new("3.12").with_filtered_exe_suffix();
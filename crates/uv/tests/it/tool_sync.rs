@@ -0,0 +1,172 @@
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use predicates::prelude::predicate;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn tool_sync() -> Result<()> {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let manifest = context.temp_dir.child("uv-tools.toml");
+    manifest.write_str(indoc! {r#"
+        [tools.black]
+        from = "black==24.2.0"
+    "#})?;
+
+    // Install the tools declared in the manifest.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Prepared 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    // Syncing again is a no-op.
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    `black==24.2.0` is already installed
+    "###);
+
+    // Tools that are removed from the manifest are uninstalled.
+    manifest.write_str("[tools]\n")?;
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Uninstalled 2 executables: black, blackd
+    "###);
+
+    tool_dir.child("black").assert(predicate::path::missing());
+
+    Ok(())
+}
+
+/// Changing a tool's requirements in the manifest reinstalls it.
+#[test]
+fn tool_sync_changed() -> Result<()> {
+    let context = TestContext::new("3.12")
+        .with_filtered_counts()
+        .with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let manifest = context.temp_dir.child("uv-tools.toml");
+    manifest.write_str(indoc! {r#"
+        [tools.black]
+        from = "black==24.2.0"
+    "#})?;
+
+    context
+        .tool_sync()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // Changing the version reinstalls the tool.
+    manifest.write_str(indoc! {r#"
+        [tools.black]
+        from = "black==24.1.0"
+    "#})?;
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Uninstalled [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     - black==24.2.0
+     + black==24.1.0
+    Installed 2 executables: black, blackd
+    "###);
+
+    // Adding constraints reinstalls the tool, too.
+    manifest.write_str(indoc! {r#"
+        [tools.black]
+        from = "black==24.1.0"
+        constraints = ["click<8.1.7"]
+    "#})?;
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved [N] packages in [TIME]
+    Prepared [N] packages in [TIME]
+    Uninstalled [N] packages in [TIME]
+    Installed [N] packages in [TIME]
+     - click==8.1.7
+     + click==8.1.6
+    Installed 2 executables: black, blackd
+    "###);
+
+    let receipt = fs_err::read_to_string(tool_dir.join("black").join("uv-receipt.toml"))?;
+    assert!(
+        receipt.contains("constraints = [{ name = \"click\", specifier = \"<8.1.7\" }]"),
+        "{receipt}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn tool_sync_missing_manifest() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_sync()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No tool manifest found at `uv-tools.toml`
+    "###);
+}
@@ -585,6 +585,71 @@ fn seed_older_python_version() {
     context.venv.assert(predicates::path::is_dir());
 }
 
+#[test]
+fn seed_with_requirements() -> Result<()> {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("typing-extensions")?;
+
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("--seed-with")
+        .arg("requirements.txt")
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment at: .venv
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + typing-extensions==4.10.0
+    Activate with: source .venv/[BIN]/activate
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+fn seed_with_packages() {
+    let context = TestContext::new_with_versions(&["3.12"]);
+
+    // The seed packages should be included in the same resolution.
+    uv_snapshot!(context.filters(), context.venv()
+        .arg(context.venv.as_os_str())
+        .arg("--seed")
+        .arg("--with")
+        .arg("iniconfig")
+        .arg("--python")
+        .arg("3.12"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Using CPython 3.12.[X] interpreter at: [PYTHON-3.12]
+    Creating virtual environment with seed packages at: .venv
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + pip==24.0
+    Activate with: source .venv/[BIN]/activate
+    "###
+    );
+
+    context.venv.assert(predicates::path::is_dir());
+}
+
 #[test]
 fn create_venv_unknown_python_minor() {
     let context = TestContext::new_with_versions(&["3.12"]);